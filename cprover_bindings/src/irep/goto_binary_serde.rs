@@ -77,32 +77,165 @@
 //! of [NumberedIrep] and [NumberedString] it has already read from file.
 //! Dually to the serializer, it will only attempt to decode the contents of an
 //! object from the byte stream on the first occurrence.
+//!
+//! A goto binary file may also contain a map of [GotoFunction] bodies. The
+//! [Irep] describing their instructions are serialized using the same sharing
+//! machinery as the symbol table.
 
 use crate::irep::{Irep, IrepId, Symbol, SymbolTable};
 use crate::{InternString, InternedString};
 use std::collections::HashMap;
+use std::fmt::{self, Display};
 use std::fs::File;
 use std::hash::Hash;
 use std::io::{self, BufReader};
 use std::io::{BufWriter, Bytes, Error, Read, Write};
 use std::path::Path;
 
-/// Writes a symbol table to a file in goto binary format in version 6.
+/// The version of the goto binary format supported by the serializer and deserializer.
+///
+/// External CBMC tools (e.g. `goto-instrument` or `goto-analyzer`) can only read goto binaries
+/// whose version matches the one they were built with.
+pub const GOTO_BINARY_VERSION: usize = 6;
+
+/// Writes a symbol table to a file in goto binary format in version [GOTO_BINARY_VERSION].
 ///
 /// In CBMC, the serialization rules are defined in :
 /// - src/goto-programs/write_goto_binary.h
 /// - src/util/irep_serialization.h
 /// - src/util/irep_hash_container.h
 /// - src/util/irep_hash.h
-pub fn write_goto_binary_file(filename: &Path, source: &crate::goto_program::SymbolTable) {
-    let out_file = File::create(filename).unwrap();
-    let mut writer = BufWriter::new(out_file);
-    let mut serializer = GotoBinarySerializer::new(&mut writer);
+///
+/// Returns statistics about the structural sharing achieved while writing the file.
+pub fn write_goto_binary_file(
+    filename: &Path,
+    source: &crate::goto_program::SymbolTable,
+) -> SymbolTableStats {
+    write_goto_binary_file_with_functions(filename, source, &[])
+}
+
+/// Writes a symbol table together with the given GOTO function bodies to a file in goto binary
+/// format in version [GOTO_BINARY_VERSION].
+pub fn write_goto_binary_file_with_functions(
+    filename: &Path,
+    source: &crate::goto_program::SymbolTable,
+    functions: &[GotoFunction],
+) -> SymbolTableStats {
+    write_goto_binary_file_with_cache(filename, source, functions, &mut IrepNumberingCache::new())
+}
+
+/// Writes a symbol table together with the given GOTO function bodies to a file in goto binary
+/// format in version [GOTO_BINARY_VERSION], reusing the structural numbering of previous writes.
+///
+/// Ireps that were already numbered while writing another file with the same `cache` keep their
+/// number, so only the ireps that are new to this file need to be hashed and numbered. Each file
+/// is still self-contained: every irep and string is written in full the first time it occurs in
+/// that file.
+pub fn write_goto_binary_file_with_cache(
+    filename: &Path,
+    source: &crate::goto_program::SymbolTable,
+    functions: &[GotoFunction],
+    cache: &mut IrepNumberingCache,
+) -> SymbolTableStats {
+    let mut out_file = File::create(filename).unwrap();
+    write_to_writer_with_cache(&mut out_file, source, functions, cache)
+}
+
+/// Writes a symbol table in goto binary format in version [GOTO_BINARY_VERSION] to an arbitrary
+/// writer, e.g. the standard input of a CBMC subprocess.
+///
+/// The output is buffered and flushed before returning, and it is byte-for-byte identical to the
+/// content written by [write_goto_binary_file].
+pub fn write_goto_binary_to_writer<W: Write>(
+    writer: &mut W,
+    source: &crate::goto_program::SymbolTable,
+) -> SymbolTableStats {
+    write_to_writer_with_cache(writer, source, &[], &mut IrepNumberingCache::new())
+}
+
+/// Buffers `writer`, writes the symbol table and GOTO function bodies to it, and flushes it.
+fn write_to_writer_with_cache<W: Write>(
+    writer: &mut W,
+    source: &crate::goto_program::SymbolTable,
+    functions: &[GotoFunction],
+    cache: &mut IrepNumberingCache,
+) -> SymbolTableStats {
+    let mut writer = BufWriter::new(writer);
     let irep_symbol_table = &source.to_irep();
-    serializer.write_file(irep_symbol_table);
+    let stats = write_with_cache(&mut writer, irep_symbol_table, functions, cache);
+    writer.flush().unwrap();
+    stats
+}
+
+/// Writes a symbol table and GOTO function bodies to `writer` using the numbering of `cache`,
+/// and stores the updated numbering back into `cache`.
+fn write_with_cache<W: Write>(
+    writer: &mut BufWriter<W>,
+    symbol_table: &SymbolTable,
+    functions: &[GotoFunction],
+    cache: &mut IrepNumberingCache,
+) -> SymbolTableStats {
+    let numbering = std::mem::replace(&mut cache.numbering, IrepNumbering::new());
+    let mut serializer = GotoBinarySerializer::with_numbering(writer, numbering);
+    serializer.write_file(symbol_table, functions);
+    let stats = serializer.symbol_table_stats();
+    cache.numbering = serializer.numbering;
+    stats
+}
+
+/// A structural numbering of ireps and strings that can be reused across several calls to
+/// [write_goto_binary_file_with_cache].
+///
+/// Only the numbering is shared between writes. The record of which ireps and strings were
+/// already written is reset for every file, so each file can be read on its own.
+///
+/// The numbers are only meaningful to the process that assigned them, since they depend on the
+/// order in which ireps were first seen. Thus, a cache must not be persisted or shared across
+/// processes, e.g. to write different parts of the same goto binary.
+pub struct IrepNumberingCache {
+    numbering: IrepNumbering,
+}
+
+impl IrepNumberingCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        IrepNumberingCache { numbering: IrepNumbering::new() }
+    }
+}
+
+impl Default for IrepNumberingCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Summary of the structural sharing achieved while writing a goto binary.
+///
+/// This is cheap to compute, since it only inspects the write counts that the serializer keeps
+/// anyway. It is useful to diagnose codegen blowups.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SymbolTableStats {
+    /// Number of structurally unique ireps written.
+    pub unique_ireps: usize,
+    /// Number of unique strings written.
+    pub unique_strings: usize,
+    /// Maximum number of references to a single irep.
+    pub max_irep_sharing: usize,
+    /// Maximum number of references to a single string.
+    pub max_string_sharing: usize,
+}
+
+impl Display for SymbolTableStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "unique ireps: {}", self.unique_ireps)?;
+        writeln!(f, "unique strings: {}", self.unique_strings)?;
+        writeln!(f, "max irep sharing: {}", self.max_irep_sharing)?;
+        write!(f, "max string sharing: {}", self.max_string_sharing)
+    }
 }
 
-/// Reads a symbol table from a file expected to be in goto binary format in version 6.
+/// Reads a symbol table from a file expected to be in goto binary format in version
+/// [GOTO_BINARY_VERSION].
 //
 /// In CBMC, the deserialization rules are defined in :
 /// - src/goto-programs/read_goto_binary.h
@@ -113,7 +246,77 @@ pub fn read_goto_binary_file(filename: &Path) -> io::Result<()> {
     let file = File::open(filename)?;
     let reader = BufReader::new(file);
     let mut deserializer = GotoBinaryDeserializer::new(reader);
-    deserializer.read_file()
+    deserializer.read_file().map(|_| ())
+}
+
+/// Reads the symbol table of a file expected to be in goto binary format in version
+/// [GOTO_BINARY_VERSION]. The GOTO functions map that follows the symbol table is not read.
+///
+/// The goto binary format only stores the string representation of each [IrepId], so the
+/// [Irep]s of the symbols use [IrepId::FreeformString] for all their ids.
+pub fn read_goto_binary_symbol_table(filename: &Path) -> io::Result<SymbolTable> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+    let mut deserializer = GotoBinaryDeserializer::new(reader);
+    deserializer.read_header()?;
+    deserializer.read_symbol_table()
+}
+
+/// Reads the GOTO function bodies of a file expected to be in goto binary format in version
+/// [GOTO_BINARY_VERSION], e.g. one written by [write_goto_binary_file_with_functions].
+///
+/// Like for [read_goto_binary_symbol_table], the ids of the [Irep]s are read back as
+/// [IrepId::FreeformString].
+pub fn read_goto_binary_functions(filename: &Path) -> io::Result<Vec<GotoFunction>> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+    let mut deserializer = GotoBinaryDeserializer::new(reader);
+    let functions = deserializer.read_file()?;
+    Ok(functions
+        .iter()
+        .map(|function| deserializer.goto_function_from_numbered(function))
+        .collect())
+}
+
+/// Compares the symbol tables of two files in goto binary format in version
+/// [GOTO_BINARY_VERSION], and returns the name of the first symbol, in lexicographic order, that
+/// is missing from one of the files or that differs structurally between them.
+///
+/// Returns `None` if both symbol tables are structurally equal.
+pub fn first_symbol_difference(left: &Path, right: &Path) -> io::Result<Option<InternedString>> {
+    let left = read_goto_binary_symbol_table(left)?.symbol_table;
+    let right = read_goto_binary_symbol_table(right)?.symbol_table;
+    let mut names: Vec<&InternedString> = left.keys().chain(right.keys()).collect();
+    names.sort();
+    Ok(names.into_iter().find(|name| left.get(*name) != right.get(*name)).copied())
+}
+
+/// A GOTO function body, i.e., an entry of the GOTO functions map of a goto binary.
+///
+/// In CBMC, this corresponds to a `goto_functiont` whose body is available.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GotoFunction {
+    /// The name of the function, which must match the name of its symbol.
+    pub name: InternedString,
+    /// The instructions of the function body, in program order.
+    pub instructions: Vec<GotoInstruction>,
+}
+
+/// A single instruction of a GOTO program, i.e., a `goto_programt::instructiont` in CBMC.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GotoInstruction {
+    /// The code of the instruction, e.g. the assignment for an `ASSIGN` instruction.
+    pub code: Irep,
+    pub source_location: Irep,
+    /// The numeric value of the CBMC `goto_program_instruction_typet` of this instruction.
+    pub instruction_type: usize,
+    /// The condition of the instruction. This should be `true` for unconditional instructions.
+    pub guard: Irep,
+    /// The number used by other instructions to refer to this instruction as their target.
+    pub target_number: usize,
+    /// The target numbers of the instructions this instruction may jump to.
+    pub targets: Vec<usize>,
+    pub labels: Vec<InternedString>,
 }
 
 /// A numbered [InternedString]. The number is guaranteed to be in [0,N].
@@ -207,6 +410,9 @@ struct IrepNumbering {
     /// Inverse string cache.
     inv_string_cache: Vec<NumberedString>,
 
+    /// Map from [IrepId] to the unique numbers of their string representation.
+    irep_id_cache: HashMap<IrepId, usize>,
+
     /// Map from [IrepKey] to their unique numbers.
     cache: HashMap<IrepKey, usize>,
 
@@ -219,6 +425,7 @@ fn new() -> Self {
         IrepNumbering {
             string_cache: HashMap::new(),
             inv_string_cache: Vec::new(),
+            irep_id_cache: HashMap::new(),
             cache: HashMap::new(),
             inv_cache: IrepNumberingInv::new(),
         }
@@ -246,9 +453,14 @@ fn number_string(&mut self, string: &InternedString) -> NumberedString {
     }
 
     /// Turns a [IrepId] to a [NumberedString]. The [IrepId] gets the number of its
-    /// string representation.
+    /// string representation, which is only computed the first time the [IrepId] is seen.
     fn number_irep_id(&mut self, irep_id: &IrepId) -> NumberedString {
-        self.number_string(&irep_id.to_string().intern())
+        if let Some(number) = self.irep_id_cache.get(irep_id) {
+            return self.inv_string_cache[*number];
+        }
+        let numbered_string = self.number_string(&irep_id.to_string().intern());
+        self.irep_id_cache.insert(irep_id.clone(), numbered_string.number);
+        numbered_string
     }
 
     /// Turns an [Irep] into a [NumberedIrep]. The [Irep] is recursively traversed
@@ -280,6 +492,26 @@ fn get_or_insert(&mut self, key: &IrepKey) -> NumberedIrep {
         self.inv_cache.index[number]
     }
 
+    /// Rebuilds the [Irep] of the given [NumberedIrep]. Since only the string representation of
+    /// an [IrepId] is numbered, ids are rebuilt as [IrepId::FreeformString].
+    ///
+    /// An irep can only refer to ireps numbered before itself, so the numbering is a DAG and the
+    /// recursion always terminates. Shared sub-ireps are rebuilt once per occurrence, and
+    /// numbering the result again yields the same [NumberedIrep].
+    fn irep_from_numbered(&self, numbered_irep: &NumberedIrep) -> Irep {
+        let id = IrepId::from_string(self.id(numbered_irep).string);
+        let sub = (0..self.nof_sub(numbered_irep))
+            .map(|sub_idx| self.irep_from_numbered(&self.sub(numbered_irep, sub_idx)))
+            .collect();
+        let named_sub = (0..self.nof_named_sub(numbered_irep))
+            .map(|named_sub_idx| {
+                let (k, v) = self.named_sub(numbered_irep, named_sub_idx);
+                (IrepId::from_string(k.string), self.irep_from_numbered(&v))
+            })
+            .collect();
+        Irep { id, sub, named_sub }
+    }
+
     /// Returns the unique number of the `id` field of the given [NumberedIrep].
     fn id(&self, numbered_irep: &NumberedIrep) -> NumberedString {
         self.inv_string_cache[self.inv_cache.keys[numbered_irep.start_index]]
@@ -352,14 +584,15 @@ impl<'a, W> GotoBinarySerializer<'a, W>
 where
     W: Write,
 {
-    /// Constructor.
+    /// Constructor with a fresh numbering.
+    #[cfg(test)]
     fn new(buf: &'a mut BufWriter<W>) -> Self {
-        GotoBinarySerializer {
-            buf,
-            numbering: IrepNumbering::new(),
-            irep_count: Vec::new(),
-            string_count: Vec::new(),
-        }
+        Self::with_numbering(buf, IrepNumbering::new())
+    }
+
+    /// Constructor that reuses an existing numbering. Nothing is considered written yet.
+    fn with_numbering(buf: &'a mut BufWriter<W>, numbering: IrepNumbering) -> Self {
+        GotoBinarySerializer { buf, numbering, irep_count: Vec::new(), string_count: Vec::new() }
     }
 
     /// Adds an InternedString uid to the "written" cache, returns true iff was never written before.
@@ -382,6 +615,18 @@ fn is_first_write_irep(&mut self, u: usize) -> bool {
         count == 0
     }
 
+    /// Returns the number of unique ireps and strings written so far, and the maximum number of
+    /// times one of them was referenced.
+    fn symbol_table_stats(&self) -> SymbolTableStats {
+        fn unique_and_max(counts: &[usize]) -> (usize, usize) {
+            let unique = counts.iter().filter(|count| **count > 0).count();
+            (unique, counts.iter().copied().max().unwrap_or(0))
+        }
+        let (unique_ireps, max_irep_sharing) = unique_and_max(&self.irep_count);
+        let (unique_strings, max_string_sharing) = unique_and_max(&self.string_count);
+        SymbolTableStats { unique_ireps, unique_strings, max_irep_sharing, max_string_sharing }
+    }
+
     /// Writes a single byte to the temporary buffer.
     fn write_u8(&mut self, u: u8) {
         assert!(self.buf.write(&[u]).unwrap() == 1);
@@ -526,10 +771,38 @@ fn write_symbol_table(&mut self, symbol_table: &SymbolTable) {
         }
     }
 
-    /// Writes an empty function map to the byte stream.
-    fn write_function_map(&mut self) {
-        // Write empty GOTO functions map
-        self.write_usize_varenc(0);
+    /// Writes a single GOTO instruction to the byte stream.
+    fn write_goto_instruction(&mut self, instruction: &GotoInstruction) {
+        self.write_irep_ref(&instruction.code);
+        self.write_irep_ref(&instruction.source_location);
+        self.write_usize_varenc(instruction.instruction_type);
+        self.write_irep_ref(&instruction.guard);
+        self.write_usize_varenc(instruction.target_number);
+
+        self.write_usize_varenc(instruction.targets.len());
+        for target in instruction.targets.iter() {
+            self.write_usize_varenc(*target);
+        }
+
+        self.write_usize_varenc(instruction.labels.len());
+        for label in instruction.labels.iter() {
+            self.write_string_ref(label);
+        }
+    }
+
+    /// Writes the GOTO functions map to the byte stream.
+    fn write_goto_functions(&mut self, functions: &[GotoFunction]) {
+        // Write GOTO functions map size
+        self.write_usize_varenc(functions.len());
+
+        // Write functions
+        for function in functions {
+            self.write_string_ref(&function.name);
+            self.write_usize_varenc(function.instructions.len());
+            for instruction in function.instructions.iter() {
+                self.write_goto_instruction(instruction);
+            }
+        }
     }
 
     /// Writes a GOTO binary file header to the byte stream.
@@ -540,17 +813,37 @@ fn write_header(&mut self) {
         assert!(written == 4);
 
         // Write goto binary version
-        self.write_usize_varenc(6);
+        self.write_usize_varenc(GOTO_BINARY_VERSION);
     }
 
-    /// Writes the symbol table using the GOTO binary file format to the byte stream.
-    fn write_file(&mut self, symbol_table: &SymbolTable) {
+    /// Writes the symbol table and the GOTO functions using the GOTO binary file format to the
+    /// byte stream.
+    fn write_file(&mut self, symbol_table: &SymbolTable, functions: &[GotoFunction]) {
         self.write_header();
         self.write_symbol_table(symbol_table);
-        self.write_function_map();
+        self.write_goto_functions(functions);
     }
 }
 
+/// A [GotoFunction] read from the byte stream, with its contents in numbered form.
+#[derive(Debug)]
+struct NumberedGotoFunction {
+    name: NumberedString,
+    instructions: Vec<NumberedGotoInstruction>,
+}
+
+/// A [GotoInstruction] read from the byte stream, with its contents in numbered form.
+#[derive(Debug)]
+struct NumberedGotoInstruction {
+    code: NumberedIrep,
+    source_location: NumberedIrep,
+    instruction_type: usize,
+    guard: NumberedIrep,
+    target_number: usize,
+    targets: Vec<usize>,
+    labels: Vec<NumberedString>,
+}
+
 /// GOTO binary deserializer. Reads GOTO constructs from the byte stream of a reader.
 struct GotoBinaryDeserializer<R>
 where
@@ -816,19 +1109,30 @@ fn read_numbered_irep_ref(&mut self) -> io::Result<NumberedIrep> {
         }
     }
 
+    /// Reads a string from the byte stream.
+    fn read_string_ref(&mut self) -> io::Result<InternedString> {
+        Ok(self.read_numbered_string_ref()?.string)
+    }
+
+    /// Reads an irep from the byte stream and rebuilds it from its numbered version.
+    fn read_irep_ref(&mut self) -> io::Result<Irep> {
+        let numbered_irep = self.read_numbered_irep_ref()?;
+        Ok(self.numbering.irep_from_numbered(&numbered_irep))
+    }
+
     /// Reads a Symbol from the byte stream.
-    fn read_symbol(&mut self) -> io::Result<()> {
+    fn read_symbol(&mut self) -> io::Result<Symbol> {
         // Read Irep attributes of the symbol
-        let _typ = self.read_numbered_irep_ref()?;
-        let _value = self.read_numbered_irep_ref()?;
-        let _location = self.read_numbered_irep_ref()?;
+        let typ = self.read_irep_ref()?;
+        let value = self.read_irep_ref()?;
+        let location = self.read_irep_ref()?;
 
         // Read string attributes of the symbol
-        let _name = self.read_numbered_string_ref()?;
-        let _module = self.read_numbered_string_ref()?;
-        let _base_name = self.read_numbered_string_ref()?;
-        let _mode = self.read_numbered_string_ref()?;
-        let _pretty_name = self.read_numbered_string_ref()?;
+        let name = self.read_string_ref()?;
+        let module = self.read_string_ref()?;
+        let base_name = self.read_string_ref()?;
+        let mode = self.read_string_ref()?;
+        let pretty_name = self.read_string_ref()?;
 
         // obsolete: symordering
         let symordering = self.read_u8()?;
@@ -837,53 +1141,114 @@ fn read_symbol(&mut self) -> io::Result<()> {
         // Decode the bit-packed flags and extract bits one by one
         let flags: usize = self.read_usize_varenc()?;
 
-        let _is_weak = (flags & (1 << 16)) != 0;
-        let _is_type = (flags & (1 << 15)) != 0;
-        let _is_property = (flags & (1 << 14)) != 0;
-        let _is_macro = (flags & (1 << 13)) != 0;
-        let _is_exported = (flags & (1 << 12)) != 0;
-        let _is_input = (flags & (1 << 11)) != 0;
-        let _is_output = (flags & (1 << 10)) != 0;
-        let _is_state_var = (flags & (1 << 9)) != 0;
-        let _is_parameter = (flags & (1 << 8)) != 0;
-        let _is_auxiliary = (flags & (1 << 7)) != 0;
+        let is_weak = (flags & (1 << 16)) != 0;
+        let is_type = (flags & (1 << 15)) != 0;
+        let is_property = (flags & (1 << 14)) != 0;
+        let is_macro = (flags & (1 << 13)) != 0;
+        let is_exported = (flags & (1 << 12)) != 0;
+        let is_input = (flags & (1 << 11)) != 0;
+        let is_output = (flags & (1 << 10)) != 0;
+        let is_state_var = (flags & (1 << 9)) != 0;
+        let is_parameter = (flags & (1 << 8)) != 0;
+        let is_auxiliary = (flags & (1 << 7)) != 0;
         // deprecated sym.binding but remains present for compatibility
         let _is_binding = (flags & (1 << 6)) != 0;
-        let _is_lvalue = (flags & (1 << 5)) != 0;
-        let _is_static_lifetime = (flags & (1 << 4)) != 0;
-        let _is_thread_local = (flags & (1 << 3)) != 0;
-        let _is_file_local = (flags & (1 << 2)) != 0;
-        let _is_extern = (flags & (1 << 1)) != 0;
-        let _is_volatile = (flags & 1) != 0;
+        let is_lvalue = (flags & (1 << 5)) != 0;
+        let is_static_lifetime = (flags & (1 << 4)) != 0;
+        let is_thread_local = (flags & (1 << 3)) != 0;
+        let is_file_local = (flags & (1 << 2)) != 0;
+        let is_extern = (flags & (1 << 1)) != 0;
+        let is_volatile = (flags & 1) != 0;
 
-        let shifted_flags = flags >> 16;
+        let shifted_flags = flags >> 17;
 
         if shifted_flags != 0 {
             return Err(Error::other(
                 "incorrect binary format: true bits remain in decoded symbol flags",
             ));
         }
-        Ok(())
+        Ok(Symbol {
+            typ,
+            value,
+            location,
+            name,
+            module,
+            base_name,
+            pretty_name,
+            mode,
+            is_type,
+            is_macro,
+            is_exported,
+            is_input,
+            is_output,
+            is_state_var,
+            is_property,
+            is_static_lifetime,
+            is_thread_local,
+            is_lvalue,
+            is_file_local,
+            is_extern,
+            is_volatile,
+            is_parameter,
+            is_auxiliary,
+            is_weak,
+        })
     }
 
     /// Reads a whole SymbolTable from the byte stream.
-    fn read_symbol_table(&mut self) -> io::Result<()> {
-        // Write symbol table size
+    fn read_symbol_table(&mut self) -> io::Result<SymbolTable> {
+        // Read symbol table size
         let symbol_table_len = self.read_usize_varenc()?;
 
-        // Write symbols
+        // Read symbols
+        let mut symbol_table = SymbolTable::new();
         for _ in 0..symbol_table_len {
-            self.read_symbol()?;
+            symbol_table.insert(self.read_symbol()?);
         }
 
-        Ok(())
+        Ok(symbol_table)
+    }
+
+    /// Reads a single GOTO instruction from the byte stream.
+    fn read_goto_instruction(&mut self) -> io::Result<NumberedGotoInstruction> {
+        let code = self.read_numbered_irep_ref()?;
+        let source_location = self.read_numbered_irep_ref()?;
+        let instruction_type = self.read_usize_varenc()?;
+        let guard = self.read_numbered_irep_ref()?;
+        let target_number = self.read_usize_varenc()?;
+
+        let targets_len = self.read_usize_varenc()?;
+        let targets =
+            (0..targets_len).map(|_| self.read_usize_varenc()).collect::<Result<_, _>>()?;
+
+        let labels_len = self.read_usize_varenc()?;
+        let labels =
+            (0..labels_len).map(|_| self.read_numbered_string_ref()).collect::<Result<_, _>>()?;
+
+        Ok(NumberedGotoInstruction {
+            code,
+            source_location,
+            instruction_type,
+            guard,
+            target_number,
+            targets,
+            labels,
+        })
     }
 
-    /// Reads an empty function map from the byte stream.
-    fn read_function_map(&mut self) -> io::Result<()> {
+    /// Reads the GOTO functions map from the byte stream. The map may be empty.
+    fn read_function_map(&mut self) -> io::Result<Vec<NumberedGotoFunction>> {
         let goto_function_len = self.read_usize_varenc()?;
-        Self::expect(goto_function_len, 0)?;
-        Ok(())
+        let mut functions = Vec::with_capacity(goto_function_len);
+        for _ in 0..goto_function_len {
+            let name = self.read_numbered_string_ref()?;
+            let instructions_len = self.read_usize_varenc()?;
+            let instructions = (0..instructions_len)
+                .map(|_| self.read_goto_instruction())
+                .collect::<Result<_, _>>()?;
+            functions.push(NumberedGotoFunction { name, instructions });
+        }
+        Ok(functions)
     }
 
     /// Reads a GOTO binary header from the byte stream.
@@ -896,21 +1261,38 @@ fn read_header(&mut self) -> io::Result<()> {
 
         // Read goto binary version
         let goto_binary_version = self.read_usize_varenc()?;
-        if goto_binary_version != 6 {
+        if goto_binary_version != GOTO_BINARY_VERSION {
             return Err(Error::other(format!(
                 "Unsupported GOTO binary version: {}. Supported version: {}",
-                goto_binary_version, 6
+                goto_binary_version, GOTO_BINARY_VERSION
             )));
         }
         Ok(())
     }
 
-    /// Read a GOTO binary file from the byte stream.
-    fn read_file(&mut self) -> io::Result<()> {
+    /// Read a GOTO binary file from the byte stream, and return its GOTO functions.
+    fn read_file(&mut self) -> io::Result<Vec<NumberedGotoFunction>> {
         self.read_header()?;
         self.read_symbol_table()?;
-        self.read_function_map()?;
-        Ok(())
+        self.read_function_map()
+    }
+
+    /// Rebuilds the [GotoFunction] of the given [NumberedGotoFunction].
+    fn goto_function_from_numbered(&self, function: &NumberedGotoFunction) -> GotoFunction {
+        let instructions = function
+            .instructions
+            .iter()
+            .map(|instruction| GotoInstruction {
+                code: self.numbering.irep_from_numbered(&instruction.code),
+                source_location: self.numbering.irep_from_numbered(&instruction.source_location),
+                instruction_type: instruction.instruction_type,
+                guard: self.numbering.irep_from_numbered(&instruction.guard),
+                target_number: instruction.target_number,
+                targets: instruction.targets.clone(),
+                labels: instruction.labels.iter().map(|label| label.string).collect(),
+            })
+            .collect();
+        GotoFunction { name: function.name.string, instructions }
     }
 }
 
@@ -922,6 +1304,7 @@ fn read_file(&mut self) -> io::Result<()> {
 mod sharing_stats {
     use super::GotoBinaryDeserializer;
     use super::GotoBinarySerializer;
+    use super::IrepId;
     use super::IrepKey;
     use super::IrepNumbering;
     use super::IrepNumberingInv;
@@ -977,6 +1360,16 @@ fn dynamic_usage_bounds(&self) -> (usize, Option<usize>) {
         }
     }
 
+    impl DynamicUsage for IrepId {
+        fn dynamic_usage(&self) -> usize {
+            std::mem::size_of::<Self>()
+        }
+        fn dynamic_usage_bounds(&self) -> (usize, Option<usize>) {
+            let s = std::mem::size_of::<Self>();
+            (s, Some(s))
+        }
+    }
+
     impl DynamicUsage for NumberedString {
         fn dynamic_usage(&self) -> usize {
             std::mem::size_of::<Self>()
@@ -992,6 +1385,7 @@ fn dynamic_usage(&self) -> usize {
             std::mem::size_of::<Self>()
                 + self.string_cache.dynamic_usage()
                 + self.inv_string_cache.dynamic_usage()
+                + self.irep_id_cache.dynamic_usage()
                 + self.cache.dynamic_usage()
                 + self.inv_cache.dynamic_usage()
         }
@@ -1000,11 +1394,14 @@ fn dynamic_usage_bounds(&self) -> (usize, Option<usize>) {
             let s = std::mem::size_of::<Self>();
             let (l1, u1) = self.string_cache.dynamic_usage_bounds();
             let (l2, u2) = self.inv_string_cache.dynamic_usage_bounds();
-            let (l3, u3) = self.cache.dynamic_usage_bounds();
-            let (l4, u4) = self.inv_cache.dynamic_usage_bounds();
-            let l = l1 + l2 + l3 + l4 + s;
+            let (l3, u3) = self.irep_id_cache.dynamic_usage_bounds();
+            let (l4, u4) = self.cache.dynamic_usage_bounds();
+            let (l5, u5) = self.inv_cache.dynamic_usage_bounds();
+            let l = l1 + l2 + l3 + l4 + l5 + s;
             let u = u1.and_then(|u1| {
-                u2.and_then(|u2| u3.and_then(|u3| u4.map(|u4| u1 + u2 + u3 + u4 + s)))
+                u2.and_then(|u2| {
+                    u3.and_then(|u3| u4.and_then(|u4| u5.map(|u5| u1 + u2 + u3 + u4 + u5 + s)))
+                })
             });
             (l, u)
         }
@@ -1177,14 +1574,21 @@ pub fn get_stats(&self) -> GotoBinarySharingStats {
 mod tests {
     use super::GotoBinarySerializer;
     use super::IrepNumbering;
+    use super::{GotoFunction, GotoInstruction, IrepNumberingCache, write_with_cache};
+    use super::{first_symbol_difference, write_goto_binary_file, write_goto_binary_to_writer};
+    use super::{read_goto_binary_functions, write_goto_binary_file_with_functions};
     use crate::InternedString;
     use crate::cbmc_string::InternString;
     use crate::irep::Irep;
     use crate::irep::IrepId;
     use crate::irep::goto_binary_serde::GotoBinaryDeserializer;
+    use crate::irep::{Symbol, SymbolTable};
     use crate::linear_map;
+    use crate::machine_model::test_util::machine_model_test_stub;
     use linear_map::LinearMap;
+    use memuse::DynamicUsage;
     use std::io::BufWriter;
+
     /// Utility function : creates a Irep representing a single symbol.
     fn make_symbol_expr(identifier: &str) -> Irep {
         Irep {
@@ -1203,6 +1607,53 @@ fn fold_with_op(identifiers: &Vec<&str>, id: IrepId) -> Irep {
         })
     }
 
+    /// Utility function: rebuilds an irep with all its ids turned into [IrepId::FreeformString],
+    /// which is how ireps are read back from a goto binary.
+    fn to_freeform(irep: &Irep) -> Irep {
+        Irep {
+            id: IrepId::from_string(irep.id.to_string()),
+            sub: irep.sub.iter().map(to_freeform).collect(),
+            named_sub: irep
+                .named_sub
+                .iter()
+                .map(|(k, v)| (IrepId::from_string(k.to_string()), to_freeform(v)))
+                .collect(),
+        }
+    }
+
+    /// Utility function: creates a symbol with the given name, type and value, and no flags set.
+    fn make_symbol(name: &str, typ: Irep, value: Irep) -> Symbol {
+        Symbol {
+            typ,
+            value,
+            location: Irep::just_named_sub(linear_map![(
+                IrepId::File,
+                Irep::just_string_id("main.rs")
+            )]),
+            name: name.intern(),
+            module: "".intern(),
+            base_name: name.intern(),
+            pretty_name: name.intern(),
+            mode: "C".intern(),
+            is_type: false,
+            is_macro: false,
+            is_exported: false,
+            is_input: false,
+            is_output: false,
+            is_state_var: false,
+            is_property: false,
+            is_static_lifetime: false,
+            is_thread_local: false,
+            is_lvalue: false,
+            is_file_local: false,
+            is_extern: false,
+            is_volatile: false,
+            is_parameter: false,
+            is_auxiliary: false,
+            is_weak: false,
+        }
+    }
+
     #[test]
     /// Create two structurally identical ireps and check that they get the same number.
     fn test_irep_numbering_eq() {
@@ -1215,6 +1666,54 @@ fn test_irep_numbering_eq() {
         assert_eq!(num1, num2);
     }
 
+    #[test]
+    /// Rebuild an irep with shared sub-ireps from its number, and check that numbering the rebuilt
+    /// irep yields the same number.
+    fn test_irep_from_numbered_renumber() {
+        let mut numbering = IrepNumbering::new();
+        let shared = fold_with_op(&vec!["foo", "bar", "foo"], IrepId::Plus);
+        let mut irep = shared.clone();
+        for _ in 0..8 {
+            irep = Irep {
+                id: IrepId::Mult,
+                sub: vec![irep.clone(), irep],
+                named_sub: linear_map![(IrepId::Type, shared.clone())],
+            };
+        }
+        let num = numbering.number_irep(&irep);
+        let rebuilt = numbering.irep_from_numbered(&num);
+        assert_eq!(rebuilt, to_freeform(&irep));
+        assert_eq!(numbering.number_irep(&rebuilt), num);
+    }
+
+    #[test]
+    /// Write two symbol tables that only differ in one symbol, and check that it's the first
+    /// difference reported.
+    fn test_first_symbol_difference() {
+        use crate::goto_program::{Location, Symbol, SymbolTable, Type};
+        let make_table = |y_type: Type| {
+            let mut symbol_table = SymbolTable::new(machine_model_test_stub());
+            for (name, typ) in [("x", Type::bool()), ("y", y_type), ("z", Type::bool())] {
+                symbol_table.insert(Symbol::variable(name, name, typ, Location::none()));
+            }
+            symbol_table
+        };
+        let dir = std::env::temp_dir();
+        let pid = std::process::id();
+        let left = dir.join(format!("symbol_difference_left_{pid}.symtab.out"));
+        let right = dir.join(format!("symbol_difference_right_{pid}.symtab.out"));
+        write_goto_binary_file(&left, &make_table(Type::bool()));
+        write_goto_binary_file(&right, &make_table(Type::bool()));
+        let same = first_symbol_difference(&left, &right).unwrap();
+        write_goto_binary_file(&right, &make_table(Type::signed_int(32)));
+        let different = first_symbol_difference(&left, &right).unwrap();
+        std::fs::remove_file(&left).unwrap();
+        std::fs::remove_file(&right).unwrap();
+
+        assert_eq!(same, None);
+        assert_eq!(different, Some("y".intern()));
+    }
+
     #[test]
     /// Create two ireps with different named subs and check that they get different numbers.
     fn test_irep_numbering_ne_named_sub() {
@@ -1246,6 +1745,40 @@ fn test_irep_numbering_ne_id() {
         assert_ne!(num1, num2);
     }
 
+    #[test]
+    /// Check that each IrepId is only converted to a string once, and that it gets the same
+    /// number as its string representation.
+    fn test_irep_numbering_irep_id_cache() {
+        let mut numbering = IrepNumbering::new();
+        let identifiers = ["foo", "bar", "baz"].repeat(50);
+        numbering.number_irep(&fold_with_op(&identifiers, IrepId::And));
+
+        // The irep contains 3 * 50 + 1 symbol expressions, but only 7 distinct ids.
+        let ids = [
+            IrepId::And,
+            IrepId::Symbol,
+            IrepId::Identifier,
+            IrepId::from_string("dummy"),
+            IrepId::from_string("foo"),
+            IrepId::from_string("bar"),
+            IrepId::from_string("baz"),
+        ];
+        assert_eq!(numbering.irep_id_cache.len(), ids.len());
+        for id in ids.iter() {
+            let expected = numbering.number_string(&id.to_string().intern());
+            assert_eq!(numbering.number_irep_id(id), expected);
+        }
+
+        // Numbering the same ids again finds them in the caches, which don't grow.
+        let usage = numbering.dynamic_usage();
+        let strings = numbering.inv_string_cache.len();
+        numbering.number_irep(&fold_with_op(&identifiers, IrepId::And));
+        assert_eq!(numbering.irep_id_cache.len(), ids.len());
+        assert_eq!(numbering.string_cache.len(), strings);
+        assert_eq!(numbering.inv_string_cache.len(), strings);
+        assert_eq!(numbering.dynamic_usage(), usage);
+    }
+
     #[test]
     /// Write and read back all possible u8 values.
     fn test_write_u8() {
@@ -1296,6 +1829,24 @@ fn test_write_usize() {
         }
     }
 
+    #[test]
+    /// Write and read back a header, and check that other versions are rejected.
+    fn test_write_read_header() {
+        let mut vec: Vec<u8> = Vec::new();
+        {
+            let mut writer = BufWriter::new(&mut vec);
+            let mut serializer = GotoBinarySerializer::new(&mut writer);
+            serializer.write_header();
+        }
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(vec.clone()));
+        assert!(deserializer.read_header().is_ok());
+
+        // Bump the version, which is encoded in the last byte.
+        *vec.last_mut().unwrap() += 1;
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(vec));
+        assert!(deserializer.read_header().is_err());
+    }
+
     #[test]
     /// Write and read back unique strings.
     fn test_write_read_unique_string_ref() {
@@ -1353,6 +1904,88 @@ fn test_write_read_multiple_string_ref() {
         println!("Deserializer stats {:?}", deserializer.get_stats());
     }
 
+    #[test]
+    /// Check the sharing statistics reported after writing repeated strings.
+    fn test_symbol_table_stats() {
+        let foo = String::from("foo").intern();
+        let bar = String::from("bar").intern();
+        let baz = String::from("baz").intern();
+        let strings = [foo, bar, foo, bar, foo, baz, baz, bar, foo];
+
+        let mut vec: Vec<u8> = Vec::new();
+        let mut writer = BufWriter::new(&mut vec);
+        let mut serializer = GotoBinarySerializer::new(&mut writer);
+        for string in strings {
+            serializer.write_string_ref(&string);
+        }
+        let stats = serializer.symbol_table_stats();
+        assert_eq!(stats.unique_strings, 3);
+        assert_eq!(stats.max_string_sharing, 4);
+        assert_eq!(stats.unique_ireps, 0);
+        assert_eq!(stats.max_irep_sharing, 0);
+    }
+
+    #[test]
+    /// Write two symbol tables that share ireps with the same numbering cache, and check that
+    /// the second write reuses the numbers assigned by the first one.
+    fn test_numbering_cache_reuse() {
+        let identifiers = vec!["foo", "bar", "baz", "zab", "rab", "oof"];
+        let shared = fold_with_op(&identifiers, IrepId::And);
+        let typ = Irep::just_id(IrepId::Bool);
+
+        let mut first = SymbolTable::new();
+        first.insert(make_symbol("first", typ.clone(), shared.clone()));
+        let mut second = SymbolTable::new();
+        second.insert(make_symbol("second", typ, shared.clone()));
+
+        let mut cache = IrepNumberingCache::new();
+        let mut first_bytes: Vec<u8> = Vec::new();
+        write_with_cache(&mut BufWriter::new(&mut first_bytes), &first, &[], &mut cache);
+        let number = cache.numbering.number_irep(&shared).number;
+        let nof_ireps = cache.numbering.cache.len();
+
+        let mut second_bytes: Vec<u8> = Vec::new();
+        {
+            let mut writer = BufWriter::new(&mut second_bytes);
+            let numbering = std::mem::replace(&mut cache.numbering, IrepNumbering::new());
+            let mut serializer = GotoBinarySerializer::with_numbering(&mut writer, numbering);
+            serializer.write_file(&second, &[]);
+            // The shared irep keeps its number and is written in full once in the second file.
+            assert_eq!(serializer.numbering.number_irep(&shared).number, number);
+            assert_eq!(serializer.irep_count[number], 1);
+            cache.numbering = serializer.numbering;
+        }
+        // Both symbols have the same type, value and location, so no new irep was numbered.
+        assert_eq!(cache.numbering.cache.len(), nof_ireps);
+
+        // The second file can be read back on its own.
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(second_bytes));
+        deserializer.read_file().unwrap();
+    }
+
+    #[test]
+    /// Write the same symbol table to a file and to an in-memory buffer, and check that the
+    /// bytes are identical.
+    fn test_write_to_writer_matches_file() {
+        let mut symbol_table = crate::goto_program::SymbolTable::new(machine_model_test_stub());
+        symbol_table.insert(crate::goto_program::Symbol::variable(
+            "foo",
+            "foo",
+            crate::goto_program::Type::bool(),
+            crate::goto_program::Location::none(),
+        ));
+        let path =
+            std::env::temp_dir().join(format!("write_to_writer_{}.symtab.out", std::process::id()));
+        let file_stats = write_goto_binary_file(&path, &symbol_table);
+        let file_bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        let writer_stats = write_goto_binary_to_writer(&mut cursor, &symbol_table);
+        assert_eq!(writer_stats, file_stats);
+        assert_eq!(cursor.into_inner(), file_bytes);
+    }
+
     #[test]
     /// Write and read back distinct ireps.
     fn test_write_irep_ref() {
@@ -1436,4 +2069,177 @@ fn test_write_read_irep_ref() {
             assert_eq!(irep1, irep6);
         }
     }
+
+    #[test]
+    /// Write and read back a file with an empty GOTO functions map.
+    fn test_write_read_empty_function_map() {
+        let mut vec: Vec<u8> = Vec::new();
+        {
+            let mut writer = BufWriter::new(&mut vec);
+            let mut serializer = GotoBinarySerializer::new(&mut writer);
+            serializer.write_file(&SymbolTable::new(), &[]);
+        }
+
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(vec));
+        deserializer.read_header().unwrap();
+        deserializer.read_symbol_table().unwrap();
+        assert!(deserializer.read_function_map().unwrap().is_empty());
+    }
+
+    #[test]
+    /// Write and read back a file with a GOTO function body.
+    fn test_write_read_function_map() {
+        let location =
+            Irep::just_named_sub(linear_map![(IrepId::File, Irep::just_string_id("main.rs"))]);
+        let guard = make_symbol_expr("cond");
+        let function = GotoFunction {
+            name: "foo".intern(),
+            instructions: vec![
+                // GOTO 1 IF cond
+                GotoInstruction {
+                    code: Irep::nil(),
+                    source_location: location.clone(),
+                    instruction_type: 1,
+                    guard: guard.clone(),
+                    target_number: 0,
+                    targets: vec![1],
+                    labels: vec![],
+                },
+                // 1: END_FUNCTION
+                GotoInstruction {
+                    code: Irep::nil(),
+                    source_location: location.clone(),
+                    instruction_type: 9,
+                    guard: Irep::just_id(IrepId::True),
+                    target_number: 1,
+                    targets: vec![],
+                    labels: vec!["end".intern(), "foo".intern()],
+                },
+            ],
+        };
+
+        let mut vec: Vec<u8> = Vec::new();
+        {
+            let mut writer = BufWriter::new(&mut vec);
+            let mut serializer = GotoBinarySerializer::new(&mut writer);
+            serializer.write_file(&SymbolTable::new(), std::slice::from_ref(&function));
+        }
+
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(vec));
+        deserializer.read_header().unwrap();
+        deserializer.read_symbol_table().unwrap();
+        let functions = deserializer.read_function_map().unwrap();
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].name.string, function.name);
+        assert_eq!(functions[0].instructions.len(), function.instructions.len());
+        for (decoded, expected) in
+            functions[0].instructions.iter().zip(function.instructions.iter())
+        {
+            // Structurally identical ireps get the same number.
+            assert_eq!(decoded.code, deserializer.numbering.number_irep(&expected.code));
+            assert_eq!(
+                decoded.source_location,
+                deserializer.numbering.number_irep(&expected.source_location)
+            );
+            assert_eq!(decoded.guard, deserializer.numbering.number_irep(&expected.guard));
+            assert_eq!(decoded.instruction_type, expected.instruction_type);
+            assert_eq!(decoded.target_number, expected.target_number);
+            assert_eq!(decoded.targets, expected.targets);
+            let labels: Vec<InternedString> =
+                decoded.labels.iter().map(|label| label.string).collect();
+            assert_eq!(labels, expected.labels);
+        }
+    }
+
+    #[test]
+    /// Write GOTO function bodies to a file and read them back.
+    fn test_goto_functions_file_round_trip() {
+        use crate::goto_program::{Location, Symbol, SymbolTable, Type};
+        let mut symbol_table = SymbolTable::new(machine_model_test_stub());
+        symbol_table.insert(Symbol::variable("cond", "cond", Type::bool(), Location::none()));
+        let location =
+            Irep::just_named_sub(linear_map![(IrepId::File, Irep::just_string_id("main.rs"))]);
+        let function = GotoFunction {
+            name: "foo".intern(),
+            instructions: vec![GotoInstruction {
+                code: Irep::nil(),
+                source_location: location,
+                instruction_type: 9,
+                guard: make_symbol_expr("cond"),
+                target_number: 0,
+                targets: vec![],
+                labels: vec!["end".intern()],
+            }],
+        };
+
+        let path = std::env::temp_dir()
+            .join(format!("goto_functions_round_trip_{}.out", std::process::id()));
+        write_goto_binary_file_with_functions(
+            &path,
+            &symbol_table,
+            std::slice::from_ref(&function),
+        );
+        let functions = read_goto_binary_functions(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let expected = GotoFunction {
+            name: function.name,
+            instructions: function
+                .instructions
+                .iter()
+                .map(|instruction| GotoInstruction {
+                    code: to_freeform(&instruction.code),
+                    source_location: to_freeform(&instruction.source_location),
+                    guard: to_freeform(&instruction.guard),
+                    ..instruction.clone()
+                })
+                .collect(),
+        };
+        assert_eq!(functions, vec![expected]);
+    }
+
+    #[test]
+    /// Write and read back a symbol table, and check that the symbols are structurally equal.
+    fn test_write_read_symbol_table() {
+        let identifiers = vec!["foo", "bar", "baz", "foo", "bar", "baz"];
+        let typ =
+            Irep::just_id(IrepId::Signedbv).with_named_sub(IrepId::Width, Irep::just_int_id(32));
+        let mut symbol_table = SymbolTable::new();
+        symbol_table.insert(make_symbol("x", typ.clone(), Irep::nil()));
+        symbol_table.insert(Symbol {
+            is_static_lifetime: true,
+            is_lvalue: true,
+            is_weak: true,
+            is_volatile: true,
+            ..make_symbol("y", typ.clone(), fold_with_op(&identifiers, IrepId::Plus))
+        });
+        symbol_table.insert(Symbol {
+            is_type: true,
+            is_file_local: true,
+            ..make_symbol("tag-T", typ, fold_with_op(&identifiers, IrepId::And))
+        });
+
+        let mut vec: Vec<u8> = Vec::new();
+        {
+            let mut writer = BufWriter::new(&mut vec);
+            let mut serializer = GotoBinarySerializer::new(&mut writer);
+            serializer.write_file(&symbol_table, &[]);
+        }
+
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(vec));
+        deserializer.read_header().unwrap();
+        let decoded = deserializer.read_symbol_table().unwrap();
+        assert!(deserializer.read_function_map().unwrap().is_empty());
+
+        assert_eq!(decoded.symbol_table.len(), symbol_table.symbol_table.len());
+        for (name, expected) in symbol_table.symbol_table {
+            let expected = Symbol {
+                typ: to_freeform(&expected.typ),
+                value: to_freeform(&expected.value),
+                location: to_freeform(&expected.location),
+                ..expected
+            };
+            assert_eq!(decoded.symbol_table[&name], expected);
+        }
+    }
 }
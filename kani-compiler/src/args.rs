@@ -51,6 +51,10 @@ pub struct Arguments {
     /// Option name used to dump function pointer restrictions.
     #[clap(long = "restrict-vtable-fn-ptrs")]
     pub emit_vtable_restrictions: bool,
+    /// Option name used to restrict calls through function pointers to the reachable functions
+    /// with a matching signature.
+    #[clap(long = "restrict-fn-ptr-calls")]
+    pub emit_fn_ptr_restrictions: bool,
     /// Option name used to use json pretty-print for output files.
     #[clap(long = "pretty-json-files")]
     pub output_pretty_json: bool,
@@ -91,6 +95,45 @@ pub struct Arguments {
     /// Print the final LLBC file to stdout.
     #[clap(long)]
     pub print_llbc: bool,
+    /// Print the time spent to codegen the slowest functions.
+    #[clap(long)]
+    pub verbose_codegen_timing: bool,
+    /// Print statistics about the structural sharing of the generated symbol tables.
+    #[clap(long)]
+    pub print_symtab_stats: bool,
+    /// The GOTO binary version expected by the tools that will consume the generated goto binary.
+    /// Kani will fail if it cannot generate a goto binary in the requested version.
+    #[clap(long)]
+    pub goto_binary_version: Option<usize>,
+    /// Route allocations through the user's `#[global_allocator]` instead of modelling them with
+    /// CBMC's built-in allocation.
+    #[clap(long)]
+    pub verify_allocator: bool,
+    /// Stop after the harnesses have been collected and instrumented, without generating any
+    /// goto binary.
+    #[clap(long)]
+    pub check_only: bool,
+    /// Print the functions that are reachable from the harnesses whose name contains the given
+    /// string.
+    #[clap(long)]
+    pub dump_reachable_functions: Option<String>,
+    /// Print the call graph of the items that are reachable from each harness in DOT format.
+    #[clap(long)]
+    pub print_call_graph: bool,
+    /// Replace every function that contains an unsupported construct by a stub that returns a
+    /// nondeterministic value.
+    #[clap(long)]
+    pub stub_unsupported: bool,
+    /// Fail if the nondeterministic input of a harness exceeds the given number of bytes.
+    #[clap(long)]
+    pub max_nondet_bytes: Option<u64>,
+    /// Assume that the capacity requested by `Vec::with_capacity` is at most the given value.
+    #[clap(long)]
+    pub vec_max_capacity: Option<u64>,
+    /// Stub a function identified by its fully qualified path, in the format
+    /// `ORIGINAL=REPLACEMENT`.
+    #[arg(long = "stub-by-name", num_args(1))]
+    pub stub_by_name: Vec<String>,
     /// If we are running the autoharness subcommand, the functions to include
     #[arg(
         long = "autoharness-include-function",
@@ -18,7 +18,7 @@
 //! 7. `codegen_sanity` : `assert` but not normally displayed as failure would be a Kani bug
 //!
 
-use super::source_region::SourceRegion;
+use super::source_region::{CoverageMapping, SourceRegion};
 use crate::codegen_cprover_gotoc::GotocCtx;
 use cbmc::InternedString;
 use cbmc::goto_program::{Expr, Location, Stmt, Type};
@@ -40,6 +40,11 @@ pub enum PropertyClass {
     ///
     /// SPECIAL BEHAVIOR: Same as SafetyCheck. TODO: Replace this with `SafetyCheck`.
     ArithmeticOverflow,
+    /// Checks that a raw pointer is aligned before it is converted into a reference.
+    ///
+    /// SPECIAL BEHAVIOR: Same as SafetyCheck, but reported separately so misaligned accesses can be
+    /// told apart from other memory safety failures.
+    Alignment,
     /// The Rust `assume` instrinsic is `assert`'d by Kani, and gets this property class.
     ///
     /// SPECIAL BEHAVIOR: None? Possibly confusing to customers that a Rust assume is a Kani assert.
@@ -61,6 +66,12 @@ pub enum PropertyClass {
     ///
     /// SPECIAL BEHAVIOR: "Errors" for this type of assertion just mean "reachable" not failure.
     CodeCoverage,
+    /// Preconditions and postconditions of function contracts.
+    ///
+    /// SPECIAL BEHAVIOR: The description is prefixed with `[precondition] ` or
+    /// `[postcondition] `, which the driver reports separately. Satisfies `#[should_panic]`, like
+    /// `Assertion`.
+    Contract,
     /// Ordinary (Rust) assertions and panics.
     ///
     /// SPECIAL BEHAVIOR: These assertion failures should be observable during normal execution of Rust code.
@@ -93,6 +104,11 @@ pub enum PropertyClass {
     /// SPECIAL BEHAVIOR: Reachability of these assertions is notable, in order to measure Kani support.
     /// Also makes other properties UNDETERMINED.
     UnsupportedConstruct,
+    /// Emitted for every function that `--stub-unsupported` replaced by a nondeterministic stub.
+    ///
+    /// SPECIAL BEHAVIOR: Always succeeds. It only exists so that the stubbed functions are listed
+    /// in the verification results.
+    UnsupportedStub,
     /// When Rust determines code is unreachable, this is the `assert(false)` we emit.
     ///
     /// SPECIAL BEHAVIOR: Kinda should be a SanityCheck, except that we emit it also for
@@ -151,9 +167,10 @@ pub fn codegen_cover(&self, cond: Expr, msg: &str, span: SpanStable) -> Stmt {
         self.codegen_assert(cond.not(), PropertyClass::Cover, msg, loc)
     }
 
-    /// Generate a cover statement for code coverage reports.
+    /// Generate a cover statement for code coverage reports, and record the region it covers in
+    /// the coverage mappings.
     pub fn codegen_coverage(
-        &self,
+        &mut self,
         counter_data: &str,
         span: SpanStable,
         source_region: SourceRegion,
@@ -165,6 +182,11 @@ pub fn codegen_coverage(
         // https://github.com/diffblue/cbmc/issues/6613). So for now use
         // `assert(false)`.
         let msg = format!("{counter_data} - {file_name}:{source_region:?}");
+        self.coverage_mappings.push(CoverageMapping {
+            counter_data: counter_data.to_string(),
+            file_name: file_name.to_string(),
+            region: source_region,
+        });
         self.codegen_assert(Expr::bool_false(), PropertyClass::CodeCoverage, &msg, loc)
     }
 
@@ -177,7 +199,8 @@ pub fn codegen_coverage(
     /// property to allow the CBMC output parser to pair the property with its
     /// reachability check.
     /// If reachability checks are disabled, the function returns the message
-    /// unmodified and an empty (skip) statement.
+    /// unmodified and an empty (skip) statement. This applies to every user check
+    /// that has a reachability check, including covers.
     pub fn codegen_reachability_check(&mut self, msg: String, span: SpanStable) -> (String, Stmt) {
         let loc = self.codegen_caller_span_stable(span);
         if self.queries.args().check_assertion_reachability {
@@ -226,14 +249,22 @@ pub fn codegen_fatal_error(
     }
 
     /// Kani hooks function calls to `panic` and calls this intead.
-    pub fn codegen_panic(&self, span: SpanStable, fargs: Vec<Expr>) -> Stmt {
+    /// If the panic happens inside `catch_unwind`, it unwinds instead of failing.
+    pub fn codegen_panic(&mut self, span: SpanStable, fargs: Vec<Expr>) -> Stmt {
         // CBMC requires that the argument to the assertion must be a string constant.
         // If there is one in the MIR, use it; otherwise, explain that we can't.
         assert!(!fargs.is_empty(), "Panic requires a string message");
         let msg = self.extract_const_message(&fargs[0]).unwrap_or(String::from(
             "This is a placeholder message; Kani doesn't support message formatted at runtime",
         ));
-        self.codegen_fatal_error(PropertyClass::Assertion, &msg, span)
+        let loc = self.codegen_caller_span_stable(span);
+        Stmt::block(
+            vec![
+                self.codegen_start_unwind(loc),
+                self.codegen_fatal_error(PropertyClass::Assertion, &msg, span),
+            ],
+            loc,
+        )
     }
 
     /// Kani does not currently support all MIR constructs.
@@ -369,7 +400,7 @@ pub fn codegen_raw_ptr_deref_validity_check(
                 // Then generate an alignment check
                 let align_ok =
                     ptr.clone().cast_to(Type::size_t()).rem(align).eq(Type::size_t().zero());
-                let align_check = self.codegen_assert_assume(align_ok, PropertyClass::SafetyCheck,
+                let align_check = self.codegen_assert_assume(align_ok, PropertyClass::Alignment,
                     "misaligned pointer to reference cast: address must be a multiple of its type's \
                     alignment", *loc);
 
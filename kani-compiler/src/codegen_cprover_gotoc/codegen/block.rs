@@ -20,28 +20,19 @@ impl GotocCtx<'_> {
     pub fn codegen_block(&mut self, bb: BasicBlockIdx, bbd: &BasicBlock) {
         debug!(?bb, "codegen_block");
         let label = bb_label(bb);
-        // the first statement should be labelled. if there is no statements, then the
-        // terminator should be labelled.
-        match bbd.statements.len() {
-            0 => {
-                let term = &bbd.terminator;
-                let tcode = self.codegen_terminator(term);
-                self.current_fn_mut().push_onto_block(tcode.with_label(label));
-            }
-            _ => {
-                let stmt = &bbd.statements[0];
-                let scode = self.codegen_statement(stmt);
-                self.current_fn_mut().push_onto_block(scode.with_label(label));
+        let term = &bbd.terminator;
+        let loc = self.codegen_span_stable(term.span);
+        let mut stmts: Vec<_> = self.codegen_assumed_loop_head(bb, loc).into_iter().collect();
+        stmts.extend(bbd.statements.iter().map(|stmt| self.codegen_statement(stmt)));
+        stmts.extend(self.codegen_assumed_loop_edges(bb, &term.successors(), loc));
+        let tcode = self.codegen_terminator(term);
+        stmts.push(self.restrict_fn_ptr_call(bb, tcode));
 
-                for s in &bbd.statements[1..] {
-                    let stmt = self.codegen_statement(s);
-                    self.current_fn_mut().push_onto_block(stmt);
-                }
-                let term = &bbd.terminator;
-                let tcode = self.codegen_terminator(term);
-                self.current_fn_mut().push_onto_block(tcode);
-            }
-        }
+        // The first statement should be labelled.
+        let mut stmts = stmts.into_iter();
+        let first = stmts.next().unwrap();
+        self.current_fn_mut().push_onto_block(first.with_label(label));
+        stmts.for_each(|stmt| self.current_fn_mut().push_onto_block(stmt));
     }
 }
 
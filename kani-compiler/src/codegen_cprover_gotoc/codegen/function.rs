@@ -4,9 +4,12 @@
 //! This file contains functions related to codegenning MIR functions into gotoc
 
 use crate::codegen_cprover_gotoc::GotocCtx;
+use crate::codegen_cprover_gotoc::codegen::PropertyClass;
 use crate::codegen_cprover_gotoc::codegen::block::reverse_postorder;
-use cbmc::InternString;
-use cbmc::goto_program::{Expr, Stmt, Symbol};
+use crate::kani_middle::attributes::is_proof_harness;
+use cbmc::goto_program::{Expr, Location, Stmt, Symbol, Type};
+use cbmc::{InternString, InternedString};
+use rustc_data_structures::fx::FxHashMap;
 use stable_mir::CrateDef;
 use stable_mir::mir::mono::Instance;
 use stable_mir::mir::{Body, Local};
@@ -61,22 +64,103 @@ pub fn codegen_function(&mut self, instance: Instance) {
         } else {
             assert!(old_sym.is_function());
             let body = self.transformer.body(self.tcx, instance);
+            // Keep track of the unsupported constructs found so far, so we can tell whether this
+            // function has any.
+            let unsupported_snapshot = self.can_stub_unsupported(instance).then(|| {
+                self.unsupported_constructs
+                    .iter()
+                    .map(|(construct, locations)| (*construct, locations.len()))
+                    .collect::<FxHashMap<_, _>>()
+            });
             self.set_current_fn(instance, &body);
             self.print_instance(instance, &body);
             self.codegen_function_prelude(&body);
             self.codegen_declare_variables(&body);
+            self.codegen_declare_assumed_loops(instance, &body);
 
             // Get the order from internal body for now.
             reverse_postorder(&body).for_each(|bb| self.codegen_block(bb, &body.blocks[bb]));
 
             let loc = self.codegen_span_stable(instance.def.span());
-            let stmts = self.current_fn_mut().extract_block();
+            let mut stmts = self.current_fn_mut().extract_block();
+            if let Some(snapshot) = unsupported_snapshot {
+                if self.remove_new_unsupported_constructs(&snapshot) {
+                    stmts = vec![self.codegen_unsupported_stub(instance, loc)];
+                }
+            }
             let goto_body = Stmt::block(stmts, loc);
             self.symbol_table.update_fn_declaration_with_definition(&name, goto_body);
             self.reset_current_fn();
         }
     }
 
+    /// Whether we should replace this function by a stub in case it contains unsupported
+    /// constructs.
+    ///
+    /// This is only enabled with `--stub-unsupported`. We never stub harnesses, since that would
+    /// make their verification vacuous, nor diverging functions, since there is no value to
+    /// return.
+    ///
+    /// We also don't stub functions whose return type has validity invariants (e.g.: references,
+    /// `char`, `NonZero`, or enums), since an unconstrained value of that type could be invalid.
+    /// Those are reported as unsupported constructs instead.
+    fn can_stub_unsupported(&self, instance: Instance) -> bool {
+        if !self.queries.args().stub_unsupported || is_proof_harness(self.tcx, instance) {
+            return false;
+        }
+        let ret_ty = instance.fn_abi().unwrap().ret.ty;
+        !matches!(ret_ty.kind(), TyKind::RigidTy(RigidTy::Never))
+            && !self.has_validity_invariant(ret_ty)
+    }
+
+    /// Remove the unsupported constructs that were found after the given snapshot was taken, and
+    /// return whether there was any.
+    fn remove_new_unsupported_constructs(
+        &mut self,
+        snapshot: &FxHashMap<InternedString, usize>,
+    ) -> bool {
+        let mut found = false;
+        self.unsupported_constructs.retain(|construct, locations| {
+            let len = snapshot.get(construct).copied().unwrap_or_default();
+            found |= locations.len() > len;
+            locations.truncate(len);
+            len > 0
+        });
+        found
+    }
+
+    /// Generate a stub body that returns a nondeterministic value for a function that contains
+    /// unsupported constructs.
+    ///
+    /// The stub also includes a property that always succeeds, so every stubbed function is
+    /// listed in the verification results.
+    ///
+    /// Note that the value returned is unconstrained, which is only valid because the return type
+    /// has no validity invariant (see [Self::can_stub_unsupported]). Any side effect the original
+    /// function may have through its arguments (e.g. writes through a pointer) is not modeled.
+    fn codegen_unsupported_stub(&mut self, instance: Instance, loc: Location) -> Stmt {
+        debug!(name=?instance.name(), "codegen_unsupported_stub");
+        let name = instance.name();
+        let msg = format!(
+            "`{name}` contains unsupported constructs and was replaced by a nondeterministic stub"
+        );
+        let report =
+            self.codegen_assert(Expr::bool_true(), PropertyClass::UnsupportedStub, &msg, loc);
+        self.auto_stubbed.push(name);
+        let rty = instance.fn_abi().unwrap().ret.ty;
+        let ret = if rty.kind().is_unit() {
+            self.codegen_ret_unit(loc)
+        } else {
+            let value = self.codegen_ty_stable(rty).nondet();
+            if rty.kind().is_bool() {
+                value.cast_to(Type::c_bool()).ret(loc)
+            } else {
+                value.ret(loc)
+            }
+        };
+        Stmt::block(vec![report, ret], loc)
+    }
+
     /// Codegen changes required due to the function ABI.
     /// We currently untuple arguments for RustCall ABI where the `spread_arg` is set.
     fn codegen_function_prelude(&mut self, body: &Body) {
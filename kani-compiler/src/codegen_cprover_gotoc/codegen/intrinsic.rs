@@ -333,6 +333,7 @@ macro_rules! unstable_codegen {
             Intrinsic::Bswap => {
                 self.codegen_expr_to_place_stable(place, fargs.remove(0).bswap(), loc)
             }
+            Intrinsic::CatchUnwind => self.codegen_catch_unwind(fargs, place, loc),
             Intrinsic::CeilF32 => codegen_simple_intrinsic!(Ceilf),
             Intrinsic::CeilF64 => codegen_simple_intrinsic!(Ceil),
             Intrinsic::CompareBytes => self.codegen_compare_bytes(fargs, place, loc),
@@ -525,10 +526,21 @@ macro_rules! unstable_codegen {
             Intrinsic::VolatileCopyNonOverlappingMemory => {
                 unstable_codegen!(codegen_intrinsic_copy!(Memcpy))
             }
-            Intrinsic::VolatileLoad => self.codegen_volatile_load(fargs, farg_types, place, loc),
+            Intrinsic::ReadViaCopy => {
+                self.codegen_volatile_load(fargs, farg_types, place, false, loc)
+            }
+            Intrinsic::VolatileLoad => {
+                let havoc = self.is_volatile_nondet_enabled();
+                self.codegen_volatile_load(fargs, farg_types, place, havoc, loc)
+            }
             Intrinsic::VolatileStore => {
                 assert!(self.place_ty_stable(place).kind().is_unit());
-                self.codegen_volatile_store(fargs, farg_types, loc)
+                let havoc = self.is_volatile_nondet_enabled();
+                self.codegen_volatile_store(fargs, farg_types, havoc, loc)
+            }
+            Intrinsic::WriteViaMove => {
+                assert!(self.place_ty_stable(place).kind().is_unit());
+                self.codegen_volatile_store(fargs, farg_types, false, loc)
             }
             Intrinsic::VtableSize => self.vtable_info(VTableInfo::Size, fargs, place, loc),
             Intrinsic::VtableAlign => self.vtable_info(VTableInfo::Align, fargs, place, loc),
@@ -1660,6 +1672,9 @@ fn codegen_intrinsic_simd_shuffle(
     /// A volatile load of a memory location:
     /// <https://doc.rust-lang.org/std/ptr/fn.read_volatile.html>
     ///
+    /// This is also used for `read_via_copy`, which `ptr::read` may be implemented with, since
+    /// both have the same requirements.
+    ///
     /// Undefined behavior if any of these conditions are violated:
     ///  * `src` must be valid for writes (done by `--pointer-check`)
     ///  * `src` must be properly aligned (done by `align_check` below)
@@ -1667,11 +1682,17 @@ fn codegen_intrinsic_simd_shuffle(
     /// TODO: Add a check for the condition:
     ///  * `src` must point to a properly initialized value of type `T`
     ///    See <https://github.com/model-checking/kani/issues/920> for more details
+    ///
+    /// If `havoc` is set, the value read is nondeterministic, since the memory may have been
+    /// modified by something outside of the program (e.g., memory-mapped I/O). The memory is
+    /// still dereferenced, so the pointer checks are the same. Havocking is only supported for
+    /// types without validity invariants, since an unconstrained value could be invalid.
     fn codegen_volatile_load(
         &mut self,
         mut fargs: Vec<Expr>,
         farg_types: &[Ty],
         p: &Place,
+        havoc: bool,
         loc: Location,
     ) -> Stmt {
         let src = fargs.remove(0);
@@ -1684,20 +1705,45 @@ fn codegen_volatile_load(
             loc,
         );
         let expr = src.dereference();
-        let res_stmt = self.codegen_expr_to_place_stable(p, expr, loc);
+        let pointee_ty = pointee_type_stable(src_typ).unwrap();
+        let res_stmt = if havoc && self.has_validity_invariant(pointee_ty) {
+            self.codegen_unimplemented_stmt(
+                "Nondeterministic volatile load of a type with validity invariants",
+                loc,
+                "https://github.com/model-checking/kani/issues/new/choose",
+            )
+        } else if havoc {
+            Stmt::block(vec![expr.as_stmt(loc), self.codegen_havoc(p, loc)], loc)
+        } else {
+            self.codegen_expr_to_place_stable(p, expr, loc)
+        };
         Stmt::block(vec![align_check, res_stmt], loc)
     }
 
+    /// Whether volatile accesses should be modeled as interacting with memory that can change
+    /// outside of the program.
+    fn is_volatile_nondet_enabled(&self) -> bool {
+        self.queries.args().unstable_features.contains(&"volatile-nondet".to_string())
+    }
+
     /// A volatile write of a memory location:
     /// <https://doc.rust-lang.org/std/ptr/fn.write_volatile.html>
     ///
+    /// This is also used for `write_via_move`, which `ptr::write` may be implemented with, since
+    /// both have the same requirements.
+    ///
     /// Undefined behavior if any of these conditions are violated:
     ///  * `dst` must be valid for writes (done by `--pointer-check`)
     ///  * `dst` must be properly aligned (done by `align_check` below)
+    ///
+    /// If `havoc` is set, the memory is havocked after the write, since it may be modified by
+    /// something outside of the program (e.g., memory-mapped I/O). Havocking is only supported
+    /// for types without validity invariants, since an unconstrained value could be invalid.
     fn codegen_volatile_store(
         &mut self,
         mut fargs: Vec<Expr>,
         farg_types: &[Ty],
+        havoc: bool,
         loc: Location,
     ) -> Stmt {
         let dst = fargs.remove(0);
@@ -1710,12 +1756,23 @@ fn codegen_volatile_store(
             "`dst` must be properly aligned",
             loc,
         );
-        if self.is_zst_stable(pointee_type_stable(dst_typ).unwrap()) {
+        let pointee_ty = pointee_type_stable(dst_typ).unwrap();
+        if self.is_zst_stable(pointee_ty) {
             // do not attempt to dereference (and assign) a ZST
             align_check
         } else {
-            let expr = dst.dereference().assign(src, loc);
-            Stmt::block(vec![align_check, expr], loc)
+            let target = dst.dereference();
+            let mut stmts = vec![align_check, target.clone().assign(src, loc)];
+            if havoc && self.has_validity_invariant(pointee_ty) {
+                stmts.push(self.codegen_unimplemented_stmt(
+                    "Nondeterministic volatile store of a type with validity invariants",
+                    loc,
+                    "https://github.com/model-checking/kani/issues/new/choose",
+                ));
+            } else if havoc {
+                stmts.push(self.codegen_havoc_expr(target, pointee_ty, loc));
+            }
+            Stmt::block(stmts, loc)
         }
     }
 
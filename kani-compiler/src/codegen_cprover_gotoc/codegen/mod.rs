@@ -16,6 +16,8 @@
 mod span;
 mod statement;
 mod static_var;
+mod unwind;
+mod unwind_assume;
 
 // Visible for all codegen module.
 pub mod contract;
@@ -24,4 +26,6 @@
 
 pub use assert::PropertyClass;
 pub use block::bb_label;
+pub use source_region::CoverageMapping;
 pub use typ::TypeExt;
+pub use unwind_assume::AssumedLoopHead;
@@ -11,6 +11,7 @@
 use crate::codegen_cprover_gotoc::codegen::typ::std_pointee_type;
 use crate::codegen_cprover_gotoc::utils::{dynamic_fat_ptr, slice_fat_ptr};
 use crate::unwrap_or_return_codegen_unimplemented;
+use crate::unwrap_or_return_codegen_unimplemented_stmt;
 use cbmc::goto_program::{Expr, ExprValue, Location, Stmt, Type};
 use rustc_abi::{TagEncoding, Variants};
 use rustc_middle::ty::layout::LayoutOf;
@@ -744,6 +745,44 @@ pub fn codegen_place_stable(
         }
     }
 
+    /// Generate a statement that overwrites `place` with a nondeterministic value of its type,
+    /// i.e., the previous contents of the place are forgotten.
+    ///
+    /// Assigning a nondet value to an aggregate havocs all of its fields, and a fat pointer is
+    /// havocked together with its metadata. Havocking a ZST is a no-op.
+    /// Unsized places, such as `*slice`, cannot be assigned as a whole, so they are unsupported.
+    pub fn codegen_havoc(&mut self, place: &Place, loc: Location) -> Stmt {
+        debug!(?place, "codegen_havoc");
+        let place_ty = self.place_ty_stable(place);
+        if self.is_zst_stable(place_ty) {
+            return Stmt::skip(loc);
+        }
+        let place_expr = unwrap_or_return_codegen_unimplemented_stmt!(
+            self,
+            self.codegen_place_stable(place, loc)
+        )
+        .goto_expr;
+        self.codegen_havoc_expr(place_expr, place_ty, loc)
+    }
+
+    /// Generate a statement that overwrites the lvalue `target` of type `ty` with a
+    /// nondeterministic value. See [Self::codegen_havoc] for more details.
+    pub fn codegen_havoc_expr(&mut self, target: Expr, ty: Ty, loc: Location) -> Stmt {
+        debug!(?target, ?ty, "codegen_havoc_expr");
+        if self.is_zst_stable(ty) {
+            return Stmt::skip(loc);
+        }
+        if ty.layout().unwrap().shape().is_unsized() {
+            return self.codegen_unimplemented_stmt(
+                "havoc of an unsized place",
+                loc,
+                "https://github.com/model-checking/kani/issues/new/choose",
+            );
+        }
+        let value = self.codegen_ty_stable(ty).nondet();
+        target.assign(value, loc)
+    }
+
     /// Given a projection, generate an lvalue that represents the given variant index.
     pub fn codegen_variant_lvalue(
         &mut self,
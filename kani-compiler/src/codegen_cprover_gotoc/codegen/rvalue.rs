@@ -161,10 +161,19 @@ fn codegen_scalar_binop(&mut self, op: &BinOp, e1: &Operand, e2: &Operand) -> Ex
     }
 
     /// Codegens expressions of the type `let a  = [4u8; 6];`
+    ///
+    /// The operand is always a place or a constant, since MIR evaluates any side effect of the
+    /// repeated expression in a previous statement. For `N == 0`, we generate an empty array
+    /// literal instead of an `array_of` with no elements.
     fn codegen_rvalue_repeat(&mut self, op: &Operand, sz: &TyConst, loc: Location) -> Expr {
         let op_expr = self.codegen_operand_stable(op);
-        let width = sz.eval_target_usize().unwrap();
-        op_expr.array_constant(width).with_location(loc)
+        let width =
+            sz.eval_target_usize().expect("array length should be known after monomorphization");
+        if width == 0 {
+            Expr::array_expr(op_expr.typ().clone().array_of(0), vec![]).with_location(loc)
+        } else {
+            op_expr.array_constant(width).with_location(loc)
+        }
     }
 
     fn codegen_rvalue_len(&mut self, p: &Place, loc: Location) -> Expr {
@@ -405,15 +414,15 @@ fn codegen_rvalue_binary_op(
             }
             // https://doc.rust-lang.org/std/primitive.pointer.html#method.offset
             BinOp::Offset => {
-                // We don't need to check for UB since we handled user calls of offset already
-                // via rustc_intrinsic transformation pass.
-                //
-                // This operation may still be used by other Kani instrumentation which should
-                // ensure safety.
-                // We should consider adding sanity checks if `debug_assertions` are enabled.
+                // We don't need to check for UB since every offset in user code was already
+                // replaced by a model that checks for UB in the rustc_intrinsic transformation
+                // pass. That pass runs before the instrumentation passes, so the offsets that
+                // reach this point were added by Kani's instrumentation, which computes offsets
+                // within the layout of a value. Checking those would only add properties that
+                // can never fail.
                 let ce1 = self.codegen_operand_stable(e1);
                 let ce2 = self.codegen_operand_stable(e2);
-                ce1.clone().plus(ce2)
+                ce1.plus(ce2)
             }
         }
     }
@@ -529,14 +538,27 @@ fn codegen_rvalue_coroutine(&mut self, operands: &[Operand], ty: Ty) -> Expr {
     }
 
     /// This code will generate an expression that initializes an enumeration.
+    fn codegen_rvalue_enum_aggregate(
+        &mut self,
+        variant_index: VariantIdx,
+        operands: &[Operand],
+        res_ty: Ty,
+        loc: Location,
+    ) -> Expr {
+        let values = operands.iter().map(|op| self.codegen_operand_stable(op)).collect();
+        self.codegen_enum_value(variant_index, values, res_ty, loc)
+    }
+
+    /// Generate an expression for the variant `variant_index` of the enum `res_ty`, where
+    /// `values` contains the value of each field of the variant, in declaration order.
     ///
     /// It will first create a temporary variant with the same enum type.
     /// Initialize the case structure and set its discriminant.
     /// Finally, it will return the temporary value.
-    fn codegen_rvalue_enum_aggregate(
+    pub fn codegen_enum_value(
         &mut self,
         variant_index: VariantIdx,
-        operands: &[Operand],
+        values: Vec<Expr>,
         res_ty: Ty,
         loc: Location,
     ) -> Expr {
@@ -546,7 +568,7 @@ fn codegen_rvalue_enum_aggregate(
         tracing::debug!(?typ, ?res_ty, "aggregate_enum");
         let (temp_var, decl) = self.decl_temp_variable(typ.clone(), None, loc);
         stmts.push(decl);
-        if !operands.is_empty() {
+        if !values.is_empty() {
             // 2- Initialize the members of the temporary variant.
             let initial_projection =
                 ProjectedPlace::try_from_ty(temp_var.clone(), res_ty, self).unwrap();
@@ -572,13 +594,10 @@ fn codegen_rvalue_enum_aggregate(
                 }
             };
 
-            trace!(?variant_expr, ?fields, ?operands, "codegen_aggregate enum");
+            trace!(?variant_expr, ?fields, ?values, "codegen_aggregate enum");
             let init_struct = Expr::struct_expr_from_values(
                 variant_expr.typ().clone(),
-                fields
-                    .index_by_increasing_offset()
-                    .map(|idx| self.codegen_operand_stable(&operands[idx]))
-                    .collect(),
+                fields.index_by_increasing_offset().map(|idx| values[idx].clone()).collect(),
                 &self.symbol_table,
             );
             let assign_case = variant_proj.goto_expr.assign(init_struct, loc);
@@ -782,12 +801,28 @@ pub fn codegen_rvalue_stable(&mut self, rv: &Rvalue, loc: Location) -> Expr {
                 | CastKind::FloatToInt
                 | CastKind::IntToFloat
                 | CastKind::FnPtrToPtr
-                | CastKind::PtrToPtr
-                | CastKind::PointerExposeAddress
-                | CastKind::PointerWithExposedProvenance,
+                | CastKind::PtrToPtr,
                 e,
                 t,
             ) => self.codegen_misc_cast(e, *t),
+            // `ptr.expose_provenance()` and `ptr::with_exposed_provenance(addr)` are modeled as
+            // plain casts between the pointer and its address. CBMC keeps the object an address
+            // points to, so a pointer that is exposed and then re-derived can still be used.
+            Rvalue::Cast(CastKind::PointerExposeAddress, e, t) => self.codegen_misc_cast(e, *t),
+            // We do not check that the provenance of the re-derived pointer was exposed. Since
+            // that is a safety requirement, we add a `SafetyCheck` that always succeeds, so the
+            // results show that it was not checked.
+            Rvalue::Cast(CastKind::PointerWithExposedProvenance, e, t) => {
+                let cast = self.codegen_misc_cast(e, *t);
+                let cast_typ = cast.typ().clone();
+                let note = self.codegen_assert(
+                    Expr::bool_true(),
+                    PropertyClass::SafetyCheck,
+                    "provenance of pointers created from exposed addresses is not tracked",
+                    loc,
+                );
+                Expr::statement_expression(vec![note, cast.as_stmt(loc)], cast_typ, loc)
+            }
             Rvalue::Cast(CastKind::DynStar, _, _) => {
                 let ty = self.codegen_ty_stable(res_ty);
                 self.codegen_unimplemented_expr(
@@ -1021,10 +1056,26 @@ pub fn codegen_get_discriminant(&mut self, e: Expr, ty: Ty, res_ty: Ty) -> Expr
                     let discr_mir_ty = self.codegen_enum_discr_typ_stable(ty);
                     let discr_type = self.codegen_ty_stable(discr_mir_ty);
                     let niche_val = self.codegen_get_niche(e, offset.bytes() as usize, discr_type);
-                    let relative_discr =
-                        wrapping_sub(&niche_val, u64::try_from(*niche_start).unwrap());
                     let relative_max =
                         niche_variants.end().as_u32() - niche_variants.start().as_u32();
+                    if niche_val.typ().is_pointer() {
+                        // The only niche of a pointer is null, e.g., `None` in `Option<&T>`.
+                        // Compare the pointer against null instead of doing integer arithmetic
+                        // on it. For fat pointers, the niche is the data pointer, which is the
+                        // field at `offset`.
+                        assert_eq!(*niche_start, 0, "unexpected pointer niche: {niche_start}");
+                        assert_eq!(relative_max, 0, "unexpected pointer niche variants");
+                        let is_null = niche_val.clone().eq(niche_val.typ().null());
+                        return is_null.ternary(
+                            Expr::int_constant(
+                                niche_variants.start().as_u32(),
+                                result_type.clone(),
+                            ),
+                            Expr::int_constant(untagged_variant.as_u32(), result_type),
+                        );
+                    }
+                    let relative_discr =
+                        wrapping_sub(&niche_val, u64::try_from(*niche_start).unwrap());
                     let is_niche = if relative_max == 0 {
                         relative_discr.clone().is_zero()
                     } else {
@@ -1265,7 +1316,7 @@ fn codegen_pointer_cast(
     /// -> All the cases above where the pointer refers to a parametrized struct where the type
     /// parameter is the target of the unsize casting.
     ///     - E.g.: `RcBox<String>` to `RcBox<dyn Any>`
-    fn codegen_unsized_cast(
+    pub(crate) fn codegen_unsized_cast(
         &mut self,
         src_goto_expr: Expr,
         src_mir_type: Ty,
@@ -1684,12 +1735,8 @@ fn comparison_expr(
 /// where "-" is wrapping subtraction, i.e., the result should be interpreted as
 /// an unsigned value (2's complement).
 fn wrapping_sub(expr: &Expr, constant: u64) -> Expr {
-    let unsigned_expr = if expr.typ().is_pointer() {
-        expr.clone()
-    } else {
-        let unsigned = expr.typ().to_unsigned().unwrap();
-        expr.clone().cast_to(unsigned)
-    };
+    let unsigned = expr.typ().to_unsigned().unwrap();
+    let unsigned_expr = expr.clone().cast_to(unsigned);
     if constant == 0 {
         // No need to subtract.
         // But we still need to make sure we return an unsigned value.
@@ -9,10 +9,11 @@
 use rustc_span::Span;
 use rustc_span::source_map::SourceMap;
 use rustc_span::{BytePos, SourceFile};
+use serde::Serialize;
 use std::fmt::{self, Debug, Formatter};
 use tracing::debug;
 
-#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct SourceRegion {
     pub start_line: u32,
     pub start_col: u32,
@@ -27,6 +28,18 @@ fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// The source region covered by a code coverage check.
+///
+/// These are collected during codegen and written to the coverage mappings artifact, so external
+/// tools can map coverage checks to source regions without parsing the property descriptions.
+#[derive(Clone, Debug, Serialize)]
+pub struct CoverageMapping {
+    /// The counter data of the check, which is also the prefix of the property description.
+    pub counter_data: String,
+    pub file_name: String,
+    pub region: SourceRegion,
+}
+
 fn ensure_non_empty_span(
     source_map: &SourceMap,
     fn_cov_info: &FunctionCoverageInfo,
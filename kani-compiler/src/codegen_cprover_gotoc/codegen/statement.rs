@@ -216,25 +216,7 @@ pub fn codegen_terminator(&mut self, term: &Terminator) -> Stmt {
                 loc,
                 "https://github.com/model-checking/kani/issues/692",
             ),
-            TerminatorKind::Return => {
-                let rty = self.current_fn().instance_stable().fn_abi().unwrap().ret.ty;
-                if rty.kind().is_unit() {
-                    self.codegen_ret_unit(loc)
-                } else {
-                    let place = Place::from(RETURN_LOCAL);
-                    let place_expr = unwrap_or_return_codegen_unimplemented_stmt!(
-                        self,
-                        self.codegen_place_stable(&place, loc)
-                    )
-                    .goto_expr;
-                    assert_eq!(rty, self.place_ty_stable(&place), "Unexpected return type");
-                    if rty.kind().is_bool() {
-                        place_expr.cast_to(Type::c_bool()).ret(loc)
-                    } else {
-                        place_expr.ret(loc)
-                    }
-                }
-            }
+            TerminatorKind::Return => self.codegen_return(loc),
             TerminatorKind::Unreachable => self.codegen_assert_assume_false(
                 PropertyClass::Unreachable,
                 "unreachable code",
@@ -288,9 +270,22 @@ pub fn codegen_terminator(&mut self, term: &Terminator) -> Stmt {
                 let (msg_str, reach_stmt) =
                     self.codegen_reachability_check(msg.to_owned(), term.span);
 
+                // Only panics can unwind, not the safety checks.
+                let unwind_stmt = if property_class == PropertyClass::Assertion {
+                    Stmt::if_then_else(
+                        cond.clone().cast_to(Type::bool()).not(),
+                        self.codegen_start_unwind(loc),
+                        None,
+                        loc,
+                    )
+                } else {
+                    Stmt::skip(loc)
+                };
+
                 Stmt::block(
                     vec![
                         reach_stmt,
+                        unwind_stmt,
                         self.codegen_assert_assume(
                             cond.cast_to(Type::bool()),
                             property_class,
@@ -302,6 +297,9 @@ pub fn codegen_terminator(&mut self, term: &Terminator) -> Stmt {
                     loc,
                 )
             }
+            // We don't model the effects of inline assembly, so we block every path that reaches it.
+            // Any property that is checked after the asm on the same path is reported as
+            // UNDETERMINED instead of SUCCESS, even if the asm has no observable effect on it.
             TerminatorKind::InlineAsm { .. } => self.codegen_unimplemented_stmt(
                 "TerminatorKind::InlineAsm",
                 loc,
@@ -401,8 +399,29 @@ fn codegen_deinit(&mut self, place: &Place, loc: Location) -> Stmt {
         }
     }
 
+    /// Generate code to return the value stored in the return local of the current function.
+    pub(crate) fn codegen_return(&mut self, loc: Location) -> Stmt {
+        let rty = self.current_fn().instance_stable().fn_abi().unwrap().ret.ty;
+        if rty.kind().is_unit() {
+            self.codegen_ret_unit(loc)
+        } else {
+            let place = Place::from(RETURN_LOCAL);
+            let place_expr = unwrap_or_return_codegen_unimplemented_stmt!(
+                self,
+                self.codegen_place_stable(&place, loc)
+            )
+            .goto_expr;
+            assert_eq!(rty, self.place_ty_stable(&place), "Unexpected return type");
+            if rty.kind().is_bool() {
+                place_expr.cast_to(Type::c_bool()).ret(loc)
+            } else {
+                place_expr.ret(loc)
+            }
+        }
+    }
+
     /// A special case handler to codegen `return ();`
-    fn codegen_ret_unit(&mut self, loc: Location) -> Stmt {
+    pub(crate) fn codegen_ret_unit(&mut self, loc: Location) -> Stmt {
         let is_file_local = false;
         let ty = self.codegen_ty_unit();
         let var = self.ensure_global_var(FN_RETURN_VOID_VAR_NAME, is_file_local, ty, loc);
@@ -474,7 +493,7 @@ fn codegen_drop(&mut self, place: &Place, target: &BasicBlockIdx, loc: Location)
             ),
         };
         let goto_target = Stmt::goto(bb_label(*target), loc);
-        let block = vec![drop_implementation, goto_target];
+        let block = vec![drop_implementation, self.codegen_propagate_unwind(loc), goto_target];
         Stmt::block(block, loc)
     }
 
@@ -552,11 +571,17 @@ fn codegen_untupled_args(&mut self, op: &Operand, args_abi: &[ArgAbi]) -> Vec<Ex
 
     /// Because function calls terminate basic blocks, to "end" a function call, we
     /// must jump to the next basic block.
-    fn codegen_end_call(&self, target: Option<BasicBlockIdx>, loc: Location) -> Stmt {
-        if let Some(next_bb) = target {
+    /// If the callee is unwinding, return from the current function instead.
+    fn codegen_end_call(&mut self, target: Option<BasicBlockIdx>, loc: Location) -> Stmt {
+        let end = if let Some(next_bb) = target {
             Stmt::goto(bb_label(next_bb), loc)
         } else {
             self.codegen_sanity(Expr::bool_false(), "Unexpected return from Never function", loc)
+        };
+        if self.is_catch_unwind_enabled() {
+            Stmt::block(vec![self.codegen_propagate_unwind(loc), end], loc)
+        } else {
+            end
         }
     }
 
@@ -695,6 +720,7 @@ fn codegen_funcall(
                 Stmt::block(
                     vec![
                         self.codegen_expr_to_place_stable(destination, func_expr.call(fargs), loc),
+                        self.codegen_propagate_unwind(loc),
                         Stmt::goto(bb_label(target.unwrap()), loc),
                     ],
                     loc,
@@ -8,11 +8,13 @@
 
 use crate::codegen_cprover_gotoc::GotocCtx;
 use crate::kani_middle::abi::LayoutOf;
+use crate::kani_middle::transform::check_values::ty_validity_per_offset;
 use cbmc::goto_program::Type;
 use rustc_middle::ty::layout::{LayoutOf as _, TyAndLayout};
 use rustc_smir::rustc_internal;
 use stable_mir::mir::mono::Instance;
 use stable_mir::mir::{Local, Operand, Place, Rvalue};
+use stable_mir::target::MachineInfo;
 use stable_mir::ty::{FnSig, RigidTy, Ty, TyKind};
 
 impl<'tcx> GotocCtx<'tcx> {
@@ -40,6 +42,16 @@ pub fn is_zst_stable(&self, ty: Ty) -> bool {
         LayoutOf::new(ty).is_zst()
     }
 
+    /// Whether some values of the given type are invalid, i.e., whether a nondeterministic value
+    /// of this type must be constrained before it can be used. Types whose validity requirements
+    /// are not supported are conservatively considered to have an invariant.
+    pub fn has_validity_invariant(&self, ty: Ty) -> bool {
+        !matches!(
+            ty_validity_per_offset(self.tcx, &MachineInfo::target(), ty, 0),
+            Ok(requirements) if requirements.is_empty()
+        )
+    }
+
     pub fn layout_of_stable(&self, ty: Ty) -> TyAndLayout<'tcx> {
         self.layout_of(rustc_internal::internal(self.tcx, ty))
     }
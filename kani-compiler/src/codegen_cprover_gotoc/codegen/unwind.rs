@@ -0,0 +1,134 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module models unwinding for panics that happen inside a call to `catch_unwind`.
+//!
+//! Kani compiles code with `panic=abort`, so there are no cleanup blocks that we could use to
+//! model the unwinding process. Instead, when the `catch-unwind` unstable feature is enabled, we
+//! keep track of how many `catch_unwind` calls are currently active and whether we are unwinding:
+//!
+//! - A panic inside a `catch_unwind` sets the unwinding flag and returns from the current function.
+//! - Every function call is followed by a check that returns from the caller if the flag is set.
+//! - The `catch_unwind` intrinsic clears the flag, invokes the catch function, and returns `1`.
+//!
+//! Panics outside of any `catch_unwind` remain fatal failures. Destructors are not executed while
+//! unwinding, and the payload of the panic is an opaque `Box<dyn Any + Send>`.
+
+use crate::codegen_cprover_gotoc::GotocCtx;
+use cbmc::goto_program::{Expr, Location, Stmt, Type};
+use stable_mir::mir::Place;
+
+/// Global variable that keeps track of the number of active `catch_unwind` calls.
+const CATCH_UNWIND_DEPTH_VAR_NAME: &str = "__kani_catch_unwind_depth";
+
+/// Global variable that is set while a panic is unwinding to the closest `catch_unwind`.
+const UNWINDING_VAR_NAME: &str = "__kani_unwinding";
+
+impl GotocCtx<'_> {
+    /// Whether panics inside `catch_unwind` should be modeled.
+    pub fn is_catch_unwind_enabled(&self) -> bool {
+        self.queries.args().unstable_features.contains(&"catch-unwind".to_string())
+    }
+
+    fn catch_unwind_depth(&mut self, loc: Location) -> Expr {
+        self.ensure_global_var_init(
+            CATCH_UNWIND_DEPTH_VAR_NAME,
+            false,
+            false,
+            Type::size_t(),
+            loc,
+            |_, _| Expr::int_constant(0, Type::size_t()),
+        )
+        .to_expr()
+    }
+
+    fn unwinding_flag(&mut self, loc: Location) -> Expr {
+        self.ensure_global_var_init(UNWINDING_VAR_NAME, false, false, Type::bool(), loc, |_, _| {
+            Expr::bool_false()
+        })
+        .to_expr()
+    }
+
+    /// Start unwinding if the panic happens inside a `catch_unwind`.
+    ///
+    /// This should precede the fatal error generated for a panic, which is only reached if there
+    /// is no `catch_unwind` to unwind to.
+    pub fn codegen_start_unwind(&mut self, loc: Location) -> Stmt {
+        if !self.is_catch_unwind_enabled() {
+            return Stmt::skip(loc);
+        }
+        let depth = self.catch_unwind_depth(loc);
+        let unwinding = self.unwinding_flag(loc);
+        Stmt::if_then_else(
+            depth.gt(Expr::int_constant(0, Type::size_t())),
+            Stmt::block(
+                vec![unwinding.assign(Expr::bool_true(), loc), self.codegen_return(loc)],
+                loc,
+            ),
+            None,
+            loc,
+        )
+    }
+
+    /// Return from the current function if the callee is unwinding.
+    pub fn codegen_propagate_unwind(&mut self, loc: Location) -> Stmt {
+        if !self.is_catch_unwind_enabled() {
+            return Stmt::skip(loc);
+        }
+        let unwinding = self.unwinding_flag(loc);
+        Stmt::if_then_else(unwinding, self.codegen_return(loc), None, loc)
+    }
+
+    /// Generate code for `catch_unwind(try_fn, data, catch_fn) -> i32`.
+    ///
+    /// Invoke `try_fn(data)`. If it panics, invoke `catch_fn(data, payload)` and return `1`.
+    /// Otherwise, return `0`. The payload pointer is null, since Kani intercepts the function
+    /// that would read it (see the `PanicCleanup` hook).
+    pub fn codegen_catch_unwind(
+        &mut self,
+        mut fargs: Vec<Expr>,
+        place: &Place,
+        loc: Location,
+    ) -> Stmt {
+        if !self.is_catch_unwind_enabled() {
+            return self.codegen_unimplemented_stmt(
+                "catch_unwind",
+                loc,
+                "https://github.com/model-checking/kani/issues/267",
+            );
+        }
+        let try_fn = fargs.remove(0);
+        let data = fargs.remove(0);
+        let catch_fn = fargs.remove(0);
+        let depth = self.catch_unwind_depth(loc);
+        let unwinding = self.unwinding_flag(loc);
+        let one = Expr::int_constant(1, Type::size_t());
+        let payload = Type::unsigned_int(8).to_pointer().null();
+        let caught = Stmt::block(
+            vec![
+                unwinding.clone().assign(Expr::bool_false(), loc),
+                catch_fn.dereference().call(vec![data.clone(), payload]).as_stmt(loc),
+                self.codegen_expr_to_place_stable(
+                    place,
+                    Expr::int_constant(1, Type::signed_int(32)),
+                    loc,
+                ),
+            ],
+            loc,
+        );
+        let returned = self.codegen_expr_to_place_stable(
+            place,
+            Expr::int_constant(0, Type::signed_int(32)),
+            loc,
+        );
+        Stmt::block(
+            vec![
+                depth.clone().assign(depth.clone().plus(one.clone()), loc),
+                try_fn.dereference().call(vec![data]).as_stmt(loc),
+                depth.clone().assign(depth.sub(one), loc),
+                Stmt::if_then_else(unwinding, caught, Some(returned), loc),
+            ],
+            loc,
+        )
+    }
+}
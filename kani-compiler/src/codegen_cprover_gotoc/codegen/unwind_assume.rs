@@ -0,0 +1,187 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module instruments the loops of harnesses annotated with `#[kani::unwind_assume]`.
+//!
+//! A loop is identified by one of its back-edges, i.e., an edge that jumps to a block that does
+//! not come later in the reverse postorder of the harness body. Loops are numbered following the
+//! order of their back-edges, since the blocks are emitted in reverse postorder. This is the same
+//! order in which CBMC numbers the backward gotos of the goto program.
+//!
+//! Note that a loop head with multiple back-edges, e.g. due to a `continue`, is the head of
+//! multiple loops, where the later ones enclose the earlier ones.
+//!
+//! For every loop head with an assumed bound, we keep a variable with the block that jumped to it,
+//! and an iteration counter per assumed loop:
+//!
+//! - The terminator of every predecessor of the loop head records its own block.
+//! - The loop head resets the counter when the loop is entered from outside. If it is reached via
+//!   the back-edge of the loop, it assumes that the counter is below the bound and increments it.
+//!   If it is reached via another back-edge, the counter is left untouched.
+//!
+//! Since iterations beyond the bound become infeasible, the unwinding assertion of the loop holds
+//! as long as the unwind value is greater than the bound. Other loops are left untouched, so they
+//! keep their unwinding assertions.
+
+use crate::codegen_cprover_gotoc::GotocCtx;
+use crate::codegen_cprover_gotoc::codegen::block::reverse_postorder;
+use crate::kani_middle::attributes::KaniAttributes;
+use cbmc::goto_program::{Expr, Location, Stmt, Type};
+use rustc_smir::rustc_internal;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{BasicBlockIdx, Body};
+use std::collections::{HashMap, HashSet};
+
+/// The variables used to bound the number of iterations of the loops with the same head.
+#[derive(Debug)]
+pub struct AssumedLoopHead {
+    /// The block that jumped to the loop head.
+    predecessor: Expr,
+    /// The blocks that jump back to the loop head.
+    latches: HashSet<BasicBlockIdx>,
+    /// The loops with an assumed bound.
+    loops: Vec<AssumedLoop>,
+}
+
+/// The variables used to bound the number of iterations of a loop.
+#[derive(Debug)]
+struct AssumedLoop {
+    /// The number of back-edges taken since the loop was entered.
+    counter: Expr,
+    /// The maximum number of back-edges that can be taken.
+    bound: u32,
+    /// The block whose back-edge defines the loop.
+    latch: BasicBlockIdx,
+}
+
+impl GotocCtx<'_> {
+    /// Declare the variables used to bound the loops of the current function if it is a harness
+    /// annotated with `#[kani::unwind_assume]`.
+    ///
+    /// Emit an error if the attribute refers to a loop that does not exist.
+    pub fn codegen_declare_assumed_loops(&mut self, instance: Instance, body: &Body) {
+        let assumptions = KaniAttributes::for_instance(self.tcx, instance).unwind_assumptions();
+        if assumptions.is_empty() {
+            return;
+        }
+        let loops = loops(body);
+        let loc = self.codegen_span_stable(instance.def.span());
+        let mut heads: HashMap<BasicBlockIdx, AssumedLoopHead> = HashMap::new();
+        for assumption in assumptions {
+            let Some(&(head, latch)) = loops.get(assumption.loop_id as usize) else {
+                self.tcx.dcx().span_err(
+                    rustc_internal::internal(self.tcx, instance.def.span()),
+                    format!(
+                        "invalid loop id `{}` in `#[kani::unwind_assume]`: harness `{}` has {} \
+                        loop(s)",
+                        assumption.loop_id,
+                        instance.name(),
+                        loops.len()
+                    ),
+                );
+                continue;
+            };
+            let (counter, decl) = self.decl_temp_variable(
+                Type::unsigned_int(32),
+                Some(Expr::int_constant(0, Type::unsigned_int(32))),
+                loc,
+            );
+            self.current_fn_mut().push_onto_block(decl);
+            let assumed_loop = AssumedLoop { counter, bound: assumption.bound, latch };
+            if let Some(assumed_head) = heads.get_mut(&head) {
+                assumed_head.loops.push(assumed_loop);
+                continue;
+            }
+            // No block can jump to the head before the function starts.
+            let (predecessor, decl) = self.decl_temp_variable(
+                Type::size_t(),
+                Some(Expr::int_constant(usize::MAX, Type::size_t())),
+                loc,
+            );
+            self.current_fn_mut().push_onto_block(decl);
+            let latches =
+                loops.iter().filter(|(other, _)| *other == head).map(|(_, latch)| *latch).collect();
+            heads.insert(head, AssumedLoopHead { predecessor, latches, loops: vec![assumed_loop] });
+        }
+        for (head, assumed_head) in heads {
+            self.current_fn_mut().add_assumed_loop_head(head, assumed_head);
+        }
+    }
+
+    /// Generate the code that bounds the number of iterations of the loops with the given head,
+    /// if any.
+    pub fn codegen_assumed_loop_head(&self, bb: BasicBlockIdx, loc: Location) -> Option<Stmt> {
+        let assumed_head = self.current_fn().assumed_loop_head(bb)?;
+        let block_id = |bb: BasicBlockIdx| Expr::int_constant(bb, Type::size_t());
+        let predecessor = &assumed_head.predecessor;
+        let is_entry = assumed_head
+            .latches
+            .iter()
+            .map(|latch| predecessor.clone().neq(block_id(*latch)))
+            .reduce(Expr::and)
+            .unwrap();
+        let stmts = assumed_head
+            .loops
+            .iter()
+            .map(|assumed_loop| {
+                let counter = assumed_loop.counter.clone();
+                let bound = Expr::int_constant(assumed_loop.bound, Type::unsigned_int(32));
+                let one = Expr::int_constant(1, Type::unsigned_int(32));
+                let iterate = Stmt::block(
+                    vec![
+                        Stmt::assume(counter.clone().lt(bound), loc),
+                        counter.clone().assign(counter.clone().plus(one), loc),
+                    ],
+                    loc,
+                );
+                let enter = Stmt::if_then_else(
+                    is_entry.clone(),
+                    counter.assign(Expr::int_constant(0, Type::unsigned_int(32)), loc),
+                    None,
+                    loc,
+                );
+                let is_back_edge = predecessor.clone().eq(block_id(assumed_loop.latch));
+                Stmt::if_then_else(is_back_edge, iterate, Some(enter), loc)
+            })
+            .collect();
+        Some(Stmt::block(stmts, loc))
+    }
+
+    /// Generate the code that must precede the terminator of the given block to keep track of
+    /// the block that jumped to its successors.
+    pub fn codegen_assumed_loop_edges(
+        &self,
+        bb: BasicBlockIdx,
+        successors: &[BasicBlockIdx],
+        loc: Location,
+    ) -> Vec<Stmt> {
+        let mut heads: Vec<_> = successors.to_vec();
+        heads.sort();
+        heads.dedup();
+        heads
+            .into_iter()
+            .filter_map(|head| self.current_fn().assumed_loop_head(head))
+            .map(|assumed_head| {
+                assumed_head.predecessor.clone().assign(Expr::int_constant(bb, Type::size_t()), loc)
+            })
+            .collect()
+    }
+}
+
+/// Find the loops of the given body, ordered by the position of their back-edges in reverse
+/// postorder.
+///
+/// Each loop is represented by its head and the block that jumps back to it.
+fn loops(body: &Body) -> Vec<(BasicBlockIdx, BasicBlockIdx)> {
+    let order: Vec<BasicBlockIdx> = reverse_postorder(body).collect();
+    let position: HashMap<BasicBlockIdx, usize> =
+        order.iter().enumerate().map(|(idx, bb)| (*bb, idx)).collect();
+    let mut loops = vec![];
+    for (idx, &bb) in order.iter().enumerate() {
+        let mut heads = body.blocks[bb].terminator.successors();
+        heads.dedup();
+        loops.extend(heads.into_iter().filter(|head| position[head] <= idx).map(|head| (head, bb)));
+    }
+    loops
+}
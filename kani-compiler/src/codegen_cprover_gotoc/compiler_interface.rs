@@ -8,8 +8,9 @@
 use crate::kani_middle::analysis;
 use crate::kani_middle::attributes::{KaniAttributes, is_test_harness_description};
 use crate::kani_middle::check_reachable_items;
-use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits};
+use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits, Stubs};
 use crate::kani_middle::metadata::gen_test_metadata;
+use crate::kani_middle::nondet_budget::check_nondet_budget;
 use crate::kani_middle::provide;
 use crate::kani_middle::reachability::{
     collect_reachable_items, filter_const_crate_items, filter_crate_items,
@@ -18,7 +19,7 @@
 use crate::kani_queries::QueryDb;
 use cbmc::RoundingMode;
 use cbmc::goto_program::Location;
-use cbmc::irep::goto_binary_serde::write_goto_binary_file;
+use cbmc::irep::goto_binary_serde::{GOTO_BINARY_VERSION, write_goto_binary_file};
 use cbmc::{InternedString, MachineModel};
 use kani_metadata::artifact::convert_type;
 use kani_metadata::{ArtifactType, HarnessMetadata, KaniMetadata, UnsupportedFeature};
@@ -45,13 +46,13 @@
 use stable_mir::mir::mono::{Instance, MonoItem};
 use stable_mir::{CrateDef, DefId};
 use std::any::Any;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Write;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
 pub type UnsupportedConstructs = FxHashMap<InternedString, Vec<Location>>;
@@ -72,7 +73,9 @@ pub fn new(queries: Arc<Mutex<QueryDb>>) -> Self {
 
     /// Generate code that is reachable from the given starting points.
     ///
-    /// Invariant: iff `check_contract.is_some()` then `return.2.is_some()`
+    /// Invariant: if `check_only` is disabled, then `check_contract.is_some()` iff
+    /// `return.2.is_some()`. In `check_only` mode, no code is generated and `return.2` is always
+    /// `None`.
     fn codegen_items<'tcx>(
         &self,
         tcx: TyCtxt<'tcx>,
@@ -119,17 +122,33 @@ fn codegen_items<'tcx>(
 
         // Re-collect reachable items after global transformations were applied. This is necessary
         // since global pass could add extra calls to instrumentation.
-        let (items, _) = with_timer(
+        let (items, call_graph) = with_timer(
             || collect_reachable_items(tcx, &mut transformer, starting_items),
             "codegen reachability analysis (second pass)",
         );
 
+        // Print the graph after the global passes, so it reflects the instances that will be
+        // codegened.
+        if self.queries.lock().unwrap().args().print_call_graph {
+            call_graph.print_dot(starting_items);
+        }
+
+        if let Some(budget) = self.queries.lock().unwrap().args().max_nondet_bytes {
+            check_nondet_budget(tcx, &mut transformer, starting_items, budget);
+        }
+
         // Follow rustc naming convention (cx is abbrev for context).
         // https://rustc-dev-guide.rust-lang.org/conventions.html#naming-conventions
         let mut gcx =
             GotocCtx::new(tcx, (*self.queries.lock().unwrap()).clone(), machine_model, transformer);
         check_reachable_items(gcx.tcx, &gcx.queries, &items);
 
+        // Stop right after instrumentation. Note that errors that are only detected during
+        // codegen, such as unsupported constructs, will not be reported.
+        if gcx.queries.args().check_only {
+            return (gcx, items, None);
+        }
+
         let contract_info = with_timer(
             || {
                 // we first declare all items
@@ -157,6 +176,7 @@ fn codegen_items<'tcx>(
                 for item in &items {
                     match *item {
                         MonoItem::Fn(instance) => {
+                            let start = gcx.codegen_timings.is_some().then(Instant::now);
                             gcx.call_with_panic_debug_info(
                                 |ctx| ctx.codegen_function(instance),
                                 format!(
@@ -166,6 +186,11 @@ fn codegen_items<'tcx>(
                                 ),
                                 instance.def,
                             );
+                            if let (Some(start), Some(timings)) =
+                                (start, gcx.codegen_timings.as_mut())
+                            {
+                                timings.record(instance, start.elapsed());
+                            }
                         }
                         MonoItem::Static(def) => {
                             gcx.call_with_panic_debug_info(
@@ -191,9 +216,11 @@ fn codegen_items<'tcx>(
         let type_map: BTreeMap<InternedString, InternedString> =
             BTreeMap::from_iter(gcx.type_map.iter().map(|(k, v)| (*k, v.to_string().into())));
 
-        // Get the vtable function pointer restrictions if requested
-        let vtable_restrictions = if gcx.vtable_ctx.emit_vtable_restrictions {
-            Some(gcx.vtable_ctx.get_virtual_function_restrictions())
+        // Get the vtable and function pointer restrictions if requested
+        let vtable_restrictions = if gcx.vtable_ctx.emit_vtable_restrictions
+            || gcx.queries.args().emit_fn_ptr_restrictions
+        {
+            Some(gcx.vtable_ctx.get_function_pointer_restrictions())
         } else {
             None
         };
@@ -202,12 +229,25 @@ fn codegen_items<'tcx>(
         if !tcx.sess.opts.unstable_opts.no_codegen && tcx.sess.opts.output_types.should_codegen() {
             let pretty = self.queries.lock().unwrap().args().output_pretty_json;
             write_file(&symtab_goto, ArtifactType::PrettyNameMap, &pretty_name_map, pretty);
-            write_goto_binary_file(symtab_goto, &gcx.symbol_table);
+            let stats = write_goto_binary_file(symtab_goto, &gcx.symbol_table);
+            if gcx.queries.args().print_symtab_stats {
+                eprintln!("====== Symbol Table Stats =======");
+                eprintln!("Symbol table: {}", symtab_goto.display());
+                eprintln!("{stats}");
+            }
             write_file(&symtab_goto, ArtifactType::TypeMap, &type_map, pretty);
-            // If they exist, write out vtable virtual call function pointer restrictions
+            // If they exist, write out vtable virtual call and function pointer restrictions
             if let Some(restrictions) = vtable_restrictions {
                 write_file(&symtab_goto, ArtifactType::VTableRestriction, &restrictions, pretty);
             }
+            if gcx.queries.args().check_coverage {
+                write_file(
+                    &symtab_goto,
+                    ArtifactType::CoverageMappings,
+                    &gcx.coverage_mappings,
+                    pretty,
+                );
+            }
         }
 
         (gcx, items, contract_info)
@@ -244,7 +284,7 @@ fn codegen_crate(
             let queries = self.queries.lock().unwrap().clone();
 
             check_target(tcx.sess);
-            check_options(tcx.sess);
+            check_options(tcx.sess, queries.args().goto_binary_version);
             if queries.args().reachability_analysis != ReachabilityType::None
                 && queries.kani_functions().is_empty()
             {
@@ -268,6 +308,7 @@ fn codegen_crate(
                     let mut units = CodegenUnits::new(&queries, tcx);
                     let mut modifies_instances = vec![];
                     let mut loop_contracts_instances = vec![];
+                    let mut unsupported_instances = vec![];
                     // Cross-crate collecting of all items that are reachable from the crate harnesses.
                     for unit in units.iter() {
                         // We reset the body cache for now because each codegen unit has different
@@ -285,9 +326,23 @@ fn codegen_crate(
                                 contract_metadata,
                                 transformer,
                             );
+                            if queries
+                                .args()
+                                .dump_reachable_functions
+                                .as_ref()
+                                .is_some_and(|target| harness.name().contains(target.as_str()))
+                            {
+                                dump_reachable_functions(&gcx, *harness, &items, &unit.stubs);
+                            }
                             if gcx.has_loop_contracts {
                                 loop_contracts_instances.push(*harness);
                             }
+                            if !gcx.unsupported_constructs.is_empty() {
+                                unsupported_instances.push((
+                                    *harness,
+                                    unsupported_features(&gcx.unsupported_constructs),
+                                ));
+                            }
                             results.extend(gcx, items, None);
                             if let Some(assigns_contract) = contract_info {
                                 modifies_instances.push((*harness, assigns_contract));
@@ -296,6 +351,7 @@ fn codegen_crate(
                     }
                     units.store_modifies(&modifies_instances);
                     units.store_loop_contracts(&loop_contracts_instances);
+                    units.store_unsupported_features(unsupported_instances);
                     units.write_metadata(&queries, tcx);
                 }
                 ReachabilityType::Tests => {
@@ -336,11 +392,13 @@ fn codegen_crate(
                         let metadata =
                             gen_test_metadata(tcx, *test_desc, *instance, &base_filename);
                         let test_model_path = &metadata.goto_file.as_ref().unwrap();
-                        std::fs::copy(&model_path, test_model_path).expect(&format!(
-                            "Failed to copy {} to {}",
-                            model_path.display(),
-                            test_model_path.display()
-                        ));
+                        if !queries.args().check_only {
+                            std::fs::copy(&model_path, test_model_path).expect(&format!(
+                                "Failed to copy {} to {}",
+                                model_path.display(),
+                                test_model_path.display()
+                            ));
+                        }
                         results.harnesses.push(metadata);
                     }
                 }
@@ -487,7 +545,7 @@ fn check_target(session: &Session) {
     session.dcx().abort_if_errors();
 }
 
-fn check_options(session: &Session) {
+fn check_options(session: &Session, goto_binary_version: Option<usize>) {
     // The requirements for `min_global_align` and `endian` are needed to build
     // a valid CBMC machine model in function `machine_model_from_session` from
     // src/kani-compiler/src/codegen_cprover_gotoc/context/goto_ctx.rs
@@ -517,6 +575,15 @@ fn check_options(session: &Session) {
         );
     }
 
+    if let Some(version) = goto_binary_version
+        && version != GOTO_BINARY_VERSION
+    {
+        session.dcx().err(format!(
+            "Kani can only generate GOTO binaries in version {GOTO_BINARY_VERSION}, but version \
+            {version} was requested."
+        ));
+    }
+
     session.dcx().abort_if_errors();
 }
 
@@ -554,6 +621,30 @@ pub fn write_file<T>(base_path: &Path, file_type: ArtifactType, source: &T, pret
     }
 }
 
+/// Maps the goto-context "unsupported features" data into the KaniMetadata "unsupported features"
+/// format.
+// TODO: Do we really need different formats??
+fn unsupported_features(constructs: &UnsupportedConstructs) -> Vec<UnsupportedFeature> {
+    constructs
+        .iter()
+        .map(|(construct, location)| UnsupportedFeature {
+            feature: construct.to_string(),
+            locations: location
+                .iter()
+                .map(|l| {
+                    // We likely (and should) have no instances of
+                    // calling `codegen_unimplemented` without file/line.
+                    // So while we map out of `Option` here, we expect them to always be `Some`
+                    kani_metadata::Location {
+                        filename: l.filename().unwrap_or_default(),
+                        start_line: l.start_line().unwrap_or_default(),
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
 struct GotoCodegenResults {
     reachability: ReachabilityType,
     harnesses: Vec<HarnessMetadata>,
@@ -562,6 +653,8 @@ struct GotoCodegenResults {
     items: Vec<MonoItem>,
     crate_name: InternedString,
     machine_model: MachineModel,
+    codegen_timings: Option<CodegenTimings>,
+    auto_stubbed: BTreeSet<String>,
 }
 
 impl GotoCodegenResults {
@@ -574,31 +667,13 @@ pub fn new(tcx: TyCtxt, reachability: ReachabilityType) -> Self {
             items: vec![],
             crate_name: tcx.crate_name(LOCAL_CRATE).as_str().into(),
             machine_model: new_machine_model(tcx.sess),
+            codegen_timings: None,
+            auto_stubbed: BTreeSet::new(),
         }
     }
     /// Method that generates `KaniMetadata` from the given compilation results.
     pub fn generate_metadata(&self) -> KaniMetadata {
-        // Maps the goto-context "unsupported features" data into the KaniMetadata "unsupported features" format.
-        // TODO: Do we really need different formats??
-        let unsupported_features = self
-            .unsupported_constructs
-            .iter()
-            .map(|(construct, location)| UnsupportedFeature {
-                feature: construct.to_string(),
-                locations: location
-                    .iter()
-                    .map(|l| {
-                        // We likely (and should) have no instances of
-                        // calling `codegen_unimplemented` without file/line.
-                        // So while we map out of `Option` here, we expect them to always be `Some`
-                        kani_metadata::Location {
-                            filename: l.filename().unwrap_or_default(),
-                            start_line: l.start_line().unwrap_or_default(),
-                        }
-                    })
-                    .collect(),
-            })
-            .collect();
+        let unsupported_features = unsupported_features(&self.unsupported_constructs);
         let (proofs, tests) = if self.reachability == ReachabilityType::Harnesses {
             (self.harnesses.clone(), vec![])
         } else {
@@ -627,6 +702,10 @@ fn extend(
         self.harnesses.extend(metadata);
         self.concurrent_constructs.extend(gcx.concurrent_constructs);
         self.unsupported_constructs.extend(gcx.unsupported_constructs);
+        if let Some(timings) = gcx.codegen_timings {
+            self.codegen_timings.get_or_insert_default().extend(timings);
+        }
+        self.auto_stubbed.extend(gcx.auto_stubbed);
         self.items.append(&mut items);
         gcx.transformer
     }
@@ -651,6 +730,19 @@ fn print_report(&self, tcx: TyCtxt) {
             tcx.dcx().warn(msg);
         }
 
+        if !self.auto_stubbed.is_empty() {
+            let mut msg = String::from(
+                "The following functions contain unsupported constructs and were replaced by \
+                stubs that return a nondeterministic value:\n",
+            );
+            for function in &self.auto_stubbed {
+                writeln!(&mut msg, "    - {function}").unwrap();
+            }
+            msg += "\nVerification results are only valid if these stubs are a sound abstraction \
+            of the original functions. Side effects through pointer arguments are not modeled.";
+            tcx.dcx().warn(msg);
+        }
+
         if !self.concurrent_constructs.is_empty() {
             let mut msg = String::from(
                 "Kani currently does not support concurrency. The following constructs will be treated \
@@ -666,6 +758,137 @@ fn print_report(&self, tcx: TyCtxt) {
         if tracing::enabled!(tracing::Level::INFO) {
             analysis::print_stats(&self.items);
         }
+
+        if let Some(timings) = &self.codegen_timings {
+            timings.print_report();
+        }
+    }
+}
+
+/// Wall-clock time spent generating code for each function.
+///
+/// This is only collected if `--verbose-codegen-timing` is enabled, and it is used to find
+/// functions that are slow to codegen, such as pathological monomorphizations.
+#[derive(Debug, Default)]
+pub struct CodegenTimings {
+    /// Timing per function definition, aggregated across all its instances.
+    per_fn: FxHashMap<String, FnTiming>,
+}
+
+#[derive(Debug, Default)]
+struct FnTiming {
+    total: Duration,
+    instances: usize,
+    /// The name of the slowest instance and the time it took.
+    worst: Option<(String, Duration)>,
+}
+
+impl CodegenTimings {
+    /// The number of functions included in the report.
+    const REPORT_SIZE: usize = 10;
+
+    /// Record the time it took to codegen the given instance.
+    pub fn record(&mut self, instance: Instance, elapsed: Duration) {
+        let timing = self.per_fn.entry(instance.def.name()).or_default();
+        timing.total += elapsed;
+        timing.instances += 1;
+        if timing.worst.as_ref().is_none_or(|(_, worst)| elapsed > *worst) {
+            timing.worst = Some((instance.name(), elapsed));
+        }
+    }
+
+    fn extend(&mut self, other: CodegenTimings) {
+        for (name, other) in other.per_fn {
+            let timing = self.per_fn.entry(name).or_default();
+            timing.total += other.total;
+            timing.instances += other.instances;
+            if let Some((instance, elapsed)) = other.worst {
+                if timing.worst.as_ref().is_none_or(|(_, worst)| elapsed > *worst) {
+                    timing.worst = Some((instance, elapsed));
+                }
+            }
+        }
+    }
+
+    /// Print the functions that took the longest to codegen.
+    fn print_report(&self) {
+        let mut sorted = self.per_fn.iter().collect::<Vec<_>>();
+        sorted.sort_by(|(_, a), (_, b)| b.total.cmp(&a.total));
+        let total: Duration = self.per_fn.values().map(|timing| timing.total).sum();
+        eprintln!("====== Codegen Timing =======");
+        eprintln!("Total codegen time: {}s", total.as_secs_f32());
+        eprintln!("Slowest functions:");
+        for (name, timing) in sorted.into_iter().take(Self::REPORT_SIZE) {
+            eprintln!(
+                "  - {name}: {}s ({} instance(s))",
+                timing.total.as_secs_f32(),
+                timing.instances
+            );
+            if let Some((instance, elapsed)) = &timing.worst {
+                eprintln!("      slowest instance: {instance} ({}s)", elapsed.as_secs_f32());
+            }
+        }
+        eprintln!("-----------------------------")
+    }
+}
+
+/// Print the functions that are reachable from the given harness, i.e., the functions that are
+/// part of its verification, as well as the functions that were stubbed out.
+///
+/// Functions are annotated if they replace a stubbed function, if they are modeled by a hook (so
+/// their body is not verified), or if they contain unsupported constructs.
+fn dump_reachable_functions(gcx: &GotocCtx, harness: Instance, items: &[MonoItem], stubs: &Stubs) {
+    let mut unsupported: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for (construct, locations) in &gcx.unsupported_constructs {
+        for location in locations {
+            if let Location::Loc { function: Some(function), .. } = location {
+                unsupported.entry(function.to_string()).or_default().insert(construct.to_string());
+            }
+        }
+    }
+    let stubbed: HashMap<DefId, String> =
+        stubs.iter().map(|(original, stub)| (stub.def_id(), original.name())).collect();
+
+    let mut functions: Vec<_> = items
+        .iter()
+        .filter_map(|item| if let MonoItem::Fn(instance) = item { Some(*instance) } else { None })
+        .map(|instance| (instance.name(), instance))
+        .collect();
+    functions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    println!("Reachable functions for harness `{}` ({}):", harness.name(), functions.len());
+    for (name, instance) in functions {
+        let signature = instance
+            .fn_abi()
+            .map(|abi| {
+                let args: Vec<_> = abi.args.iter().map(|arg| arg.ty.to_string()).collect();
+                format!("fn({}) -> {}", args.join(", "), abi.ret.ty)
+            })
+            .unwrap_or_default();
+        let mut notes = vec![];
+        if let Some(original) = stubbed.get(&instance.def.def_id()) {
+            notes.push(format!("stub for `{original}`"));
+        }
+        if gcx.hooks.hook_applies(gcx.tcx, instance).is_some() {
+            notes.push("modeled by Kani, not verified".to_string());
+        }
+        if let Some(constructs) = unsupported.get(&name) {
+            let constructs: Vec<_> = constructs.iter().map(String::as_str).collect();
+            notes.push(format!("unsupported: {}", constructs.join(", ")));
+        }
+        let notes =
+            if notes.is_empty() { String::new() } else { format!(" [{}]", notes.join("; ")) };
+        println!("  - {name}: {signature} (crate `{}`){notes}", instance.def.krate().name);
+    }
+
+    if !stubs.is_empty() {
+        let mut stubs: Vec<_> =
+            stubs.iter().map(|(original, stub)| (original.name(), stub.name())).collect();
+        stubs.sort();
+        println!("Stubbed functions for harness `{}` ({}):", harness.name(), stubs.len());
+        for (original, stub) in stubs {
+            println!("  - {original} replaced by {stub}");
+        }
     }
 }
 
@@ -2,13 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::codegen_cprover_gotoc::GotocCtx;
+use crate::codegen_cprover_gotoc::codegen::AssumedLoopHead;
 use cbmc::InternedString;
 use cbmc::goto_program::Stmt;
 use rustc_middle::ty::Instance as InstanceInternal;
 use rustc_smir::rustc_internal;
 use stable_mir::CrateDef;
 use stable_mir::mir::mono::Instance;
-use stable_mir::mir::{Body, Local, LocalDecl, Rvalue, visit::Location, visit::MirVisitor};
+use stable_mir::mir::{
+    BasicBlockIdx, Body, Local, LocalDecl, Rvalue, visit::Location, visit::MirVisitor,
+};
 use std::collections::{HashMap, HashSet};
 
 /// This structure represents useful data about the function we are currently compiling.
@@ -34,6 +37,8 @@ pub struct CurrentFnCtx<'tcx> {
     readable_name: String,
     /// A counter to enable creating temporary variables
     temp_var_counter: u64,
+    /// Loops whose number of iterations is assumed to be bounded, indexed by their head.
+    assumed_loops: HashMap<BasicBlockIdx, AssumedLoopHead>,
 }
 
 struct AddressTakenLocalsCollector {
@@ -79,6 +84,7 @@ pub fn new(instance: Instance, gcx: &GotocCtx<'tcx>, body: &Body) -> Self {
             name,
             readable_name,
             temp_var_counter: 0,
+            assumed_loops: HashMap::new(),
         }
     }
 }
@@ -99,6 +105,10 @@ pub fn get_and_incr_counter(&mut self) -> u64 {
     pub fn push_onto_block(&mut self, s: Stmt) {
         self.block.push(s)
     }
+
+    pub fn add_assumed_loop_head(&mut self, head: BasicBlockIdx, assumed_head: AssumedLoopHead) {
+        self.assumed_loops.insert(head, assumed_head);
+    }
 }
 
 /// Getters
@@ -133,6 +143,11 @@ pub fn local_name(&self, local: Local) -> Option<InternedString> {
     pub fn is_address_taken_local(&self, local: Local) -> bool {
         self.address_taken_locals.contains(&local)
     }
+
+    /// The loops with the given head whose number of iterations is assumed to be bounded.
+    pub fn assumed_loop_head(&self, head: BasicBlockIdx) -> Option<&AssumedLoopHead> {
+        self.assumed_loops.get(&head)
+    }
 }
 
 /// Utility functions
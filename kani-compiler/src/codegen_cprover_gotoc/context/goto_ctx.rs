@@ -16,9 +16,10 @@
 //! this structure as input.
 use super::current_fn::CurrentFnCtx;
 use super::vtable_ctx::VtableCtx;
-use crate::codegen_cprover_gotoc::UnsupportedConstructs;
+use crate::codegen_cprover_gotoc::codegen::CoverageMapping;
 use crate::codegen_cprover_gotoc::overrides::{GotocHooks, fn_hooks};
 use crate::codegen_cprover_gotoc::utils::full_crate_name;
+use crate::codegen_cprover_gotoc::{CodegenTimings, UnsupportedConstructs};
 use crate::kani_middle::transform::BodyTransformation;
 use crate::kani_queries::QueryDb;
 use cbmc::goto_program::{
@@ -76,6 +77,13 @@ pub struct GotocCtx<'tcx> {
     pub transformer: BodyTransformation,
     /// If there exist some usage of loop contracts int context.
     pub has_loop_contracts: bool,
+    /// Time spent to codegen each function. Only collected if `--verbose-codegen-timing` is set.
+    pub codegen_timings: Option<CodegenTimings>,
+    /// Functions that were replaced by a nondeterministic stub because they contain unsupported
+    /// constructs. Only collected if `--stub-unsupported` is set.
+    pub auto_stubbed: Vec<String>,
+    /// The source regions covered by the code coverage checks generated so far.
+    pub coverage_mappings: Vec<CoverageMapping>,
 }
 
 /// Constructor
@@ -86,9 +94,10 @@ pub fn new(
         machine_model: &MachineModel,
         transformer: BodyTransformation,
     ) -> GotocCtx<'tcx> {
-        let fhks = fn_hooks();
+        let fhks = fn_hooks(&queries);
         let symbol_table = SymbolTable::new(machine_model.clone());
         let emit_vtable_restrictions = queries.args().emit_vtable_restrictions;
+        let codegen_timings = queries.args().verbose_codegen_timing.then(CodegenTimings::default);
         GotocCtx {
             tcx,
             queries,
@@ -106,6 +115,9 @@ pub fn new(
             concurrent_constructs: FxHashMap::default(),
             transformer,
             has_loop_contracts: false,
+            codegen_timings,
+            auto_stubbed: vec![],
+            coverage_mappings: vec![],
         }
     }
 }
@@ -19,8 +19,11 @@
 use crate::codegen_cprover_gotoc::codegen::typ::pointee_type;
 use cbmc::InternedString;
 use cbmc::goto_program::{Stmt, Type};
-use kani_metadata::{CallSite, PossibleMethodEntry, TraitDefinedMethod, VtableCtxResults};
+use kani_metadata::{
+    CallSite, FnPtrCallSite, PossibleMethodEntry, TraitDefinedMethod, VtableCtxResults,
+};
 use rustc_data_structures::fx::FxHashMap;
+use stable_mir::mir::BasicBlockIdx;
 use tracing::debug;
 
 /// This structure represents data about the vtable that we construct
@@ -38,6 +41,9 @@ pub struct VtableCtx {
 
     // Internal tracing of index needed for call site wrappers
     call_site_global_idx: usize,
+
+    // All calls through a function pointer that have a known set of targets
+    fn_ptr_call_sites: Vec<FnPtrCallSite>,
 }
 
 /// Constructor
@@ -49,6 +55,7 @@ pub fn new(emit_vtable_restrictions: bool) -> Self {
             possible_methods: FxHashMap::default(),
             call_sites: Vec::new(),
             call_site_global_idx: 0,
+            fn_ptr_call_sites: Vec::new(),
         }
     }
 }
@@ -102,6 +109,16 @@ fn add_call_site(
         };
         self.call_sites.push(site);
     }
+
+    /// Add a call through a function pointer with its possible targets.
+    fn add_fn_ptr_call_site(
+        &mut self,
+        function_name: InternedString,
+        label: InternedString,
+        possibilities: Vec<InternedString>,
+    ) {
+        self.fn_ptr_call_sites.push(FnPtrCallSite { function_name, label, possibilities });
+    }
 }
 
 impl GotocCtx<'_> {
@@ -132,15 +149,30 @@ pub fn virtual_call_with_restricted_fn_ptr(
         );
         body.with_label(label)
     }
+
+    /// Label the call through a function pointer at the end of the given basic block, if its
+    /// possible targets were collected by the function pointer restriction analysis.
+    ///
+    /// Calls with no known targets are left untouched, i.e., they are not restricted.
+    pub fn restrict_fn_ptr_call(&mut self, bb: BasicBlockIdx, body: Stmt) -> Stmt {
+        let instance = self.current_fn().instance_stable();
+        let Some(targets) = self.transformer.fn_ptr_targets(instance, bb) else {
+            return body;
+        };
+        let possibilities = targets.iter().map(|target| target.mangled_name().into()).collect();
+        let function_name = self.current_fn().name().into();
+        let label: InternedString = format!("restricted_fn_ptr_call_bb{bb}").into();
+        self.vtable_ctx.add_fn_ptr_call_site(function_name, label, possibilities);
+        body.with_label(label)
+    }
 }
 
 /// Write out information per crate. We need to later aggregate the information
 /// for the final combined executable (virtual calls can be across dependencies).
 impl VtableCtx {
-    /// Write out (1) all call sites and (2) possible concrete methods to JSON.
-    pub fn get_virtual_function_restrictions(&mut self) -> VtableCtxResults {
-        assert!(self.emit_vtable_restrictions);
-
+    /// Write out (1) all call sites, (2) possible concrete methods, and (3) all restricted
+    /// function pointer calls to JSON.
+    pub fn get_function_pointer_restrictions(&mut self) -> VtableCtxResults {
         VtableCtxResults {
             call_sites: self.call_sites.clone(),
             possible_methods: self
@@ -148,6 +180,7 @@ pub fn get_virtual_function_restrictions(&mut self) -> VtableCtxResults {
                 .drain()
                 .map(|(k, v)| PossibleMethodEntry { trait_method: k, possibilities: v })
                 .collect(),
+            fn_ptr_call_sites: std::mem::take(&mut self.fn_ptr_call_sites),
         }
     }
 }
@@ -6,6 +6,6 @@
 mod overrides;
 mod utils;
 
-pub use compiler_interface::{GotocCodegenBackend, UnsupportedConstructs};
+pub use compiler_interface::{CodegenTimings, GotocCodegenBackend, UnsupportedConstructs};
 pub use context::GotocCtx;
 pub use context::VtableCtx;
@@ -12,14 +12,16 @@
 use crate::codegen_cprover_gotoc::{GotocCtx, utils};
 use crate::kani_middle::attributes;
 use crate::kani_middle::kani_functions::{KaniFunction, KaniHook};
+use crate::kani_queries::QueryDb;
 use crate::unwrap_or_return_codegen_unimplemented_stmt;
 use cbmc::goto_program::CIntType;
 use cbmc::goto_program::{BuiltinFn, Expr, Stmt, Type};
+use kani_metadata::ASSERTION_TAGS;
 use rustc_middle::ty::TyCtxt;
 use rustc_smir::rustc_internal;
 use stable_mir::mir::mono::Instance;
-use stable_mir::mir::{BasicBlockIdx, Place};
-use stable_mir::ty::RigidTy;
+use stable_mir::mir::{BasicBlockIdx, Mutability, Place};
+use stable_mir::ty::{IndexedVal, RigidTy, Ty, TyKind, UintTy, VariantIdx};
 use stable_mir::{CrateDef, ty::Span};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -145,6 +147,104 @@ fn handle(
     }
 }
 
+/// A hook for Kani's `assert_tagged` function (declared in `library/kani_core/src/lib.rs`).
+/// The function takes a condition, a tag and a message. The tag is recorded as a `[<tag>] `
+/// prefix of the property description, so the driver can report it.
+struct AssertTagged;
+impl GotocHook for AssertTagged {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 3);
+        let cond = fargs.remove(0).cast_to(Type::bool());
+        let tag = fargs.remove(0);
+        let tag = gcx.extract_const_message(&tag).unwrap();
+        let msg = fargs.remove(0);
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let target = target.unwrap();
+        let caller_loc = gcx.codegen_caller_span_stable(span);
+
+        // The tag is usually validated by the `assert_tagged!` macro, but the function may be
+        // called directly.
+        if !ASSERTION_TAGS.contains(&tag.as_str()) {
+            gcx.tcx.dcx().span_err(
+                rustc_internal::internal(gcx.tcx, span),
+                format!(
+                    "invalid assertion tag `{tag}`, expected one of {}",
+                    ASSERTION_TAGS.map(|tag| format!("`{tag}`")).join(", ")
+                ),
+            );
+        }
+
+        let (msg, reach_stmt) = gcx.codegen_reachability_check(format!("[{tag}] {msg}"), span);
+
+        Stmt::block(
+            vec![
+                reach_stmt,
+                gcx.codegen_assert_assume(cond, PropertyClass::Assertion, &msg, caller_loc),
+                Stmt::goto(bb_label(target), caller_loc),
+            ],
+            caller_loc,
+        )
+    }
+}
+
+/// A hook for Kani's `contract_assert` function (declared in `library/kani_core/src/lib.rs`).
+/// The function takes a condition, the kind of contract clause and a message. The clause is
+/// recorded as a `[<clause>] ` prefix of the property description, so the driver can report it.
+struct ContractAssert;
+impl GotocHook for ContractAssert {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 3);
+        let cond = fargs.remove(0).cast_to(Type::bool());
+        let clause = fargs.remove(0);
+        let clause = gcx.extract_const_message(&clause).unwrap();
+        let msg = fargs.remove(0);
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let target = target.unwrap();
+        let caller_loc = gcx.codegen_caller_span_stable(span);
+
+        // This function is only called by the code generated by the contract macros.
+        assert!(
+            matches!(clause.as_str(), "precondition" | "postcondition"),
+            "Unexpected contract clause `{clause}`"
+        );
+
+        let (msg, reach_stmt) = gcx.codegen_reachability_check(format!("[{clause}] {msg}"), span);
+
+        Stmt::block(
+            vec![
+                reach_stmt,
+                gcx.codegen_assert_assume(cond, PropertyClass::Contract, &msg, caller_loc),
+                Stmt::goto(bb_label(target), caller_loc),
+            ],
+            caller_loc,
+        )
+    }
+}
+
 struct UnsupportedCheck;
 impl GotocHook for UnsupportedCheck {
     fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
@@ -299,23 +399,7 @@ fn handle(
         assert!(fargs.is_empty());
         let loc = gcx.codegen_span_stable(span);
         let target = target.unwrap();
-        let pt = gcx.place_ty_stable(assign_to);
-        if pt.kind().is_unit() {
-            Stmt::goto(bb_label(target), loc)
-        } else {
-            let pe = unwrap_or_return_codegen_unimplemented_stmt!(
-                gcx,
-                gcx.codegen_place_stable(assign_to, loc)
-            )
-            .goto_expr;
-            Stmt::block(
-                vec![
-                    pe.assign(gcx.codegen_ty_stable(pt).nondet(), loc),
-                    Stmt::goto(bb_label(target), loc),
-                ],
-                loc,
-            )
-        }
+        Stmt::block(vec![gcx.codegen_havoc(assign_to, loc), Stmt::goto(bb_label(target), loc)], loc)
     }
 }
 
@@ -343,6 +427,51 @@ fn handle(
     }
 }
 
+/// Replace the function that retrieves the payload of a panic caught by `catch_unwind`.
+///
+/// Kani doesn't keep track of the panic payload, so this hook returns an opaque
+/// `Box<dyn Any + Send>` that wraps a `()`. This also skips the update to the panic count, which
+/// is not incremented when Kani intercepts a panic. See the `unwind` codegen module for more
+/// details.
+struct PanicCleanup;
+
+impl GotocHook for PanicCleanup {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        instance.def.name() == "std::panicking::r#try::cleanup"
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        _fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        let loc = gcx.codegen_span_stable(span);
+        let unit_box_ty = Ty::new_box(Ty::new_tuple(&[]));
+        let unit_box_typ = gcx.codegen_ty_stable(unit_box_ty);
+        // Use a dangling pointer, which is valid for a zero-sized type.
+        let dangling = Expr::pointer_constant(1, gcx.codegen_ty_unit().to_pointer());
+        let unit_box = gcx.box_value(dangling, unit_box_typ);
+        let payload_ty = gcx.place_ty_stable(assign_to);
+        let payload = gcx.codegen_unsized_cast(unit_box, unit_box_ty, payload_ty, loc);
+        Stmt::block(
+            vec![
+                unwrap_or_return_codegen_unimplemented_stmt!(
+                    gcx,
+                    gcx.codegen_place_stable(assign_to, loc)
+                )
+                .goto_expr
+                .assign(payload, loc),
+                Stmt::goto(bb_label(target.unwrap()), loc),
+            ],
+            loc,
+        )
+    }
+}
+
 /// Encodes __CPROVER_r_ok(ptr, size)
 struct IsAllocated;
 impl GotocHook for IsAllocated {
@@ -541,6 +670,325 @@ fn handle(
     }
 }
 
+/// This hook intercepts calls to `ptr::copy` and `ptr::copy_nonoverlapping`, and models them with
+/// a `memmove` or `memcpy` of `count * size_of::<T>()` bytes.
+///
+/// A copy of zero elements is a no-op, so no bounds check is performed in that case, and copies of
+/// ZSTs are elided. For `copy_nonoverlapping`, we also check that the source and destination
+/// regions do not overlap.
+struct PtrCopy;
+
+impl GotocHook for PtrCopy {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        // `ptr::copy*` are re-exports of the functions declared in `core::intrinsics`.
+        matches!(
+            instance.def.name().as_str(),
+            "core::ptr::copy"
+                | "core::ptr::copy_nonoverlapping"
+                | "std::ptr::copy"
+                | "std::ptr::copy_nonoverlapping"
+                | "core::intrinsics::copy"
+                | "core::intrinsics::copy_nonoverlapping"
+        )
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        debug!(?instance, "Replace pointer copy");
+        let loc = gcx.codegen_span_stable(span);
+        let target = target.unwrap();
+        let name = instance.def.name();
+        let is_non_overlapping = name.ends_with("copy_nonoverlapping");
+        let pointee_ty = *instance.args().0[0].expect_ty();
+        let src = fargs.remove(0);
+        let dst = fargs.remove(0);
+        let count = fargs.remove(0);
+
+        if gcx.is_zst_stable(pointee_ty) {
+            // Do not attempt to copy a ZST.
+            return Stmt::goto(bb_label(target), loc);
+        }
+
+        let mut stmts = vec![];
+        if is_non_overlapping {
+            let elem_size =
+                Expr::int_constant(gcx.layout_of_stable(pointee_ty).size.bytes(), Type::size_t());
+            let num_bytes = count.clone().cast_to(Type::size_t()).mul(elem_size);
+            let src_offset = src.clone().pointer_offset().cast_to(Type::size_t());
+            let dst_offset = dst.clone().pointer_offset().cast_to(Type::size_t());
+            let non_overlapping = count
+                .clone()
+                .is_zero()
+                .or(src.clone().same_object(dst.clone()).not())
+                .or(src_offset.clone().plus(num_bytes.clone()).le(dst_offset.clone()))
+                .or(dst_offset.plus(num_bytes).le(src_offset));
+            stmts.push(gcx.codegen_assert_assume(
+                non_overlapping,
+                PropertyClass::SafetyCheck,
+                "`copy_nonoverlapping`: memory regions of `src` and `dst` must not overlap",
+                loc,
+            ));
+        }
+        let farg_types =
+            [Ty::new_ptr(pointee_ty, Mutability::Not), Ty::new_ptr(pointee_ty, Mutability::Mut)];
+        stmts.push(gcx.codegen_copy(
+            &name,
+            is_non_overlapping,
+            vec![src, dst, count],
+            &farg_types,
+            None,
+            loc,
+        ));
+        stmts.push(Stmt::goto(bb_label(target), loc));
+        Stmt::block(stmts, loc)
+    }
+}
+
+/// This hook replaces `ptr::null` and `ptr::null_mut` by a NULL pointer of the destination type.
+///
+/// The library implementation builds the pointer from an address and its metadata, which can
+/// produce a pointer whose type does not match the one it is later compared against. Both
+/// functions require the pointee to be `Thin`, so the result is never a fat pointer.
+struct PtrNull;
+
+impl GotocHook for PtrNull {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        matches!(
+            instance.def.name().as_str(),
+            "core::ptr::null" | "core::ptr::null_mut" | "std::ptr::null" | "std::ptr::null_mut"
+        )
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert!(fargs.is_empty());
+        let loc = gcx.codegen_span_stable(span);
+        let target = target.unwrap();
+        let pointee_ty = *instance.args().0[0].expect_ty();
+        assert!(gcx.use_thin_pointer_stable(pointee_ty), "Expected `Thin` pointee: {pointee_ty:?}");
+        let null = gcx.codegen_ty_stable(pointee_ty).to_pointer().null();
+        Stmt::block(
+            vec![
+                gcx.codegen_expr_to_place_stable(assign_to, null, loc),
+                Stmt::goto(bb_label(target), loc),
+            ],
+            loc,
+        )
+    }
+}
+
+/// This hook models `slice::from_raw_parts` and `slice::from_raw_parts_mut` by building the
+/// slice fat pointer directly from the data pointer and the length.
+///
+/// The library checks its safety preconditions only when UB checks are enabled, which Kani
+/// disables. Instead, we check that the total size of the slice, `len * size_of::<T>()`, does not
+/// exceed `isize::MAX`. This can never happen for ZSTs, so no check is added in that case.
+struct SliceFromRawParts;
+
+impl GotocHook for SliceFromRawParts {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        matches!(
+            instance.def.name().as_str(),
+            "core::slice::from_raw_parts"
+                | "core::slice::from_raw_parts_mut"
+                | "core::slice::raw::from_raw_parts"
+                | "core::slice::raw::from_raw_parts_mut"
+                | "std::slice::from_raw_parts"
+                | "std::slice::from_raw_parts_mut"
+        )
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        debug!(?instance, "Replace slice from raw parts");
+        let loc = gcx.codegen_span_stable(span);
+        let target = target.unwrap();
+        let elem_ty = *instance.args().0[0].expect_ty();
+        let data = fargs.remove(0);
+        let len = fargs.remove(0);
+
+        let mut stmts = vec![];
+        if !gcx.is_zst_stable(elem_ty) {
+            let elem_size =
+                Expr::int_constant(gcx.layout_of_stable(elem_ty).size.bytes(), Type::size_t());
+            let max_len = Type::ssize_t()
+                .max_int_expr(gcx.symbol_table.machine_model())
+                .cast_to(Type::size_t())
+                .div(elem_size);
+            stmts.push(gcx.codegen_assert_assume(
+                len.clone().le(max_len),
+                PropertyClass::SafetyCheck,
+                "`slice::from_raw_parts`: the total size of the slice must be no larger than \
+                `isize::MAX`",
+                loc,
+            ));
+        }
+        let slice_ty = gcx.place_ty_stable(assign_to);
+        let slice_typ = gcx.codegen_ty_stable(slice_ty);
+        let data_typ = gcx.codegen_ty_stable(elem_ty).to_pointer();
+        let slice = utils::slice_fat_ptr(slice_typ, data.cast_to(data_typ), len, &gcx.symbol_table);
+        stmts.push(gcx.codegen_expr_to_place_stable(assign_to, slice, loc));
+        stmts.push(Stmt::goto(bb_label(target), loc));
+        Stmt::block(stmts, loc)
+    }
+}
+
+/// This hook replaces the branch hints `likely` and `unlikely` by their argument.
+///
+/// These functions only affect the code layout chosen by the backend, so there is no need to
+/// codegen their bodies, which introduce extra branches and a call to `cold_path`.
+struct BranchHint;
+
+impl GotocHook for BranchHint {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        matches!(
+            instance.def.name().as_str(),
+            "core::intrinsics::likely" | "core::intrinsics::unlikely"
+        )
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let loc = gcx.codegen_span_stable(span);
+        let target = target.unwrap();
+        Stmt::block(
+            vec![
+                gcx.codegen_expr_to_place_stable(assign_to, fargs.pop().unwrap(), loc),
+                Stmt::goto(bb_label(target), loc),
+            ],
+            loc,
+        )
+    }
+}
+
+/// This hook models `<[T]>::get` and `<[T]>::get_mut` for `usize` and `Range<usize>` indices,
+/// instead of going through the range checks of the `SliceIndex` implementations:
+/// - `get(i)` returns `Some(&slice[i])` if `i < len`, and `None` otherwise.
+/// - `get(a..b)` returns `Some(&slice[a..b])` if `a <= b <= len`, and `None` otherwise.
+///
+/// Other index types, such as `RangeTo<usize>`, are handled by the library code.
+struct SliceGet;
+
+impl SliceGet {
+    fn is_usize(ty: Ty) -> bool {
+        matches!(ty.kind(), TyKind::RigidTy(RigidTy::Uint(UintTy::Usize)))
+    }
+
+    fn is_usize_range(ty: Ty) -> bool {
+        match ty.kind() {
+            TyKind::RigidTy(RigidTy::Adt(def, args)) => {
+                def.krate().name == "core"
+                    && def.trimmed_name() == "Range"
+                    && Self::is_usize(*args.0[0].expect_ty())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl GotocHook for SliceGet {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        let is_get = matches!(
+            instance.def.name().as_str(),
+            "core::slice::<impl [T]>::get"
+                | "core::slice::<impl [T]>::get_mut"
+                | "std::slice::<impl [T]>::get"
+                | "std::slice::<impl [T]>::get_mut"
+        );
+        is_get && {
+            let index_ty = *instance.args().0[1].expect_ty();
+            Self::is_usize(index_ty) || Self::is_usize_range(index_ty)
+        }
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        debug!(?instance, "Replace slice get");
+        let loc = gcx.codegen_span_stable(span);
+        let target = target.unwrap();
+        let elem_ty = *instance.args().0[0].expect_ty();
+        let index_ty = *instance.args().0[1].expect_ty();
+        let slice = fargs.remove(0);
+        let index = fargs.remove(0);
+        let data = slice.clone().member("data", &gcx.symbol_table);
+        let len = slice.member("len", &gcx.symbol_table);
+        // Pointers to ZST elements all have the same address as the slice data.
+        let is_zst = gcx.is_zst_stable(elem_ty);
+        let elem_ptr = |offset: Expr| if is_zst { data.clone() } else { data.clone().plus(offset) };
+
+        // The result is an `Option<&I::Output>`.
+        let res_ty = gcx.place_ty_stable(assign_to);
+        let TyKind::RigidTy(RigidTy::Adt(_, args)) = res_ty.kind() else {
+            unreachable!("Expected `Option` return type, but found `{res_ty}`")
+        };
+        let ref_typ = gcx.codegen_ty_stable(*args.0[0].expect_ty());
+        let (in_bounds, reference) = if Self::is_usize(index_ty) {
+            (index.clone().lt(len), elem_ptr(index).cast_to(ref_typ))
+        } else {
+            let start = index.clone().member("start", &gcx.symbol_table);
+            let end = index.member("end", &gcx.symbol_table);
+            let in_bounds = start.clone().le(end.clone()).and(end.clone().le(len));
+            let reference = utils::slice_fat_ptr(
+                ref_typ,
+                elem_ptr(start.clone()),
+                end.sub(start),
+                &gcx.symbol_table,
+            );
+            (in_bounds, reference)
+        };
+        let some = gcx.codegen_enum_value(VariantIdx::to_val(1), vec![reference], res_ty, loc);
+        let none = gcx.codegen_enum_value(VariantIdx::to_val(0), vec![], res_ty, loc);
+        let place_expr = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        )
+        .goto_expr;
+        Stmt::block(
+            vec![
+                place_expr.assign(in_bounds.ternary(some, none), loc),
+                Stmt::goto(bb_label(target), loc),
+            ],
+            loc,
+        )
+    }
+}
+
 /// This hook intercepts calls to `memcmp` and skips CBMC's pointer checks if the number of bytes to be compared is zero.
 /// See issue <https://github.com/model-checking/kani/issues/1489>
 ///
@@ -761,12 +1209,14 @@ fn handle(
     }
 }
 
-pub fn fn_hooks() -> GotocHooks {
+pub fn fn_hooks(queries: &QueryDb) -> GotocHooks {
     let kani_lib_hooks = [
         (KaniHook::Assert, Rc::new(Assert) as Rc<dyn GotocHook>),
+        (KaniHook::AssertTagged, Rc::new(AssertTagged)),
         (KaniHook::Assume, Rc::new(Assume)),
         (KaniHook::Panic, Rc::new(Panic)),
         (KaniHook::Check, Rc::new(Check)),
+        (KaniHook::ContractAssert, Rc::new(ContractAssert)),
         (KaniHook::Cover, Rc::new(Cover)),
         (KaniHook::AnyRaw, Rc::new(Nondet)),
         (KaniHook::SafetyCheck, Rc::new(SafetyCheck)),
@@ -779,15 +1229,28 @@ pub fn fn_hooks() -> GotocHooks {
         (KaniHook::InitContracts, Rc::new(InitContracts)),
         (KaniHook::FloatToIntInRange, Rc::new(FloatToIntInRange)),
     ];
-    GotocHooks {
-        kani_lib_hooks: HashMap::from(kani_lib_hooks),
-        other_hooks: vec![
-            Rc::new(Panic),
-            Rc::new(RustAlloc),
-            Rc::new(MemCmp),
-            Rc::new(LoopInvariantRegister),
-        ],
+    let mut other_hooks: Vec<Rc<dyn GotocHook>> = vec![
+        Rc::new(Panic),
+        Rc::new(MemCmp),
+        Rc::new(SliceGet),
+        Rc::new(BranchHint),
+        Rc::new(PtrNull),
+        Rc::new(SliceFromRawParts),
+        Rc::new(LoopInvariantRegister),
+    ];
+    // This hook skips the body of the copy functions, which is where the extra UB checks are
+    // instrumented, so only use it when those checks are disabled.
+    if queries.args().ub_check.is_empty() {
+        other_hooks.push(Rc::new(PtrCopy));
+    }
+    if queries.args().unstable_features.contains(&"catch-unwind".to_string()) {
+        other_hooks.push(Rc::new(PanicCleanup));
+    }
+    // Allocations must go through the user's global allocator when verifying it.
+    if !queries.args().verify_allocator {
+        other_hooks.push(Rc::new(RustAlloc));
     }
+    GotocHooks { kani_lib_hooks: HashMap::from(kani_lib_hooks), other_hooks }
 }
 
 pub struct GotocHooks {
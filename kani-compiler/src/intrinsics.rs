@@ -40,6 +40,7 @@ pub enum Intrinsic {
     BlackBox,
     Breakpoint,
     Bswap,
+    CatchUnwind,
     CeilF32,
     CeilF64,
     CompareBytes,
@@ -96,6 +97,7 @@ pub enum Intrinsic {
     PtrOffsetFrom,
     PtrOffsetFromUnsigned,
     RawEq,
+    ReadViaCopy,
     RetagBoxToRaw,
     RotateLeft,
     RotateRight,
@@ -150,6 +152,7 @@ pub enum Intrinsic {
     WrappingMul,
     WrappingSub,
     WriteBytes,
+    WriteViaMove,
     Unimplemented { name: String, issue_link: String },
 }
 
@@ -230,10 +233,7 @@ pub fn from_instance(intrinsic_instance: &Instance) -> Self {
             }
             "catch_unwind" => {
                 assert_sig_matches!(sig, RigidTy::FnPtr(_), RigidTy::RawPtr(_, Mutability::Mut), RigidTy::FnPtr(_) => RigidTy::Int(IntTy::I32));
-                Self::Unimplemented {
-                    name: intrinsic_str,
-                    issue_link: "https://github.com/model-checking/kani/issues/267".into(),
-                }
+                Self::CatchUnwind
             }
             "compare_bytes" => {
                 assert_sig_matches!(sig, RigidTy::RawPtr(_, Mutability::Not), RigidTy::RawPtr(_, Mutability::Not), RigidTy::Uint(UintTy::Usize) => RigidTy::Int(IntTy::I32));
@@ -350,6 +350,10 @@ pub fn from_instance(intrinsic_instance: &Instance) -> Self {
                 assert_sig_matches!(sig, RigidTy::Ref(_, _, Mutability::Not), RigidTy::Ref(_, _, Mutability::Not) => RigidTy::Bool);
                 Self::RawEq
             }
+            "read_via_copy" => {
+                assert_sig_matches!(sig, RigidTy::RawPtr(_, Mutability::Not) => _);
+                Self::ReadViaCopy
+            }
             "rotate_left" => {
                 assert_sig_matches!(sig, _, RigidTy::Uint(UintTy::U32) => _);
                 Self::RotateLeft
@@ -454,6 +458,10 @@ pub fn from_instance(intrinsic_instance: &Instance) -> Self {
                 assert_sig_matches!(sig, RigidTy::RawPtr(_, Mutability::Mut), RigidTy::Uint(UintTy::U8), RigidTy::Uint(UintTy::Usize) => RigidTy::Tuple(_));
                 Self::WriteBytes
             }
+            "write_via_move" => {
+                assert_sig_matches!(sig, RigidTy::RawPtr(_, Mutability::Mut), _ => RigidTy::Tuple(_));
+                Self::WriteViaMove
+            }
             _ => try_match_atomic(intrinsic_instance)
                 .or_else(|| try_match_simd(intrinsic_instance))
                 .or_else(|| try_match_f32(intrinsic_instance))
@@ -28,13 +28,22 @@
 #[derive(Debug, Clone, Copy, AsRefStr, EnumString, PartialEq, Eq, PartialOrd, Ord)]
 #[strum(serialize_all = "snake_case")]
 enum KaniAttributeKind {
+    /// Attribute used to specify the expected verification result of a harness,
+    /// e.g. `#[kani::expect(failure)]`.
+    Expect,
     Proof,
     ShouldPanic,
     Solver,
+    /// Attribute used to bound the time spent verifying a harness,
+    /// e.g. `#[kani::solver_timeout(secs = 60)]`.
+    SolverTimeout,
     Stub,
     /// Attribute used to mark unstable APIs.
     Unstable,
     Unwind,
+    /// Attribute used to bound the number of iterations of a single loop of a harness with an
+    /// assumption, e.g. `#[kani::unwind_assume(loop_id = 0, bound = 10)]`.
+    UnwindAssume,
     /// A sound [`Self::Stub`] that replaces a function by a stub generated from
     /// its contract.
     StubVerified,
@@ -82,13 +91,16 @@ impl KaniAttributeKind {
     /// Returns whether an item is only relevant for harnesses.
     pub fn is_harness_only(self) -> bool {
         match self {
-            KaniAttributeKind::Proof
+            KaniAttributeKind::Expect
+            | KaniAttributeKind::Proof
             | KaniAttributeKind::ShouldPanic
             | KaniAttributeKind::Solver
+            | KaniAttributeKind::SolverTimeout
             | KaniAttributeKind::Stub
             | KaniAttributeKind::ProofForContract
             | KaniAttributeKind::StubVerified
-            | KaniAttributeKind::Unwind => true,
+            | KaniAttributeKind::Unwind
+            | KaniAttributeKind::UnwindAssume => true,
             KaniAttributeKind::Unstable
             | KaniAttributeKind::FnMarker
             | KaniAttributeKind::Recursion
@@ -127,6 +139,15 @@ pub struct KaniAttributes<'tcx> {
     map: BTreeMap<KaniAttributeKind, Vec<&'tcx Attribute>>,
 }
 
+/// A bound on the number of iterations of a loop that should be assumed instead of checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnwindAssumption {
+    /// The index of the loop in the harness body, following the order of the loop heads.
+    pub loop_id: u32,
+    /// The maximum number of times the loop back-edge can be taken.
+    pub bound: u32,
+}
+
 #[derive(Clone, Debug)]
 /// Bundle contract attributes for a function annotated with contracts.
 pub struct ContractAttributes {
@@ -310,6 +331,16 @@ pub fn contract_attributes(&self) -> Option<ContractAttributes> {
         })
     }
 
+    /// Return the loop bounds that should be assumed for this harness, if any.
+    ///
+    /// Invalid attributes are ignored, since they are reported by [Self::check_attributes].
+    pub fn unwind_assumptions(&self) -> Vec<UnwindAssumption> {
+        self.map
+            .get(&KaniAttributeKind::UnwindAssume)
+            .map(|attrs| attrs.iter().filter_map(|attr| parse_unwind_assume(attr).ok()).collect())
+            .unwrap_or_default()
+    }
+
     // Is this a function inserted by Kani instrumentation?
     pub fn is_kani_instrumentation(&self) -> bool {
         self.fn_marker().is_some() || self.is_contract_generated()
@@ -368,12 +399,26 @@ pub(super) fn check_attributes(&self) {
                         expect_no_args(self.tcx, kind, attr);
                     })
                 }
+                KaniAttributeKind::Expect => {
+                    expect_single(self.tcx, kind, &attrs);
+                    parse_expect(self.tcx, attrs[0]);
+                    if self.map.contains_key(&KaniAttributeKind::ShouldPanic) {
+                        local_error(
+                            "the `expect` attribute cannot be used together with `should_panic`"
+                                .to_string(),
+                        );
+                    }
+                }
                 KaniAttributeKind::Solver => {
                     expect_single(self.tcx, kind, &attrs);
                     attrs.iter().for_each(|attr| {
                         parse_solver(self.tcx, attr);
                     })
                 }
+                KaniAttributeKind::SolverTimeout => {
+                    expect_single(self.tcx, kind, &attrs);
+                    parse_solver_timeout(self.tcx, attrs[0]);
+                }
                 KaniAttributeKind::Stub => {
                     parse_stubs(self.tcx, self.item, attrs);
                 }
@@ -383,6 +428,32 @@ pub(super) fn check_attributes(&self) {
                         parse_unwind(self.tcx, attr);
                     })
                 }
+                KaniAttributeKind::UnwindAssume => {
+                    let mut loop_ids = HashSet::new();
+                    for attr in attrs {
+                        match parse_unwind_assume(attr) {
+                            Ok(assumption) if !loop_ids.insert(assumption.loop_id) => {
+                                self.tcx.dcx().span_err(
+                                    attr.span(),
+                                    format!(
+                                        "duplicated `unwind_assume` attribute for loop {}",
+                                        assumption.loop_id
+                                    ),
+                                );
+                            }
+                            Ok(_) => {}
+                            Err(msg) => {
+                                self.tcx.dcx().span_err(
+                                    attr.span(),
+                                    format!(
+                                        "invalid argument for `#[kani::unwind_assume]` attribute, \
+                                        expected `loop_id = <INTEGER>, bound = <INTEGER>`: {msg}"
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
                 KaniAttributeKind::Proof => {
                     if self.map.contains_key(&KaniAttributeKind::ProofForContract) {
                         local_error(
@@ -469,6 +540,19 @@ pub fn check_unstable_features(&self, enabled_features: &[String]) {
             }
         }
 
+        // Assuming a loop bound is unsound, so users must opt-in with `-Z assume-unwind`.
+        if !enabled_features.iter().any(|feature| feature == "assume-unwind") {
+            if let Some(attr) =
+                self.map.get(&KaniAttributeKind::UnwindAssume).and_then(|a| a.first())
+            {
+                self.tcx.dcx().span_err(
+                    attr.span(),
+                    "Using the `unwind_assume` attribute requires activating the unstable \
+                    `assume-unwind` feature",
+                );
+            }
+        }
+
         if let Some(unstable_attrs) = self.map.get(&KaniAttributeKind::Unstable) {
             for attr in unstable_attrs {
                 let unstable_attr = UnstableAttribute::try_from(*attr).unwrap();
@@ -540,18 +624,28 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
         self.map.iter().fold(harness_attrs, |mut harness, (kind, attributes)| {
             match kind {
                 KaniAttributeKind::ShouldPanic => harness.should_panic = true,
+                KaniAttributeKind::Expect => {
+                    harness.expect_failure = parse_expect(self.tcx, attributes[0])
+                }
                 KaniAttributeKind::Recursion => {
                     self.tcx.dcx().span_err(self.tcx.def_span(self.item), "The attribute `kani::recursion` should only be used in combination with function contracts.");
                 }
                 KaniAttributeKind::Solver => {
                     harness.solver = parse_solver(self.tcx, attributes[0]);
                 }
+                KaniAttributeKind::SolverTimeout => {
+                    harness.solver_timeout = parse_solver_timeout(self.tcx, attributes[0]);
+                }
                 KaniAttributeKind::Stub => {
                     harness.stubs.extend_from_slice(&parse_stubs(self.tcx, self.item, attributes));
                 }
                 KaniAttributeKind::Unwind => {
                     harness.unwind_value = parse_unwind(self.tcx, attributes[0])
                 }
+                KaniAttributeKind::UnwindAssume => {
+                    // The assumptions are instrumented during codegen of the harness body.
+                    // See [KaniAttributes::unwind_assumptions].
+                }
                 KaniAttributeKind::Proof => { /* no-op */ }
                 KaniAttributeKind::ProofForContract => self.handle_proof_for_contract(&mut harness),
                 KaniAttributeKind::StubVerified => self.handle_stub_verified(&mut harness),
@@ -857,6 +951,43 @@ fn parse_unwind(tcx: TyCtxt, attr: &Attribute) -> Option<u32> {
     }
 }
 
+/// Parse the argument of `#[kani::solver_timeout(secs = <SECONDS>)]`.
+fn parse_solver_timeout(tcx: TyCtxt, attr: &Attribute) -> Option<u32> {
+    let secs = parse_key_values(attr).and_then(|key_values| {
+        if let Some(key) = key_values.keys().find(|key| *key != "secs") {
+            return Err(format!("unexpected key `{key}`"));
+        }
+        match key_values.get("secs").map(|value| value.parse::<u32>()) {
+            Some(Ok(secs)) if secs > 0 => Ok(secs),
+            Some(_) => Err("`secs` must be a positive `u32` value".to_string()),
+            None => Err("missing `secs`".to_string()),
+        }
+    });
+    secs.map_err(|msg| {
+        tcx.dcx().span_err(
+            attr.span(),
+            format!(
+                "invalid argument for `#[kani::solver_timeout]` attribute, \
+                expected `secs = <INTEGER>`: {msg}"
+            ),
+        );
+    })
+    .ok()
+}
+
+/// Parse the arguments of `#[kani::unwind_assume(loop_id = <ID>, bound = <BOUND>)]`.
+fn parse_unwind_assume(attr: &Attribute) -> Result<UnwindAssumption, String> {
+    let key_values = parse_key_values(attr)?;
+    if let Some(key) = key_values.keys().find(|key| *key != "loop_id" && *key != "bound") {
+        return Err(format!("unexpected key `{key}`"));
+    }
+    let parse_value = |key: &str| match key_values.get(key) {
+        Some(value) => value.parse::<u32>().map_err(|_| format!("`{key}` must be a `u32` value")),
+        None => Err(format!("missing `{key}`")),
+    };
+    Ok(UnwindAssumption { loop_id: parse_value("loop_id")?, bound: parse_value("bound")? })
+}
+
 fn parse_stubs(tcx: TyCtxt, harness: DefId, attributes: &[&Attribute]) -> Vec<Stub> {
     let current_module = tcx.parent_module_from_def_id(harness.expect_local());
     let check_resolve = |attr: &Attribute, path: &TypePath| {
@@ -917,6 +1048,31 @@ fn parse_stubs(tcx: TyCtxt, harness: DefId, attributes: &[&Attribute]) -> Vec<St
         .collect()
 }
 
+/// Parse the argument of `#[kani::expect(..)]`. Returns whether the harness is expected to fail.
+///
+/// For now, `failure` is the only supported expectation.
+fn parse_expect(tcx: TyCtxt, attr: &Attribute) -> bool {
+    const ATTRIBUTE: &str = "#[kani::expect]";
+    let attr_args = attr.meta_item_list().unwrap_or_default();
+    match attr_args.as_slice() {
+        [arg]
+            if arg.meta_item().is_some_and(|meta| {
+                matches!(meta.kind, MetaItemKind::Word)
+                    && meta.ident().is_some_and(|ident| ident.as_str() == "failure")
+            }) =>
+        {
+            true
+        }
+        _ => {
+            tcx.dcx().span_err(
+                attr.span(),
+                format!("invalid argument for `{ATTRIBUTE}` attribute, expected `failure`"),
+            );
+            false
+        }
+    }
+}
+
 fn parse_solver(tcx: TyCtxt, attr: &Attribute) -> Option<CbmcSolver> {
     // TODO: Argument validation should be done as part of the `kani_macros` crate
     // <https://github.com/model-checking/kani/issues/2192>
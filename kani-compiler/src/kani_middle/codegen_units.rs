@@ -15,11 +15,11 @@
 };
 use crate::kani_middle::reachability::filter_crate_items;
 use crate::kani_middle::resolve::expect_resolve_fn;
-use crate::kani_middle::stubbing::{check_compatibility, harness_stub_map};
+use crate::kani_middle::stubbing::{check_compatibility, harness_stub_map, name_stub_map};
 use crate::kani_queries::QueryDb;
 use kani_metadata::{
     ArtifactType, AssignsContract, AutoHarnessSkipReason, AutoHarnessSkippedFns, HarnessKind,
-    HarnessMetadata, KaniMetadata,
+    HarnessMetadata, KaniMetadata, UnsupportedFeature,
 };
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::TyCtxt;
@@ -70,8 +70,9 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
         match args.reachability_analysis {
             ReachabilityType::Harnesses => {
                 let all_harnesses = get_all_manual_harnesses(tcx, base_filename);
+                let name_stubs = name_stubs(tcx, &args.stub_by_name, &all_harnesses);
                 // Even if no_stubs is empty we still need to store rustc metadata.
-                let units = group_by_stubs(tcx, &all_harnesses);
+                let units = group_by_stubs(tcx, &all_harnesses, &name_stubs);
                 validate_units(tcx, &units);
                 debug!(?units, "CodegenUnits::new");
                 CodegenUnits {
@@ -83,7 +84,8 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
             }
             ReachabilityType::AllFns => {
                 let mut all_harnesses = get_all_manual_harnesses(tcx, base_filename);
-                let mut units = group_by_stubs(tcx, &all_harnesses);
+                let name_stubs = name_stubs(tcx, &args.stub_by_name, &all_harnesses);
+                let mut units = group_by_stubs(tcx, &all_harnesses, &name_stubs);
                 validate_units(tcx, &units);
 
                 let kani_fns = queries.kani_functions();
@@ -155,6 +157,16 @@ pub fn store_loop_contracts(&mut self, harnesses: &[Harness]) {
         }
     }
 
+    /// We store the unsupported constructs found in the code reachable from each harness.
+    pub fn store_unsupported_features(
+        &mut self,
+        harness_features: Vec<(Harness, Vec<UnsupportedFeature>)>,
+    ) {
+        for (harness, features) in harness_features {
+            self.harness_info.get_mut(&harness).unwrap().unsupported_features = features;
+        }
+    }
+
     /// Write compilation metadata into a file.
     pub fn write_metadata(&self, queries: &QueryDb, tcx: TyCtxt) {
         let metadata = self.generate_metadata(tcx);
@@ -191,14 +203,32 @@ fn stub_def(tcx: TyCtxt, def_id: DefId) -> FnDef {
     }
 }
 
+/// Resolve the stubs passed via `--stub-by-name`.
+///
+/// The paths are only resolved if the crate has harnesses. Every target of a package is compiled
+/// with the same arguments, so the paths may not exist in targets that are not being verified,
+/// e.g., the binary of a package whose harnesses are in the library.
+fn name_stubs(
+    tcx: TyCtxt,
+    stubs: &[String],
+    all_harnesses: &HashMap<Harness, HarnessMetadata>,
+) -> HashMap<DefId, DefId> {
+    if all_harnesses.is_empty() { HashMap::default() } else { name_stub_map(tcx, stubs) }
+}
+
 /// Group the harnesses by their stubs and contract usage.
+///
+/// The stubs passed via `--stub-by-name` apply to every harness, unless the harness stubs the
+/// same function via an attribute.
 fn group_by_stubs(
     tcx: TyCtxt,
     all_harnesses: &HashMap<Harness, HarnessMetadata>,
+    name_stubs: &HashMap<DefId, DefId>,
 ) -> Vec<CodegenUnit> {
     let mut per_stubs: HashMap<_, CodegenUnit> = HashMap::default();
     for (harness, metadata) in all_harnesses {
-        let stub_ids = harness_stub_map(tcx, *harness, metadata);
+        let mut stub_ids = name_stubs.clone();
+        stub_ids.extend(harness_stub_map(tcx, *harness, metadata));
         let contracts = extract_contracts(tcx, *harness, metadata);
         let stub_map = stub_ids
             .iter()
@@ -48,10 +48,14 @@ pub enum KaniIntrinsic {
     CheckedAlignOf,
     #[strum(serialize = "CheckedSizeOfIntrinsic")]
     CheckedSizeOf,
+    #[strum(serialize = "DebugAssertionsEnabledIntrinsic")]
+    DebugAssertionsEnabled,
     #[strum(serialize = "AutomaticHarnessIntrinsic")]
     AutomaticHarness,
     #[strum(serialize = "IsInitializedIntrinsic")]
     IsInitialized,
+    #[strum(serialize = "PoisonIntrinsic")]
+    Poison,
     #[strum(serialize = "ValidValueIntrinsic")]
     ValidValue,
     #[strum(serialize = "WriteAnyIntrinsic")]
@@ -127,10 +131,14 @@ pub enum KaniHook {
     AnyRaw,
     #[strum(serialize = "AssertHook")]
     Assert,
+    #[strum(serialize = "AssertTaggedHook")]
+    AssertTagged,
     #[strum(serialize = "AssumeHook")]
     Assume,
     #[strum(serialize = "CheckHook")]
     Check,
+    #[strum(serialize = "ContractAssertHook")]
+    ContractAssert,
     #[strum(serialize = "CoverHook")]
     Cover,
     // TODO: this is temporarily implemented as a hook, but should be implemented as an intrinsic
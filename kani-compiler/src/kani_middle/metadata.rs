@@ -40,6 +40,7 @@ pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) ->
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: false,
+        unsupported_features: vec![],
     }
 }
 
@@ -124,6 +125,7 @@ pub fn gen_automatic_proof_metadata(
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: true,
+        unsupported_features: vec![],
     }
 }
 
@@ -154,5 +156,6 @@ pub fn gen_test_metadata(
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: false,
+        unsupported_features: vec![],
     }
 }
@@ -25,6 +25,7 @@
 mod intrinsics;
 pub mod kani_functions;
 pub mod metadata;
+pub mod nondet_budget;
 pub mod points_to;
 pub mod provide;
 pub mod reachability;
@@ -127,7 +128,6 @@ pub fn check_reachable_items(tcx: TyCtxt, queries: &QueryDb, items: &[MonoItem])
 pub struct SourceLocation {
     pub filename: String,
     pub start_line: usize,
-    #[allow(dead_code)]
     pub start_col: usize, // set, but not currently used in Goto output
     pub end_line: usize,
     #[allow(dead_code)]
@@ -0,0 +1,180 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the nondeterministic input of a harness does not exceed the budget set by
+//! `--max-nondet-bytes`.
+//!
+//! All nondeterministic values are generated by `kani::any_raw`, so we tally the size of the
+//! values returned by every call to it that is reachable from the harness. The tally is static:
+//! every call site is counted once per path in the call tree, calls inside loops are only counted
+//! once, and recursive calls as well as calls through function pointers or vtables are ignored.
+//!
+//! Arrays of types that are a single unconstrained symbolic value are generated as a single
+//! `any_raw` call of the array type, so they count their entire length. Other arrays are generated
+//! one element at a time by `core::array::from_fn`, whose closure is only visited once, so we
+//! multiply its tally by the array length. The same applies to `<[T; N]>::map`. Symbolic-length
+//! slices and vectors are generated from an array of their maximum length, so they count their
+//! maximum bound.
+
+use crate::kani_middle::kani_functions::{KaniFunction, KaniHook};
+use crate::kani_middle::transform::BodyTransformation;
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::{Instance, MonoItem};
+use stable_mir::mir::{Body, TerminatorKind};
+use stable_mir::ty::{RigidTy, Span, TyKind};
+use std::collections::{HashMap, HashSet};
+
+/// Emit an error for every harness whose nondeterministic input exceeds `budget` bytes.
+pub fn check_nondet_budget(
+    tcx: TyCtxt,
+    transformer: &mut BodyTransformation,
+    starting_items: &[MonoItem],
+    budget: u64,
+) {
+    let mut tally =
+        NondetTally { tcx, transformer, cache: HashMap::new(), visiting: HashSet::new() };
+    for item in starting_items {
+        let MonoItem::Fn(harness) = item else { continue };
+        let total = tally.bytes(*harness);
+        if total <= budget {
+            continue;
+        }
+        let msg = format!(
+            "harness `{}` generates {total} bytes of nondeterministic input, which exceeds the \
+            limit of {budget} bytes set by `--max-nondet-bytes`",
+            harness.name()
+        );
+        if let Some(site) = tally.largest_call_site(*harness) {
+            let span = rustc_internal::internal(tcx, site.span);
+            tcx.dcx()
+                .struct_span_err(span, msg)
+                .with_span_label(
+                    span,
+                    format!("the largest contributor generates {} bytes", site.bytes),
+                )
+                .emit();
+        } else {
+            tcx.dcx().err(msg);
+        }
+    }
+}
+
+/// A call that contributes to the nondeterministic input of a function.
+#[derive(Clone, Copy, Debug)]
+struct CallSite {
+    span: Span,
+    callee: Instance,
+    bytes: u64,
+}
+
+struct NondetTally<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    transformer: &'a mut BodyTransformation,
+    /// Number of nondeterministic bytes generated by each function that was already visited.
+    cache: HashMap<Instance, u64>,
+    /// Functions that are currently being visited, used to ignore recursive calls.
+    visiting: HashSet<Instance>,
+}
+
+impl NondetTally<'_, '_> {
+    /// The number of nondeterministic bytes generated by a call to the given function.
+    fn bytes(&mut self, instance: Instance) -> u64 {
+        if let Some(bytes) = self.cache.get(&instance) {
+            return *bytes;
+        }
+        if !self.visiting.insert(instance) {
+            return 0;
+        }
+        let bytes = self.call_sites(instance).iter().map(|site| site.bytes).sum();
+        self.visiting.remove(&instance);
+        self.cache.insert(instance, bytes);
+        bytes
+    }
+
+    /// Find the call site that contributes the most to the nondeterministic input of the given
+    /// function. We follow the largest contributor while it is defined in the local crate, so the
+    /// result points to user code, e.g., a call to `kani::any()`.
+    fn largest_call_site(&mut self, instance: Instance) -> Option<CallSite> {
+        let mut visited = HashSet::from([instance]);
+        let mut largest = self.max_call_site(instance)?;
+        while largest.callee.def.krate().is_local && visited.insert(largest.callee) {
+            match self.max_call_site(largest.callee) {
+                Some(site) => largest = site,
+                None => break,
+            }
+        }
+        Some(largest)
+    }
+
+    fn max_call_site(&mut self, instance: Instance) -> Option<CallSite> {
+        self.call_sites(instance)
+            .into_iter()
+            .filter(|site| site.bytes > 0)
+            .max_by_key(|site| site.bytes)
+    }
+
+    /// Collect the calls in the body of the given function that generate nondeterministic values.
+    fn call_sites(&mut self, instance: Instance) -> Vec<CallSite> {
+        if !instance.has_body() {
+            return vec![];
+        }
+        let body = self.transformer.body(self.tcx, instance);
+        resolved_calls(&body)
+            .into_iter()
+            .map(|call| {
+                let bytes = call.bytes.unwrap_or_else(|| call.repeat * self.bytes(call.callee));
+                CallSite { span: call.span, callee: call.callee, bytes }
+            })
+            .collect()
+    }
+}
+
+/// A function call in a body.
+struct ResolvedCall {
+    span: Span,
+    callee: Instance,
+    /// For calls to `kani::any_raw`, the size of the generated value.
+    bytes: Option<u64>,
+    /// How many times the nondeterministic input of the callee is generated by this call.
+    repeat: u64,
+}
+
+/// Resolve the functions called in the given body.
+fn resolved_calls(body: &Body) -> Vec<ResolvedCall> {
+    body.blocks
+        .iter()
+        .filter_map(|block| {
+            let TerminatorKind::Call { func, destination, .. } = &block.terminator.kind else {
+                return None;
+            };
+            let TyKind::RigidTy(RigidTy::FnDef(def, args)) = func.ty(body.locals()).ok()?.kind()
+            else {
+                return None;
+            };
+            let callee = Instance::resolve(def, &args).ok()?;
+            let ty = destination.ty(body.locals()).ok()?;
+            let bytes = if KaniFunction::try_from(callee) == Ok(KaniHook::AnyRaw.into()) {
+                Some(ty.layout().ok()?.shape().size.bytes() as u64)
+            } else {
+                None
+            };
+            let repeat = match ty.kind() {
+                TyKind::RigidTy(RigidTy::Array(_, len)) if is_per_element_fn(callee) => {
+                    len.eval_target_usize().ok()?
+                }
+                _ => 1,
+            };
+            Some(ResolvedCall { span: block.terminator.span, callee, bytes, repeat })
+        })
+        .collect()
+}
+
+/// Whether the given function builds an array by calling a closure once per element.
+fn is_per_element_fn(instance: Instance) -> bool {
+    matches!(
+        instance.def.name().as_str(),
+        "core::array::from_fn" | "core::array::<impl [T; N]>::map"
+    )
+}
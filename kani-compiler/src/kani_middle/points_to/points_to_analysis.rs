@@ -284,14 +284,16 @@ fn apply_primary_terminator_effect<'mir>(
                             );
                         }
                         // Semantically equivalent to dest = *a
-                        Intrinsic::VolatileLoad | Intrinsic::UnalignedVolatileLoad => {
+                        Intrinsic::ReadViaCopy
+                        | Intrinsic::VolatileLoad
+                        | Intrinsic::UnalignedVolatileLoad => {
                             // Destination of the return value.
                             let lvalue_set = state.resolve_place(*destination, self.instance);
                             let rvalue_set = self.successors_for_deref(state, args[0].node.clone());
                             state.extend(&lvalue_set, &state.successors(&rvalue_set));
                         }
                         // Semantically equivalent *a = b.
-                        Intrinsic::VolatileStore => {
+                        Intrinsic::VolatileStore | Intrinsic::WriteViaMove => {
                             let lvalue_set = self.successors_for_deref(state, args[0].node.clone());
                             let rvalue_set =
                                 self.successors_for_operand(state, args[1].node.clone());
@@ -35,7 +35,7 @@
 use stable_mir::{CrateDef, ItemKind};
 use std::fmt::{Display, Formatter};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     fs::File,
     io::{BufWriter, Write},
 };
@@ -627,6 +627,42 @@ fn dump_dot(&self, tcx: TyCtxt, initial: Option<MonoItem>) -> std::io::Result<()
         Ok(())
     }
 
+    /// Print the graph in DOT format to the standard output.
+    ///
+    /// Unlike [CallGraph::dump_dot], every edge is printed only once, even if the callee was
+    /// collected multiple times (e.g., when a function is called from different places or when it
+    /// calls itself recursively). Nodes and edges are sorted by name so the output is stable.
+    pub fn print_dot(&self, starting_items: &[MonoItem]) {
+        self.write_dot(&mut std::io::stdout().lock(), starting_items)
+            .unwrap_or_else(|e| tracing::error!("Failed to print call graph: {e}"));
+    }
+
+    /// Write the deduplicated graph to the given writer.
+    fn write_dot<W: Write>(
+        &self,
+        writer: &mut W,
+        starting_items: &[MonoItem],
+    ) -> std::io::Result<()> {
+        let roots: Vec<_> =
+            starting_items.iter().map(|item| Node(item.clone()).to_string()).collect();
+        let nodes: BTreeSet<String> = self.nodes.iter().map(Node::to_string).collect();
+        let edges: BTreeSet<(String, String)> = self
+            .edges
+            .iter()
+            .flat_map(|(from, to)| to.iter().map(move |succ| (from.to_string(), succ.to_string())))
+            .collect();
+
+        writeln!(writer, "// Call graph of `{}`", roots.join("`, `"))?;
+        writeln!(writer, "digraph ReachabilityGraph {{")?;
+        for node in &nodes {
+            writeln!(writer, r#"  "{}""#, escape_dot(node))?;
+        }
+        for (from, to) in &edges {
+            writeln!(writer, r#"  "{}" -> "{}""#, escape_dot(from), escape_dot(to))?;
+        }
+        writeln!(writer, "}}")
+    }
+
     /// Write all notes to the given writer.
     fn dump_all<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         tracing::info!(nodes=?self.nodes.len(), edges=?self.edges.len(), "dump_all");
@@ -674,6 +710,11 @@ fn dump_reason<W: Write>(&self, writer: &mut W, target: &str) -> std::io::Result
     }
 }
 
+/// Escape the characters that cannot appear inside a quoted DOT identifier.
+fn escape_dot(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 impl Display for Node {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self.0 {
@@ -37,6 +37,62 @@ pub fn harness_stub_map(
     stub_pairs
 }
 
+/// Resolves the stubs passed via `--stub-by-name`.
+///
+/// Each stub has the format `ORIGINAL=REPLACEMENT`, where both sides are fully qualified paths
+/// of functions as printed by `tcx.def_path_str`, e.g., `some_crate::module::func`. Items of the
+/// local crate may be prefixed with `crate::`. Errors are emitted for malformed stubs and paths
+/// that cannot be resolved, but we delay aborting, so we emit as many errors as possible.
+pub fn name_stub_map(tcx: TyCtxt, stubs: &[String]) -> HashMap<DefId, DefId> {
+    stubs
+        .iter()
+        .filter_map(|stub| {
+            let Some((orig, new)) = stub.split_once('=') else {
+                tcx.dcx().err(format!(
+                    "invalid `--stub-by-name` value `{stub}`: expected `ORIGINAL=REPLACEMENT`"
+                ));
+                return None;
+            };
+            let orig = resolve_fn_by_name(tcx, orig.trim());
+            let new = resolve_fn_by_name(tcx, new.trim());
+            Some((orig?, new?))
+        })
+        .collect()
+}
+
+/// Find the function whose fully qualified path is `path`.
+fn resolve_fn_by_name(tcx: TyCtxt, path: &str) -> Option<DefId> {
+    let local_crate = stable_mir::local_crate();
+    let (crate_name, crate_path) = path.split_once("::").unwrap_or((path, ""));
+    // Paths of local items are printed without the crate name.
+    let (crates, expected) = if crate_name == "crate" || crate_name == local_crate.name {
+        (vec![local_crate], crate_path)
+    } else {
+        (stable_mir::find_crates(crate_name), path)
+    };
+    let candidates = crates
+        .iter()
+        .flat_map(|krate| krate.fn_defs())
+        .map(|def| rustc_internal::internal(tcx, def.def_id()))
+        .filter(|def_id| tcx.def_path_str(*def_id) == expected)
+        .unique()
+        .collect::<Vec<_>>();
+    match candidates.as_slice() {
+        [def_id] => Some(*def_id),
+        [] => {
+            tcx.dcx().err(format!("`--stub-by-name`: cannot find function `{path}`"));
+            None
+        }
+        _ => {
+            tcx.dcx().err(format!(
+                "`--stub-by-name`: path `{path}` is ambiguous, it matches {} functions",
+                candidates.len()
+            ));
+            None
+        }
+    }
+}
+
 /// Checks whether the stub is compatible with the original function/method: do
 /// the arities and types (of the parameters and return values) match up? This
 /// does **NOT** check whether the type variables are constrained to implement
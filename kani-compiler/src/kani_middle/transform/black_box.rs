@@ -0,0 +1,84 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Implement a transformation pass that removes calls to `core::hint::black_box`.
+//!
+//! `black_box` is an identity function whose only purpose is to stop the compiler from
+//! optimizing its argument. For verification, it has no semantic effect, but keeping it as a
+//! call makes it look like an opaque function with side effects to later passes.
+//!
+//! Thus, we replace every call `dest = black_box(arg) -> target` by the assignment
+//! `dest = arg`, followed by a `goto target`.
+use crate::kani_middle::abi::LayoutOf;
+use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{Body, Rvalue, Statement, StatementKind, Terminator, TerminatorKind};
+use stable_mir::ty::{RigidTy, TyKind};
+use tracing::debug;
+
+/// Replace calls to `core::hint::black_box` by a direct assignment of its argument.
+#[derive(Debug)]
+pub struct BlackBoxPass;
+
+impl TransformPass for BlackBoxPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        debug!(function=?instance.name(), "transform");
+        let mut new_body = MutableBody::from(body);
+        let mut changed = false;
+        for bb in 0..new_body.blocks().len() {
+            changed |= replace_black_box(&mut new_body, bb);
+        }
+        (changed, new_body.into())
+    }
+}
+
+/// Replace the terminator of the given basic block if it is a call to `black_box`.
+///
+/// Diverging calls (i.e., `black_box::<!>`) have no target and are left untouched.
+fn replace_black_box(body: &mut MutableBody, bb: usize) -> bool {
+    let terminator = &body.blocks()[bb].terminator;
+    let TerminatorKind::Call { func, args, destination, target: Some(target), .. } =
+        &terminator.kind
+    else {
+        return false;
+    };
+    let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).unwrap().kind() else {
+        return false;
+    };
+    if def.name() != "core::hint::black_box" {
+        return false;
+    }
+    let span = terminator.span;
+    let target = *target;
+    // For zero-sized types, there is nothing to be assigned.
+    // Otherwise, the argument is used as is, which works for both constants and places.
+    let dest_ty = destination.ty(body.locals()).unwrap();
+    let assign = (!LayoutOf::new(dest_ty).is_zst()).then(|| Statement {
+        kind: StatementKind::Assign(destination.clone(), Rvalue::Use(args[0].clone())),
+        span,
+    });
+    let mut source = SourceInstruction::Terminator { bb };
+    if let Some(stmt) = assign {
+        body.insert_stmt(stmt, &mut source, InsertPosition::Before);
+    }
+    body.replace_terminator(&source, Terminator { kind: TerminatorKind::Goto { target }, span });
+    true
+}
@@ -434,12 +434,20 @@ pub fn replace_statements(
         self.blocks.get_mut(source_instruction.bb()).unwrap().statements = new_stmts;
     }
 
-    /// Replace a terminator from the given basic block
+    /// Replace the terminator of the given basic block in place.
+    ///
+    /// This does not create any new basic block, so the number of blocks and the position of
+    /// every other instruction remain unchanged. The source instruction must point to the
+    /// terminator being replaced.
     pub fn replace_terminator(
         &mut self,
         source_instruction: &SourceInstruction,
         new_term: Terminator,
     ) {
+        assert!(
+            matches!(source_instruction, SourceInstruction::Terminator { .. }),
+            "Expected a terminator, but found `{source_instruction:?}`"
+        );
         self.blocks.get_mut(source_instruction.bb()).unwrap().terminator = new_term;
     }
 
@@ -649,3 +657,76 @@ fn get_mut_target_ref(terminator: &mut Terminator) -> &mut BasicBlockIdx {
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stable_mir::ty::IndexedVal;
+
+    /// Create a body with two basic blocks: `bb0` has one statement and jumps to `bb1`, which
+    /// returns.
+    fn two_blocks_body() -> MutableBody {
+        let span = Span::to_val(0);
+        let nop = Statement { kind: StatementKind::Nop, span };
+        let goto = Terminator { kind: TerminatorKind::Goto { target: 1 }, span };
+        let ret = Terminator { kind: TerminatorKind::Return, span };
+        MutableBody {
+            blocks: vec![
+                BasicBlock { statements: vec![nop], terminator: goto },
+                BasicBlock { statements: vec![], terminator: ret },
+            ],
+            locals: vec![],
+            arg_count: 0,
+            var_debug_info: vec![],
+            spread_arg: None,
+            span,
+        }
+    }
+
+    #[test]
+    fn check_replace_terminator() {
+        let mut body = two_blocks_body();
+        let span = body.span;
+        let unreachable = Terminator { kind: TerminatorKind::Unreachable, span };
+        body.replace_terminator(&SourceInstruction::Terminator { bb: 0 }, unreachable);
+
+        // The terminator is replaced in place, and nothing else changes.
+        assert_eq!(body.blocks().len(), 2);
+        assert_eq!(body.blocks()[0].statements.len(), 1);
+        assert_eq!(body.blocks()[0].terminator.kind, TerminatorKind::Unreachable);
+        assert_eq!(body.blocks()[1].statements.len(), 0);
+        assert_eq!(body.blocks()[1].terminator.kind, TerminatorKind::Return);
+    }
+
+    #[test]
+    fn check_replace_call_with_goto() {
+        let mut body = two_blocks_body();
+        let span = body.span;
+        let call = TerminatorKind::Call {
+            func: Operand::Copy(Place::from(1)),
+            args: vec![Operand::Move(Place::from(2))],
+            destination: Place::from(0),
+            target: Some(1),
+            unwind: UnwindAction::Terminate,
+        };
+        body.blocks[0].terminator = Terminator { kind: call, span };
+        let goto = Terminator { kind: TerminatorKind::Goto { target: 1 }, span };
+        body.replace_terminator(&SourceInstruction::Terminator { bb: 0 }, goto);
+
+        // The call is gone, and the block still jumps to the call's target.
+        assert_eq!(body.blocks().len(), 2);
+        assert_eq!(body.blocks()[0].statements.len(), 1);
+        assert_eq!(body.blocks()[0].terminator.kind, TerminatorKind::Goto { target: 1 });
+        assert_eq!(body.blocks()[0].terminator.successors(), vec![1]);
+        assert_eq!(body.blocks()[1].terminator.kind, TerminatorKind::Return);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a terminator")]
+    fn check_replace_terminator_of_statement() {
+        let mut body = two_blocks_body();
+        let span = body.span;
+        let unreachable = Terminator { kind: TerminatorKind::Unreachable, span };
+        body.replace_terminator(&SourceInstruction::Statement { idx: 0, bb: 0 }, unreachable);
+    }
+}
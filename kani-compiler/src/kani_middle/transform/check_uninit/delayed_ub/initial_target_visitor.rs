@@ -16,7 +16,7 @@
         mono::{Instance, InstanceKind, StaticDef},
         visit::Location,
     },
-    ty::{ConstantKind, RigidTy, TyKind},
+    ty::{ConstantKind, RigidTy, Span, TyKind},
 };
 
 /// Pointer, write through which might trigger delayed UB.
@@ -27,31 +27,38 @@ pub enum AnalysisTarget {
 
 /// Visitor that finds initial analysis targets for delayed UB instrumentation. For our purposes,
 /// analysis targets are *pointers* to places reading and writing from which should be tracked.
+///
+/// Each target is paired with the span of the instruction that produced it, so that the checks
+/// can point users to the origin of the delayed UB.
 pub struct InitialTargetVisitor {
     body: Body,
-    targets: Vec<AnalysisTarget>,
+    targets: Vec<(AnalysisTarget, Span)>,
+    /// The span of the instruction that is currently being visited.
+    current_span: Span,
 }
 
 impl InitialTargetVisitor {
     pub fn new(body: Body) -> Self {
-        Self { body, targets: vec![] }
+        let current_span = body.span;
+        Self { body, targets: vec![], current_span }
     }
 
-    pub fn into_targets(self) -> Vec<AnalysisTarget> {
+    pub fn into_targets(self) -> Vec<(AnalysisTarget, Span)> {
         self.targets
     }
 
     pub fn push_operand(&mut self, operand: &Operand) {
         match operand {
             Operand::Copy(place) | Operand::Move(place) => {
-                self.targets.push(AnalysisTarget::Place(place.clone()));
+                self.targets.push((AnalysisTarget::Place(place.clone()), self.current_span));
             }
             Operand::Constant(constant) => {
                 // Extract the static from the constant.
                 if let ConstantKind::Allocated(allocation) = constant.const_.kind() {
                     for (_, prov) in &allocation.provenance.ptrs {
                         if let GlobalAlloc::Static(static_def) = GlobalAlloc::from(prov.0) {
-                            self.targets.push(AnalysisTarget::Static(static_def));
+                            self.targets
+                                .push((AnalysisTarget::Static(static_def), self.current_span));
                         };
                     }
                 }
@@ -92,6 +99,7 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
     }
 
     fn visit_statement(&mut self, stmt: &Statement, location: Location) {
+        self.current_span = stmt.span;
         if let StatementKind::Intrinsic(NonDivergingIntrinsic::CopyNonOverlapping(copy)) =
             &stmt.kind
         {
@@ -101,6 +109,7 @@ fn visit_statement(&mut self, stmt: &Statement, location: Location) {
     }
 
     fn visit_terminator(&mut self, term: &Terminator, location: Location) {
+        self.current_span = term.span;
         if let TerminatorKind::Call { func, args, .. } = &term.kind {
             let instance = try_resolve_instance(self.body.locals(), func).unwrap();
             if instance.kind == InstanceKind::Intrinsic {
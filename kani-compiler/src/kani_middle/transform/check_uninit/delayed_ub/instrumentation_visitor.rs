@@ -5,6 +5,7 @@
 //! UB. In practice, that means collecting all instructions where the place is featured.
 
 use crate::kani_middle::{
+    SourceLocation,
     points_to::{MemLoc, PointsToGraph},
     transform::{
         body::{InsertPosition, MutableBody, SourceInstruction},
@@ -20,7 +21,8 @@
     mono::Instance,
     visit::{Location, PlaceContext},
 };
-use std::collections::HashSet;
+use stable_mir::ty::Span;
+use std::collections::{HashMap, HashSet};
 
 pub struct InstrumentationVisitor<'a, 'tcx> {
     /// All target instructions in the body.
@@ -31,6 +33,8 @@ pub struct InstrumentationVisitor<'a, 'tcx> {
     points_to: &'a PointsToGraph<'tcx>,
     /// The list of places we should be looking for, ignoring others
     analysis_targets: &'a HashSet<MemLoc<'tcx>>,
+    /// The span of the instruction that produced a pointer to each analysis target.
+    producers: &'a HashMap<MemLoc<'tcx>, Span>,
     current_instance: Instance,
     tcx: TyCtxt<'tcx>,
 }
@@ -53,6 +57,7 @@ impl<'a, 'tcx> InstrumentationVisitor<'a, 'tcx> {
     pub fn new(
         points_to: &'a PointsToGraph<'tcx>,
         analysis_targets: &'a HashSet<MemLoc<'tcx>>,
+        producers: &'a HashMap<MemLoc<'tcx>, Span>,
         current_instance: Instance,
         tcx: TyCtxt<'tcx>,
     ) -> Self {
@@ -65,6 +70,7 @@ pub fn new(
             },
             points_to,
             analysis_targets,
+            producers,
             current_instance,
             tcx,
         }
@@ -73,6 +79,15 @@ pub fn new(
     fn push_target(&mut self, source_op: MemoryInitOp) {
         self.current_target.push_operation(source_op);
     }
+
+    /// Find the instruction that produced a pointer to one of the given analysis targets. If there
+    /// are several, pick the first one in source order so the diagnostic is deterministic.
+    fn producer(&self, targets: &HashSet<MemLoc<'tcx>>) -> Option<Span> {
+        targets.iter().filter_map(|target| self.producers.get(target).copied()).min_by_key(|span| {
+            let loc = SourceLocation::new(*span);
+            (loc.filename, loc.start_line, loc.start_col)
+        })
+    }
 }
 
 impl MirVisitor for InstrumentationVisitor<'_, '_> {
@@ -120,13 +135,13 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
     fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location) {
         // In order to check whether we should get-instrument the place, see if it resolves to the
         // analysis target.
-        let needs_get = {
-            self.points_to
-                .resolve_place_stable(place.clone(), self.current_instance, self.tcx)
-                .intersection(&self.analysis_targets)
-                .next()
-                .is_some()
-        };
+        let accessed_targets: HashSet<_> = self
+            .points_to
+            .resolve_place_stable(place.clone(), self.current_instance, self.tcx)
+            .intersection(&self.analysis_targets)
+            .copied()
+            .collect();
+        let needs_get = !accessed_targets.is_empty();
 
         // In order to check whether we should set-instrument the place, we need to figure out if
         // the place has a common ancestor of the same level with the target.
@@ -181,7 +196,10 @@ fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location)
             });
         } else if !ptx.is_mutating() && needs_get {
             // Otherwise, check its initialization.
-            self.push_target(MemoryInitOp::CheckRef { operand: Operand::Copy(place.clone()) });
+            self.push_target(MemoryInitOp::CheckRef {
+                operand: Operand::Copy(place.clone()),
+                producer: self.producer(&accessed_targets),
+            });
         }
         self.super_place(place, ptx, location)
     }
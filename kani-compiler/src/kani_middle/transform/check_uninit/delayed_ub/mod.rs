@@ -67,22 +67,24 @@ fn transform(
         transformer: &mut BodyTransformation,
     ) {
         // Collect all analysis targets (pointers to places reading and writing from which should be
-        // tracked).
-        let targets: HashSet<_> = instances
+        // tracked), together with the span of the instruction that produced them.
+        let targets: Vec<_> = instances
             .iter()
             .flat_map(|instance| {
                 let body = instance.body().unwrap();
                 let mut visitor = InitialTargetVisitor::new(body.clone());
                 visitor.visit_body(&body);
                 // Convert all places into the format of aliasing graph for later comparison.
-                visitor.into_targets().into_iter().map(move |analysis_target| match analysis_target
-                {
-                    AnalysisTarget::Place(place) => {
-                        MemLoc::from_stable_stack_allocation(*instance, place, tcx)
-                    }
-                    AnalysisTarget::Static(static_def) => {
-                        MemLoc::from_stable_static_allocation(static_def, tcx)
-                    }
+                visitor.into_targets().into_iter().map(move |(analysis_target, span)| {
+                    let mem_loc = match analysis_target {
+                        AnalysisTarget::Place(place) => {
+                            MemLoc::from_stable_stack_allocation(*instance, place, tcx)
+                        }
+                        AnalysisTarget::Static(static_def) => {
+                            MemLoc::from_stable_static_allocation(static_def, tcx)
+                        }
+                    };
+                    (mem_loc, span)
                 })
             })
             .collect();
@@ -110,7 +112,14 @@ fn transform(
             }
 
             // Since analysis targets are *pointers*, need to get its successors for instrumentation.
-            analysis_targets.extend(global_points_to_graph.successors(&targets));
+            // Keep track of the first instruction that produced a pointer to each of them.
+            let mut producers = HashMap::new();
+            for (target, span) in &targets {
+                for pointee in global_points_to_graph.successors(&HashSet::from([*target])) {
+                    producers.entry(pointee).or_insert(*span);
+                }
+            }
+            analysis_targets.extend(producers.keys().copied());
 
             // If we are generating MIR, generate the points-to graph as well.
             if tcx.sess.opts.output_types.contains_key(&OutputType::Mir) {
@@ -125,6 +134,7 @@ fn transform(
                 let target_finder = InstrumentationVisitor::new(
                     &global_points_to_graph,
                     &analysis_targets,
+                    &producers,
                     instance,
                     tcx,
                 );
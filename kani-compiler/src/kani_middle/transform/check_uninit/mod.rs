@@ -17,6 +17,7 @@
 };
 use std::collections::HashMap;
 
+use crate::kani_middle::SourceLocation;
 use crate::kani_middle::kani_functions::{KaniFunction, KaniModel};
 pub use delayed_ub::DelayedUbPass;
 pub use ptr_uninit::UninitPass;
@@ -173,7 +174,8 @@ fn build_check_for_operation(
         match &operation {
             MemoryInitOp::CheckSliceChunk { .. }
             | MemoryInitOp::Check { .. }
-            | MemoryInitOp::CheckRef { .. } => {
+            | MemoryInitOp::CheckRef { .. }
+            | MemoryInitOp::CheckAs { .. } => {
                 self.build_get_and_check(body, source, operation, pointee_info)
             }
             MemoryInitOp::SetSliceChunk { .. }
@@ -218,7 +220,9 @@ fn build_get_and_check(
                 // Depending on whether accessing the known number of elements in the slice, need to
                 // pass is as an argument.
                 let (diagnostic, args) = match &operation {
-                    MemoryInitOp::Check { .. } | MemoryInitOp::CheckRef { .. } => {
+                    MemoryInitOp::Check { .. }
+                    | MemoryInitOp::CheckRef { .. }
+                    | MemoryInitOp::CheckAs { .. } => {
                         let diagnostic = KANI_IS_PTR_INITIALIZED;
                         let args = vec![ptr_operand.clone(), layout_operand];
                         (diagnostic, args)
@@ -307,17 +311,27 @@ fn build_get_and_check(
         let operand_ty = match &operation {
             MemoryInitOp::Check { operand }
             | MemoryInitOp::CheckSliceChunk { operand, .. }
-            | MemoryInitOp::CheckRef { operand } => operand.ty(body.locals()).unwrap(),
+            | MemoryInitOp::CheckRef { operand, .. } => operand.ty(body.locals()).unwrap(),
+            MemoryInitOp::CheckAs { .. } => operation.operand_ty(body),
             _ => unreachable!(),
         };
+        let mut msg = format!(
+            "Undefined Behavior: Reading from an uninitialized pointer of type `{operand_ty}`"
+        );
+        if let MemoryInitOp::CheckRef { producer: Some(span), .. } = &operation {
+            let loc = SourceLocation::new(*span);
+            msg.push_str(&format!(
+                " (uninitialized bytes may have been written through the pointer produced at \
+                {}:{}:{})",
+                loc.filename, loc.start_line, loc.start_col
+            ));
+        }
         body.insert_check(
             &self.safety_check_type,
             source,
             operation.position(),
             Some(ret_place.local),
-            &format!(
-                "Undefined Behavior: Reading from an uninitialized pointer of type `{operand_ty}`"
-            ),
+            &msg,
         )
     }
 
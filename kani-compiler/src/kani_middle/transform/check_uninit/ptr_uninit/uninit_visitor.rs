@@ -5,6 +5,7 @@
 
 use crate::{
     intrinsics::Intrinsic,
+    kani_middle::kani_functions::{KaniFunction, KaniIntrinsic},
     kani_middle::transform::{
         body::{InsertPosition, MutableBody, SourceInstruction},
         check_uninit::{
@@ -15,6 +16,7 @@
     },
 };
 use stable_mir::{
+    CrateDef,
     mir::{
         AggregateKind, CastKind, LocalDecl, MirVisitor, NonDivergingIntrinsic, Operand, Place,
         PointerCoercion, ProjectionElem, Rvalue, Statement, StatementKind, Terminator,
@@ -335,10 +337,12 @@ fn visit_terminator(&mut self, term: &Terminator, location: Location) {
                                 self.push_target(MemoryInitOp::Check { operand: args[0].clone() });
                                 self.push_target(MemoryInitOp::Check { operand: args[1].clone() });
                             }
-                            Intrinsic::VolatileLoad | Intrinsic::UnalignedVolatileLoad => {
+                            Intrinsic::ReadViaCopy
+                            | Intrinsic::VolatileLoad
+                            | Intrinsic::UnalignedVolatileLoad => {
                                 self.push_target(MemoryInitOp::Check { operand: args[0].clone() });
                             }
-                            Intrinsic::VolatileStore => {
+                            Intrinsic::VolatileStore | Intrinsic::WriteViaMove => {
                                 self.push_target(MemoryInitOp::Set {
                                     operand: args[0].clone(),
                                     value: true,
@@ -386,6 +390,23 @@ fn visit_terminator(&mut self, term: &Terminator, location: Location) {
                                 }
                                 _ => {}
                             }
+                        } else if is_assume_init_read(&instance) {
+                            /* The user asserts that the value is initialized, so check it. */
+                            let pointee_ty = *instance.args().0[0].expect_ty();
+                            self.push_target(MemoryInitOp::CheckAs {
+                                operand: args[0].clone(),
+                                pointee_ty,
+                            });
+                        } else if KaniFunction::try_from(instance)
+                            == Ok(KaniIntrinsic::Poison.into())
+                        {
+                            /* Memory is explicitly marked as uninitialized by the user. */
+                            self.push_target(MemoryInitOp::SetSliceChunk {
+                                operand: args[0].clone(),
+                                count: args[1].clone(),
+                                value: false,
+                                position: InsertPosition::After,
+                            });
                         } else {
                             let union_args: Vec<_> = args
                                 .iter()
@@ -479,6 +500,7 @@ fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location)
                         // Accessing a place inside the union, need to check if it is initialized.
                         self.push_target(MemoryInitOp::CheckRef {
                             operand: Operand::Copy(place.clone()),
+                            producer: None,
                         });
                     }
                 }
@@ -568,6 +590,16 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
     }
 }
 
+/// Whether the instance is `MaybeUninit::assume_init_read`, which reads the value stored in the
+/// `MaybeUninit` referenced by its first argument.
+fn is_assume_init_read(instance: &Instance) -> bool {
+    matches!(
+        instance.def.name().as_str(),
+        "core::mem::MaybeUninit::<T>::assume_init_read"
+            | "std::mem::MaybeUninit::<T>::assume_init_read"
+    )
+}
+
 /// Determines if the intrinsic has no memory initialization related function and hence can be
 /// safely skipped.
 fn can_skip_intrinsic(intrinsic: Intrinsic) -> bool {
@@ -582,6 +614,7 @@ fn can_skip_intrinsic(intrinsic: Intrinsic) -> bool {
         | Intrinsic::BlackBox
         | Intrinsic::Breakpoint
         | Intrinsic::Bswap
+        | Intrinsic::CatchUnwind
         | Intrinsic::CeilF32
         | Intrinsic::CeilF64
         | Intrinsic::CopySignF32
@@ -6,8 +6,11 @@
 
 use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
 use stable_mir::{
-    mir::{FieldIdx, Mutability, Operand, Place, RawPtrKind, Rvalue, Statement, StatementKind},
-    ty::{RigidTy, Ty},
+    mir::{
+        CastKind, FieldIdx, Mutability, Operand, Place, RawPtrKind, Rvalue, Statement,
+        StatementKind,
+    },
+    ty::{RigidTy, Span, Ty},
 };
 use strum_macros::AsRefStr;
 
@@ -26,9 +29,16 @@ pub enum MemoryInitOp {
     /// Set memory initialization state of data bytes in a memory region starting from the pointer
     /// `operand` and of length `count * sizeof(operand)` bytes.
     SetSliceChunk { operand: Operand, count: Operand, value: bool, position: InsertPosition },
-    /// Set memory initialization of data bytes in a memory region starting from the reference to
+    /// Check memory initialization of data bytes in a memory region starting from the reference to
     /// `operand` and of length `sizeof(operand)` bytes.
-    CheckRef { operand: Operand },
+    ///
+    /// For delayed UB, `producer` is the span of the instruction that produced the pointer through
+    /// which the memory may have been left uninitialized.
+    CheckRef { operand: Operand, producer: Option<Span> },
+    /// Check memory initialization of data bytes in a memory region starting from the pointer
+    /// `operand` reinterpreted as a pointer to `pointee_ty`, and of length `sizeof(pointee_ty)`
+    /// bytes.
+    CheckAs { operand: Operand, pointee_ty: Ty },
     /// Set memory initialization of data bytes in a memory region starting from the reference to
     /// `operand` and of length `sizeof(operand)` bytes.
     SetRef { operand: Operand, value: bool, position: InsertPosition },
@@ -72,6 +82,9 @@ pub fn mk_operand(
             | MemoryInitOp::StoreArgument { operand, .. } => {
                 mk_ref(operand, body, statements, source)
             }
+            MemoryInitOp::CheckAs { operand, pointee_ty } => {
+                mk_ptr_cast(operand, *pointee_ty, body, statements, source)
+            }
             MemoryInitOp::Copy { .. }
             | MemoryInitOp::AssignUnion { .. }
             | MemoryInitOp::Unsupported { .. }
@@ -126,6 +139,7 @@ pub fn operand_ty(&self, body: &MutableBody) -> Ty {
                 let rvalue = Rvalue::AddressOf(RawPtrKind::Const, place.clone());
                 rvalue.ty(body.locals()).unwrap()
             }
+            MemoryInitOp::CheckAs { pointee_ty, .. } => Ty::new_ptr(*pointee_ty, Mutability::Not),
             MemoryInitOp::Unsupported { .. } | MemoryInitOp::TriviallyUnsafe { .. } => {
                 unreachable!("operands do not exist for this operation")
             }
@@ -161,6 +175,7 @@ pub fn expect_count(&self) -> Operand {
             MemoryInitOp::Check { .. }
             | MemoryInitOp::Set { .. }
             | MemoryInitOp::CheckRef { .. }
+            | MemoryInitOp::CheckAs { .. }
             | MemoryInitOp::SetRef { .. }
             | MemoryInitOp::CreateUnion { .. }
             | MemoryInitOp::AssignUnion { .. }
@@ -180,6 +195,7 @@ pub fn expect_value(&self) -> bool {
             MemoryInitOp::Check { .. }
             | MemoryInitOp::CheckSliceChunk { .. }
             | MemoryInitOp::CheckRef { .. }
+            | MemoryInitOp::CheckAs { .. }
             | MemoryInitOp::Unsupported { .. }
             | MemoryInitOp::TriviallyUnsafe { .. }
             | MemoryInitOp::Copy { .. }
@@ -195,6 +211,7 @@ pub fn union_field(&self) -> Option<FieldIdx> {
             MemoryInitOp::Check { .. }
             | MemoryInitOp::CheckSliceChunk { .. }
             | MemoryInitOp::CheckRef { .. }
+            | MemoryInitOp::CheckAs { .. }
             | MemoryInitOp::Set { .. }
             | MemoryInitOp::SetSliceChunk { .. }
             | MemoryInitOp::SetRef { .. }
@@ -215,6 +232,7 @@ pub fn position(&self) -> InsertPosition {
             MemoryInitOp::Check { .. }
             | MemoryInitOp::CheckSliceChunk { .. }
             | MemoryInitOp::CheckRef { .. }
+            | MemoryInitOp::CheckAs { .. }
             | MemoryInitOp::Unsupported { .. }
             | MemoryInitOp::TriviallyUnsafe { .. }
             | MemoryInitOp::StoreArgument { .. }
@@ -281,3 +299,20 @@ fn mk_ref(
 
     Operand::Copy(Place { local: ref_local, projection: vec![] })
 }
+
+/// Reinterpret the thin pointer or reference `operand` as a raw pointer to `pointee_ty`.
+fn mk_ptr_cast(
+    operand: &Operand,
+    pointee_ty: Ty,
+    body: &mut MutableBody,
+    statements: &mut Vec<Statement>,
+    source: &mut SourceInstruction,
+) -> Operand {
+    let span = source.span(body.blocks());
+    let ptr_ty = Ty::new_ptr(pointee_ty, Mutability::Not);
+    let result = body.new_local(ptr_ty, span, Mutability::Not);
+    let rvalue = Rvalue::Cast(CastKind::Transmute, operand.clone(), ptr_ty);
+    let stmt = Statement { kind: StatementKind::Assign(Place::from(result), rvalue), span };
+    statements.push(stmt);
+    Operand::Copy(Place::from(result))
+}
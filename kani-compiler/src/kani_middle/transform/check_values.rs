@@ -173,9 +173,19 @@ enum ValidityRange {
     /// This includes cases where the full range is covered.
     Single(WrappingRange),
     /// The validity includes more than one value range.
-    /// Currently, this is only the case for `char`, which has two ranges.
-    /// If more cases come up, we could turn this into a vector instead.
-    Multiple([WrappingRange; 2]),
+    /// This is the case for `char`, which has two ranges, and for the tag of enums whose
+    /// discriminants have gaps.
+    Multiple(Vec<WrappingRange>),
+}
+
+impl ValidityRange {
+    /// The value ranges that are valid.
+    fn ranges(&self) -> &[WrappingRange] {
+        match self {
+            ValidityRange::Single(range) => std::slice::from_ref(range),
+            ValidityRange::Multiple(ranges) => ranges,
+        }
+    }
 }
 
 // TODO: Optimize checks by merging requirements whenever possible.
@@ -195,7 +205,7 @@ pub fn try_from_ty(machine_info: &MachineInfo, ty: Ty) -> Option<ValidValueReq>
             Some(ValidValueReq {
                 offset: 0,
                 size: MachineSize::from_bits(size_of::<char>() * 8),
-                valid_range: ValidityRange::Multiple([
+                valid_range: ValidityRange::Multiple(vec![
                     WrappingRange { start: 0, end: 0xD7FF },
                     WrappingRange { start: 0xE000, end: char::MAX.into() },
                 ]),
@@ -233,31 +243,12 @@ pub fn is_full(&self) -> bool {
     /// I.e., `scalar_2` ⊆ `scalar_1`
     pub fn contains(&self, other: &ValidValueReq) -> bool {
         assert_eq!(self.size, other.size);
-        match (&self.valid_range, &other.valid_range) {
-            (ValidityRange::Single(this_range), ValidityRange::Single(other_range)) => {
-                range_contains(this_range, other_range, self.size)
-            }
-            (ValidityRange::Multiple(this_ranges), ValidityRange::Single(other_range)) => {
-                range_contains(&this_ranges[0], other_range, self.size)
-                    || range_contains(&this_ranges[1], other_range, self.size)
-            }
-            (ValidityRange::Single(this_range), ValidityRange::Multiple(other_ranges)) => {
-                range_contains(this_range, &other_ranges[0], self.size)
-                    && range_contains(this_range, &other_ranges[1], self.size)
-            }
-            (ValidityRange::Multiple(this_ranges), ValidityRange::Multiple(other_ranges)) => {
-                let contains = (range_contains(&this_ranges[0], &other_ranges[0], self.size)
-                    || range_contains(&this_ranges[1], &other_ranges[0], self.size))
-                    && (range_contains(&this_ranges[0], &other_ranges[1], self.size)
-                        || range_contains(&this_ranges[1], &other_ranges[1], self.size));
-                // Multiple today only cover `char` case.
-                debug_assert!(
-                    contains,
-                    "Expected validity of `char` for Multiple ranges. Found: {self:?}, {other:?}"
-                );
-                contains
-            }
-        }
+        other.valid_range.ranges().iter().all(|other_range| {
+            self.valid_range
+                .ranges()
+                .iter()
+                .any(|this_range| range_contains(this_range, other_range, self.size))
+        })
     }
 }
 
@@ -453,7 +444,8 @@ fn visit_terminator(&mut self, term: &Terminator, location: Location) {
                             else {
                                 unreachable!()
                             };
-                            let validity = ty_validity_per_offset(&self.machine, target_ty, 0);
+                            let validity =
+                                ty_validity_per_offset(self.tcx, &self.machine, target_ty, 0);
                             match validity {
                                 Ok(ranges) if ranges.is_empty() => {}
                                 Ok(ranges) => {
@@ -476,6 +468,30 @@ fn visit_terminator(&mut self, term: &Terminator, location: Location) {
                         Some("transmute") | Some("transmute_copy") => {
                             unreachable!("Should've been lowered")
                         }
+                        _ if is_assume_init(self.locals, func) => {
+                            // `MaybeUninit::<T>::assume_init` produces a `T` from bytes that may
+                            // have never been written, e.g.: `MaybeUninit::uninit().assume_init()`.
+                            // This is immediate UB if the bytes are not a valid `T`.
+                            // `MaybeUninit<T>` has the same layout as `T`, so check the argument
+                            // against the requirements of `T` before the call.
+                            let TyKind::RigidTy(RigidTy::Adt(_, generic_args)) =
+                                args[0].ty(self.locals).unwrap().kind()
+                            else {
+                                unreachable!()
+                            };
+                            let target_ty = *generic_args.0[0].expect_ty();
+                            if let Ok(ranges) =
+                                ty_validity_per_offset(self.tcx, &self.machine, target_ty, 0)
+                                && !ranges.is_empty()
+                                && let Operand::Copy(place) | Operand::Move(place) = &args[0]
+                            {
+                                self.push_target(SourceOp::BytesValidity {
+                                    target_ty,
+                                    rvalue: Rvalue::Use(Operand::Copy(place.clone())),
+                                    ranges,
+                                })
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -500,7 +516,8 @@ fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location)
                     let ptr_ty = place_ref.ty(self.locals).unwrap();
                     if ptr_ty.kind().is_raw_ptr() {
                         let target_ty = elem.ty(ptr_ty).unwrap();
-                        let validity = ty_validity_per_offset(&self.machine, target_ty, 0);
+                        let validity =
+                            ty_validity_per_offset(self.tcx, &self.machine, target_ty, 0);
                         match validity {
                             Ok(ranges) if !ranges.is_empty() => {
                                 self.push_target(SourceOp::DerefValidity {
@@ -527,7 +544,8 @@ fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location)
                     if target_ty.kind().is_union()
                         && (!ptx.is_mutating() || place.projection.len() > idx + 1)
                     {
-                        let validity = ty_validity_per_offset(&self.machine, *target_ty, 0);
+                        let validity =
+                            ty_validity_per_offset(self.tcx, &self.machine, *target_ty, 0);
                         match validity {
                             Ok(ranges) if !ranges.is_empty() => {
                                 self.push_target(SourceOp::BytesValidity {
@@ -593,11 +611,11 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
                     }
 
                     if let Ok(src_validity) =
-                        ty_validity_per_offset(&self.machine, src_pointee_ty, 0)
+                        ty_validity_per_offset(self.tcx, &self.machine, src_pointee_ty, 0)
                     {
                         if !src_validity.is_empty() {
                             if let Ok(dest_validity) =
-                                ty_validity_per_offset(&self.machine, dest_pointee_ty, 0)
+                                ty_validity_per_offset(self.tcx, &self.machine, dest_pointee_ty, 0)
                             {
                                 if dest_validity != src_validity {
                                     self.push_target(SourceOp::UnsupportedCheck {
@@ -624,7 +642,9 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
                     // For transmute, we care about the destination type only.
                     // This could be optimized to only add a check if the requirements of the
                     // destination type are stricter than the source.
-                    if let Ok(dest_validity) = ty_validity_per_offset(&self.machine, *dest_ty, 0) {
+                    if let Ok(dest_validity) =
+                        ty_validity_per_offset(self.tcx, &self.machine, *dest_ty, 0)
+                    {
                         trace!(?dest_validity, "transmute");
                         if !dest_validity.is_empty() {
                             self.push_target(SourceOp::BytesValidity {
@@ -799,6 +819,17 @@ fn intrinsic_name(locals: &[LocalDecl], func: &Operand) -> Option<String> {
     Instance::resolve(def, &args).unwrap().intrinsic_name()
 }
 
+/// Whether this operand is a call to `MaybeUninit::assume_init`.
+fn is_assume_init(locals: &[LocalDecl], func: &Operand) -> bool {
+    let ty = func.ty(locals).unwrap();
+    let TyKind::RigidTy(RigidTy::FnDef(def, args)) = ty.kind() else { return false };
+    let Ok(instance) = Instance::resolve(def, &args) else { return false };
+    matches!(
+        instance.def.name().as_str(),
+        "core::mem::MaybeUninit::<T>::assume_init" | "std::mem::MaybeUninit::<T>::assume_init"
+    )
+}
+
 /// Instrument MIR to check the value pointed by `rvalue_ptr` satisfies requirement `req`.
 ///
 /// The MIR will do something equivalent to:
@@ -855,17 +886,23 @@ pub fn build_limits(
         ValidityRange::Single(range) => {
             build_single_limit(body, range, source, span, primitive_ty, value)
         }
-        ValidityRange::Multiple([range1, range2]) => {
-            // Build `let valid = range1.contains(value) || range2.contains(value);
-            let cond1 = build_single_limit(body, range1, source, span, primitive_ty, value.clone());
-            let cond2 = build_single_limit(body, range2, source, span, primitive_ty, value);
-            body.insert_binary_op(
-                BinOp::BitOr,
-                move_local(cond1),
-                move_local(cond2),
-                source,
-                InsertPosition::Before,
-            )
+        ValidityRange::Multiple(ranges) => {
+            // Build `let valid = range1.contains(value) || range2.contains(value) || ...;
+            let (first, rest) = ranges.split_first().unwrap();
+            let mut valid =
+                build_single_limit(body, first, source, span, primitive_ty, value.clone());
+            for range in rest {
+                let cond =
+                    build_single_limit(body, range, source, span, primitive_ty, value.clone());
+                valid = body.insert_binary_op(
+                    BinOp::BitOr,
+                    move_local(valid),
+                    move_local(cond),
+                    source,
+                    InsertPosition::Before,
+                );
+            }
+            valid
         }
     }
 }
@@ -914,6 +951,7 @@ fn build_single_limit(
 ///
 /// Not all values are currently supported. For those not supported, we return Error.
 pub fn ty_validity_per_offset(
+    tcx: TyCtxt,
     machine_info: &MachineInfo,
     ty: Ty,
     current_offset: usize,
@@ -933,7 +971,7 @@ pub fn ty_validity_per_offset(
         FieldsShape::Primitive => Ok(ty_req()),
         FieldsShape::Array { stride, count } if count > 0 => {
             let TyKind::RigidTy(RigidTy::Array(elem_ty, _)) = ty.kind() else { unreachable!() };
-            let elem_validity = ty_validity_per_offset(machine_info, elem_ty, current_offset)?;
+            let elem_validity = ty_validity_per_offset(tcx, machine_info, elem_ty, current_offset)?;
             let mut result = vec![];
             if !elem_validity.is_empty() {
                 for idx in 0..count {
@@ -969,6 +1007,7 @@ pub fn ty_validity_per_offset(
                                         let field_offset = offsets[idx].bytes();
                                         let field_ty = fields[idx].ty_with_args(&args);
                                         fields_validity.append(&mut ty_validity_per_offset(
+                                            tcx,
                                             machine_info,
                                             field_ty,
                                             field_offset + current_offset,
@@ -977,28 +1016,50 @@ pub fn ty_validity_per_offset(
                                     Ok(fields_validity)
                                 }
                                 VariantsShape::Multiple {
-                                    tag_encoding: TagEncoding::Niche { .. },
-                                    ..
+                                    tag,
+                                    tag_encoding,
+                                    tag_field,
+                                    variants,
                                 } => {
-                                    Err(format!("Unsupported Enum `{}` check", def.trimmed_name()))?
-                                }
-                                VariantsShape::Multiple { variants, .. } => {
-                                    let enum_validity = ty_req();
+                                    let tag_req = enum_tag_validity(
+                                        tcx,
+                                        machine_info,
+                                        ty,
+                                        &tag,
+                                        &tag_encoding,
+                                        offsets[tag_field].bytes() + current_offset,
+                                    );
                                     let mut fields_validity = vec![];
                                     for (index, variant) in variants.iter().enumerate() {
                                         let fields = ty_variants[index].fields();
+                                        let FieldsShape::Arbitrary { offsets: field_offsets } =
+                                            &variant.fields
+                                        else {
+                                            unreachable!()
+                                        };
                                         for field_idx in variant.fields.fields_by_offset_order() {
-                                            let field_offset = offsets[field_idx].bytes();
+                                            let field_offset = field_offsets[field_idx].bytes();
                                             let field_ty = fields[field_idx].ty_with_args(&args);
                                             fields_validity.append(&mut ty_validity_per_offset(
+                                                tcx,
                                                 machine_info,
                                                 field_ty,
                                                 field_offset + current_offset,
                                             )?);
                                         }
                                     }
+                                    // The requirement of the field that holds the niche is
+                                    // already covered by the tag requirement, which also allows
+                                    // the niche values of the other variants. Note that a niche
+                                    // with more than one range, such as `char`, is not supported.
+                                    fields_validity.retain(|req| {
+                                        req.offset != tag_req.offset
+                                            || req.size != tag_req.size
+                                            || !matches!(req.valid_range, ValidityRange::Single(_))
+                                            || !tag_req.contains(req)
+                                    });
                                     if fields_validity.is_empty() {
-                                        Ok(enum_validity)
+                                        Ok(if tag_req.is_full() { vec![] } else { vec![tag_req] })
                                     } else {
                                         Err(format!(
                                             "Unsupported Enum `{}` check",
@@ -1017,6 +1078,7 @@ pub fn ty_validity_per_offset(
                                 let field_offset = offsets[idx].bytes();
                                 let field_ty = fields[idx].ty_with_args(&args);
                                 struct_validity.append(&mut ty_validity_per_offset(
+                                    tcx,
                                     machine_info,
                                     field_ty,
                                     field_offset + current_offset,
@@ -1029,7 +1091,12 @@ pub fn ty_validity_per_offset(
                 RigidTy::Pat(base_ty, ..) => {
                     // This is similar to a structure with one field and with niche defined.
                     let mut pat_validity = ty_req();
-                    pat_validity.append(&mut ty_validity_per_offset(machine_info, *base_ty, 0)?);
+                    pat_validity.append(&mut ty_validity_per_offset(
+                        tcx,
+                        machine_info,
+                        *base_ty,
+                        0,
+                    )?);
                     Ok(pat_validity)
                 }
                 RigidTy::Tuple(tys) => {
@@ -1038,6 +1105,7 @@ pub fn ty_validity_per_offset(
                         let field_offset = offsets[idx].bytes();
                         let field_ty = tys[idx];
                         tuple_validity.append(&mut ty_validity_per_offset(
+                            tcx,
                             machine_info,
                             field_ty,
                             field_offset + current_offset,
@@ -1076,3 +1144,52 @@ pub fn ty_validity_per_offset(
         }
     }
 }
+
+/// Compute the requirement for the tag of an enum with multiple variants.
+///
+/// For a direct encoding, the tag must be equal to the discriminant of one of the variants, which
+/// may include more than one range if there are gaps between the discriminant values.
+/// For a niche encoding, the tag must be within the valid range of the niche, which covers the
+/// valid values of the untagged variant as well as the niche values of the other variants.
+fn enum_tag_validity(
+    tcx: TyCtxt,
+    machine_info: &MachineInfo,
+    ty: Ty,
+    tag: &Scalar,
+    tag_encoding: &TagEncoding,
+    offset: usize,
+) -> ValidValueReq {
+    let Scalar::Initialized { value, valid_range } = tag else {
+        unreachable!("Enum tag should not be a union.")
+    };
+    let size = value.size(machine_info);
+    let valid_range = match tag_encoding {
+        TagEncoding::Direct => {
+            let rustc_middle::ty::Adt(adt_def, _) = rustc_internal::internal(tcx, ty).kind() else {
+                unreachable!("Expected an enum, but found: {ty:?}")
+            };
+            // Discriminants are represented with the size of their type, which may be larger
+            // than the tag.
+            let mask = u128::MAX >> (128 - size.bits());
+            let mut discriminants: Vec<u128> =
+                adt_def.discriminants(tcx).map(|(_, discr)| discr.val & mask).collect();
+            discriminants.sort_unstable();
+            discriminants.dedup();
+            // Merge consecutive discriminants into a single range.
+            let mut ranges: Vec<WrappingRange> = vec![];
+            for discr in discriminants {
+                match ranges.last_mut() {
+                    Some(range) if range.end + 1 == discr => range.end = discr,
+                    _ => ranges.push(WrappingRange { start: discr, end: discr }),
+                }
+            }
+            if let [range] = ranges.as_slice() {
+                ValidityRange::Single(*range)
+            } else {
+                ValidityRange::Multiple(ranges)
+            }
+        }
+        TagEncoding::Niche { .. } => ValidityRange::Single(*valid_range),
+    };
+    ValidValueReq { offset, size, valid_range }
+}
@@ -0,0 +1,136 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Global analysis pass that computes the possible targets of calls through function pointers.
+//!
+//! For every call through a function pointer in the reachable code, we collect every reachable
+//! function whose signature matches the signature of the pointer. This over-approximates the set
+//! of functions that the pointer may point to, since a function can only be called through a
+//! pointer if its address was taken in the reachable code.
+//!
+//! The result is later used by the code generation to emit restrictions in the format expected by
+//! CBMC's `--function-pointer-restrictions-file`. Calls with no matching candidate are left
+//! unrestricted.
+//!
+//! Note that foreign functions are not part of the reachable items, so we only consider the ones
+//! that are explicitly coerced into a function pointer in a reachable body.
+
+use crate::kani_middle::reachability::CallGraph;
+use crate::kani_middle::transform::{BodyTransformation, GlobalPass};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::{List, TyCtxt, TypingEnv};
+use rustc_smir::rustc_internal;
+use stable_mir::abi::{FnAbi, PassMode};
+use stable_mir::mir::mono::{Instance, MonoItem, StaticDef};
+use stable_mir::mir::{
+    BasicBlockIdx, Body, CastKind, PointerCoercion, Rvalue, StatementKind, TerminatorKind,
+};
+use stable_mir::ty::{PolyFnSig, RigidTy, Ty, TyKind};
+use tracing::debug;
+
+/// Compute the possible targets of each call through a function pointer.
+#[derive(Debug)]
+pub struct FnPtrRestrictionPass;
+
+impl GlobalPass for FnPtrRestrictionPass {
+    fn is_enabled(&self, query_db: &QueryDb) -> bool {
+        query_db.args().emit_fn_ptr_restrictions
+    }
+
+    fn transform(
+        &mut self,
+        tcx: TyCtxt,
+        _call_graph: &CallGraph,
+        _starting_items: &[MonoItem],
+        instances: Vec<Instance>,
+        transformer: &mut BodyTransformation,
+    ) {
+        let bodies: Vec<_> = instances
+            .iter()
+            .filter(|instance| instance.has_body())
+            .map(|instance| (*instance, transformer.body(tcx, *instance)))
+            .collect();
+
+        // Every reachable function plus the foreign functions whose address is taken.
+        let mut candidates: Vec<Instance> = instances
+            .iter()
+            .filter(|instance| StaticDef::try_from(**instance).is_err())
+            .copied()
+            .collect();
+        candidates.extend(bodies.iter().flat_map(|(_, body)| reified_foreign_fns(body)));
+        candidates.sort_by_key(|instance| instance.mangled_name());
+        candidates.dedup();
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .filter_map(|instance| Some((instance, signature(&instance.fn_abi().ok()?))))
+            .collect();
+
+        for (instance, body) in &bodies {
+            for (bb, sig) in fn_ptr_calls(body) {
+                let expected = signature(&fn_ptr_abi(tcx, sig));
+                let targets: Vec<_> = candidates
+                    .iter()
+                    .filter_map(|(candidate, candidate_sig)| {
+                        (*candidate_sig == expected).then_some(*candidate)
+                    })
+                    .collect();
+                debug!(function=?instance.name(), ?bb, targets=?targets.len(), "fn_ptr_call");
+                if !targets.is_empty() {
+                    transformer.fn_ptr_targets.insert((*instance, bb), targets);
+                }
+            }
+        }
+    }
+}
+
+/// The argument types and return type of a function ABI, which is what we use to match a
+/// function against a function pointer. The calling convention is ignored.
+///
+/// Arguments that are ignored by the ABI are skipped, the same way code generation skips them.
+/// E.g.: the zero-sized `self` argument of a closure shim has no counterpart in the pointer.
+fn signature(abi: &FnAbi) -> (Vec<Ty>, Ty) {
+    let args = abi.args.iter().filter(|arg| arg.mode != PassMode::Ignore).map(|arg| arg.ty);
+    (args.collect(), abi.ret.ty)
+}
+
+/// Compute the ABI of a function pointer the same way it is done during code generation.
+fn fn_ptr_abi(tcx: TyCtxt, sig: PolyFnSig) -> FnAbi {
+    let sig_internal = rustc_internal::internal(tcx, sig);
+    rustc_internal::stable(
+        tcx.fn_abi_of_fn_ptr(
+            TypingEnv::fully_monomorphized().as_query_input((sig_internal, &List::empty())),
+        )
+        .unwrap(),
+    )
+}
+
+/// Find all the basic blocks that end in a call through a function pointer.
+fn fn_ptr_calls(body: &Body) -> impl Iterator<Item = (BasicBlockIdx, PolyFnSig)> + '_ {
+    body.blocks.iter().enumerate().filter_map(|(bb, block)| {
+        let TerminatorKind::Call { func, .. } = &block.terminator.kind else {
+            return None;
+        };
+        match func.ty(body.locals()).unwrap().kind() {
+            TyKind::RigidTy(RigidTy::FnPtr(sig)) => Some((bb, sig)),
+            _ => None,
+        }
+    })
+}
+
+/// Find the foreign functions that are coerced into a function pointer in the given body.
+fn reified_foreign_fns(body: &Body) -> impl Iterator<Item = Instance> + '_ {
+    body.blocks.iter().flat_map(|block| &block.statements).filter_map(|stmt| {
+        let StatementKind::Assign(
+            _,
+            Rvalue::Cast(CastKind::PointerCoercion(PointerCoercion::ReifyFnPointer), operand, _),
+        ) = &stmt.kind
+        else {
+            return None;
+        };
+        let TyKind::RigidTy(RigidTy::FnDef(def, args)) = operand.ty(body.locals()).ok()?.kind()
+        else {
+            return None;
+        };
+        Instance::resolve_for_fn_ptr(def, &args).ok().filter(|instance| instance.is_foreign_item())
+    })
+}
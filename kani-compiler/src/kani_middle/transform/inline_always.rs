@@ -0,0 +1,248 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Implement a transformation pass that inlines calls to small `#[inline(always)]` functions.
+//!
+//! For very small leaf functions, modeling the call and return in CBMC can cost more than the
+//! function body itself. When the `inline-always` unstable feature is enabled, we replace every
+//! call `dest = callee(args) -> target` where `callee`:
+//! - is marked `#[inline(always)]`,
+//! - has a body with a single basic block that ends with a `return`, and
+//! - has no locals other than its return value and its arguments,
+//!
+//! by the statements of the callee body, with the callee locals renamed to new locals of the
+//! caller, followed by `dest = move ret` and a `goto target`.
+//!
+//! Since the body of an inlined function cannot contain any call, recursive functions are never
+//! inlined, and a single pass over the caller is enough.
+//!
+//! The inlined statements keep their original spans, so diagnostics still point to the callee.
+//! This pass runs before the instrumentation passes, so the inlined statements get the same checks
+//! as the rest of the caller body.
+use crate::kani_middle::attributes::KaniAttributes;
+use crate::kani_middle::codegen_units::Stubs;
+use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::{Instance, InstanceKind};
+use stable_mir::mir::{
+    Body, CopyNonOverlapping, Local, NonDivergingIntrinsic, Operand, Place, ProjectionElem, Rvalue,
+    Statement, StatementKind, Terminator, TerminatorKind,
+};
+use stable_mir::ty::{RigidTy, TyKind};
+use tracing::debug;
+
+/// Inline calls to small `#[inline(always)]` functions.
+#[derive(Debug)]
+pub struct InlineAlwaysPass {
+    /// Functions that are stubbed in this codegen unit. We must not inline their original body.
+    stubs: Stubs,
+}
+
+impl InlineAlwaysPass {
+    pub fn new(stubs: &Stubs) -> Self {
+        InlineAlwaysPass { stubs: stubs.clone() }
+    }
+}
+
+impl TransformPass for InlineAlwaysPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        query_db.args().unstable_features.contains(&"inline-always".to_string())
+    }
+
+    fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        debug!(function=?instance.name(), "transform");
+        let mut new_body = MutableBody::from(body);
+        let mut changed = false;
+        for bb in 0..new_body.blocks().len() {
+            changed |= self.inline_call(tcx, &mut new_body, bb);
+        }
+        (changed, new_body.into())
+    }
+}
+
+impl InlineAlwaysPass {
+    /// Inline the call that terminates the given basic block, if its callee can be inlined.
+    fn inline_call(&self, tcx: TyCtxt, body: &mut MutableBody, bb: usize) -> bool {
+        let terminator = &body.blocks()[bb].terminator;
+        let TerminatorKind::Call { func, args, destination, target: Some(target), .. } =
+            &terminator.kind
+        else {
+            return false;
+        };
+        let TyKind::RigidTy(RigidTy::FnDef(def, fn_args)) = func.ty(body.locals()).unwrap().kind()
+        else {
+            return false;
+        };
+        if self.stubs.contains_key(&def) {
+            return false;
+        }
+        let Ok(callee) = Instance::resolve(def, &fn_args) else {
+            return false;
+        };
+        let Some(callee_body) = inlinable_body(tcx, callee) else {
+            return false;
+        };
+        debug!(callee=?callee.name(), "inline_call");
+        let span = terminator.span;
+        let target = *target;
+        let args = args.clone();
+        let destination = destination.clone();
+
+        // The callee locals are the return value followed by the arguments.
+        let locals: Vec<Local> = callee_body
+            .locals()
+            .iter()
+            .map(|decl| body.new_local(decl.ty, decl.span, decl.mutability))
+            .collect();
+        let renamer = LocalRenamer { locals: &locals };
+        let arg_assigns = args.into_iter().enumerate().map(|(idx, arg)| Statement {
+            kind: StatementKind::Assign(Place::from(locals[idx + 1]), Rvalue::Use(arg)),
+            span,
+        });
+        let callee_stmts = callee_body.blocks[0].statements.iter().map(|stmt| {
+            let mut stmt = stmt.clone();
+            renamer.rename_statement(&mut stmt);
+            stmt
+        });
+        let ret_assign = Statement {
+            kind: StatementKind::Assign(
+                destination,
+                Rvalue::Use(Operand::Move(Place::from(locals[0]))),
+            ),
+            span,
+        };
+        let mut source = SourceInstruction::Terminator { bb };
+        for stmt in arg_assigns.chain(callee_stmts).collect::<Vec<_>>() {
+            body.insert_stmt(stmt, &mut source, InsertPosition::Before);
+        }
+        body.insert_stmt(ret_assign, &mut source, InsertPosition::Before);
+        body.replace_terminator(
+            &source,
+            Terminator { kind: TerminatorKind::Goto { target }, span },
+        );
+        true
+    }
+}
+
+/// Return the body of the given function if calls to it should be inlined.
+fn inlinable_body(tcx: TyCtxt, callee: Instance) -> Option<Body> {
+    if !matches!(callee.kind, InstanceKind::Item) || !callee.has_body() {
+        return None;
+    }
+    // Kani functions are handled by later passes and by codegen, and functions with contracts
+    // may be replaced by their contract.
+    let attributes = KaniAttributes::for_instance(tcx, callee);
+    if attributes.is_kani_instrumentation() || attributes.has_contract() {
+        return None;
+    }
+    let def_id = rustc_internal::internal(tcx, callee.def.def_id());
+    if !tcx.codegen_fn_attrs(def_id).inline.always() {
+        return None;
+    }
+    let body = callee.body()?;
+    let [block] = body.blocks.as_slice() else {
+        return None;
+    };
+    let is_leaf = matches!(block.terminator.kind, TerminatorKind::Return);
+    let has_no_temps = body.locals().len() == body.arg_locals().len() + 1;
+    (is_leaf && has_no_temps && body.spread_arg().is_none()).then_some(body)
+}
+
+/// Rename the locals of an inlined body to the new locals of the caller, which are indexed by the
+/// callee local.
+struct LocalRenamer<'a> {
+    locals: &'a [Local],
+}
+
+impl LocalRenamer<'_> {
+    fn rename_statement(&self, stmt: &mut Statement) {
+        match &mut stmt.kind {
+            StatementKind::Assign(place, rvalue) => {
+                self.rename_place(place);
+                self.rename_rvalue(rvalue);
+            }
+            StatementKind::FakeRead(_, place)
+            | StatementKind::SetDiscriminant { place, .. }
+            | StatementKind::Deinit(place)
+            | StatementKind::Retag(_, place)
+            | StatementKind::PlaceMention(place)
+            | StatementKind::AscribeUserType { place, .. } => self.rename_place(place),
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+                *local = self.locals[*local];
+            }
+            StatementKind::Intrinsic(NonDivergingIntrinsic::Assume(operand)) => {
+                self.rename_operand(operand)
+            }
+            StatementKind::Intrinsic(NonDivergingIntrinsic::CopyNonOverlapping(
+                CopyNonOverlapping { src, dst, count },
+            )) => {
+                self.rename_operand(src);
+                self.rename_operand(dst);
+                self.rename_operand(count);
+            }
+            StatementKind::Coverage(_) | StatementKind::ConstEvalCounter | StatementKind::Nop => {}
+        }
+    }
+
+    fn rename_rvalue(&self, rvalue: &mut Rvalue) {
+        match rvalue {
+            Rvalue::AddressOf(_, place)
+            | Rvalue::CopyForDeref(place)
+            | Rvalue::Discriminant(place)
+            | Rvalue::Len(place)
+            | Rvalue::Ref(_, _, place) => self.rename_place(place),
+            Rvalue::Aggregate(_, operands) => {
+                for operand in operands {
+                    self.rename_operand(operand);
+                }
+            }
+            Rvalue::BinaryOp(_, lhs, rhs) | Rvalue::CheckedBinaryOp(_, lhs, rhs) => {
+                self.rename_operand(lhs);
+                self.rename_operand(rhs);
+            }
+            Rvalue::Cast(_, operand, _)
+            | Rvalue::Repeat(operand, _)
+            | Rvalue::ShallowInitBox(operand, _)
+            | Rvalue::UnaryOp(_, operand)
+            | Rvalue::Use(operand) => self.rename_operand(operand),
+            Rvalue::ThreadLocalRef(_) | Rvalue::NullaryOp(..) => {}
+        }
+    }
+
+    fn rename_operand(&self, operand: &mut Operand) {
+        match operand {
+            Operand::Copy(place) | Operand::Move(place) => self.rename_place(place),
+            Operand::Constant(_) => {}
+        }
+    }
+
+    fn rename_place(&self, place: &mut Place) {
+        place.local = self.locals[place.local];
+        for elem in &mut place.projection {
+            match elem {
+                ProjectionElem::Index(local) => *local = self.locals[*local],
+                ProjectionElem::Deref
+                | ProjectionElem::Field(..)
+                | ProjectionElem::ConstantIndex { .. }
+                | ProjectionElem::Subslice { .. }
+                | ProjectionElem::Downcast(_)
+                | ProjectionElem::OpaqueCast(_)
+                | ProjectionElem::Subtype(_) => {}
+            }
+        }
+    }
+}
@@ -44,6 +44,8 @@ pub struct IntrinsicGeneratorPass {
     kani_defs: HashMap<KaniFunction, FnDef>,
     /// Whether the user enabled uninitialized memory checks when they invoked Kani.
     enable_uninit: bool,
+    /// Whether the user asked Kani to skip the assertions of `debug_assert!` and related macros.
+    skip_debug_assertions: bool,
 }
 
 impl TransformPass for IntrinsicGeneratorPass {
@@ -72,8 +74,12 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
             match kani_intrinsic {
                 KaniIntrinsic::CheckedAlignOf => (true, self.checked_align_of(body, instance)),
                 KaniIntrinsic::CheckedSizeOf => (true, self.checked_size_of(body, instance)),
+                KaniIntrinsic::DebugAssertionsEnabled => {
+                    (true, self.debug_assertions_enabled_body(body))
+                }
                 KaniIntrinsic::IsInitialized => (true, self.is_initialized_body(body)),
-                KaniIntrinsic::ValidValue => (true, self.valid_value_body(body)),
+                KaniIntrinsic::Poison => (true, self.poison_body(body)),
+                KaniIntrinsic::ValidValue => (true, self.valid_value_body(tcx, body)),
                 // The former two are handled in contracts pass for now, while the latter is handled in the the automatic harness pass.
                 KaniIntrinsic::WriteAny
                 | KaniIntrinsic::AnyModifies
@@ -88,9 +94,43 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
 impl IntrinsicGeneratorPass {
     pub fn new(unsupported_check_type: CheckType, queries: &QueryDb) -> Self {
         let enable_uninit = queries.args().ub_check.contains(&ExtraChecks::Uninit);
+        let skip_debug_assertions =
+            queries.args().unstable_features.contains(&"skip-debug-assertions".to_string());
         let kani_defs = queries.kani_functions().clone();
-        debug!(?kani_defs, ?enable_uninit, "IntrinsicGeneratorPass::new");
-        IntrinsicGeneratorPass { unsupported_check_type, enable_uninit, kani_defs }
+        debug!(?kani_defs, ?enable_uninit, ?skip_debug_assertions, "IntrinsicGeneratorPass::new");
+        IntrinsicGeneratorPass {
+            unsupported_check_type,
+            enable_uninit,
+            skip_debug_assertions,
+            kani_defs,
+        }
+    }
+
+    /// Generate the body for `debug_assertions_enabled`, which simply returns whether the
+    /// assertions of the debug assertion macros should be checked.
+    ///
+    /// Kani's overrides of these macros only evaluate their arguments if this returns `true`.
+    /// Thus, skipping them also skips the evaluation of the asserted condition, as it would happen
+    /// in release mode.
+    fn debug_assertions_enabled_body(&self, body: Body) -> Body {
+        let mut new_body = MutableBody::from(body);
+        new_body.clear_body(TerminatorKind::Return);
+        let span = new_body.locals()[RETURN_LOCAL].span;
+        let assign = StatementKind::Assign(
+            Place::from(RETURN_LOCAL),
+            Rvalue::Use(Operand::Constant(ConstOperand {
+                span,
+                user_ty: None,
+                const_: MirConst::from_bool(!self.skip_debug_assertions),
+            })),
+        );
+        let mut terminator = SourceInstruction::Terminator { bb: 0 };
+        new_body.insert_stmt(
+            Statement { kind: assign, span },
+            &mut terminator,
+            InsertPosition::Before,
+        );
+        new_body.into()
     }
 
     /// Generate the body for valid value. Which should be something like:
@@ -105,7 +145,7 @@ pub fn new(unsupported_check_type: CheckType, queries: &QueryDb) -> Self {
     ///     ret
     /// }
     /// ```
-    fn valid_value_body(&self, body: Body) -> Body {
+    fn valid_value_body(&self, tcx: TyCtxt, body: Body) -> Body {
         let mut new_body = MutableBody::from(body);
         new_body.clear_body(TerminatorKind::Return);
 
@@ -128,7 +168,7 @@ fn valid_value_body(&self, body: Body) -> Body {
         // The first and only argument type.
         let arg_ty = new_body.locals()[1].ty;
         let TyKind::RigidTy(RigidTy::RawPtr(target_ty, _)) = arg_ty.kind() else { unreachable!() };
-        let validity = ty_validity_per_offset(&machine_info, target_ty, 0);
+        let validity = ty_validity_per_offset(tcx, &machine_info, target_ty, 0);
         match validity {
             Ok(ranges) if ranges.is_empty() => {
                 // Nothing to check
@@ -166,6 +206,16 @@ fn valid_value_body(&self, body: Body) -> Body {
         new_body.into()
     }
 
+    /// Generate the body for `poison`, which is a no-op.
+    ///
+    /// The memory initialization state is updated by the uninitialized memory instrumentation at
+    /// the call site, since it only exists if uninitialized memory checks are enabled.
+    fn poison_body(&mut self, body: Body) -> Body {
+        let mut new_body = MutableBody::from(body);
+        new_body.clear_body(TerminatorKind::Return);
+        new_body.into()
+    }
+
     /// Generate the body for `is_initialized`, which looks like the following
     ///
     /// ```
@@ -18,19 +18,25 @@
 //! case is added.
 use crate::kani_middle::codegen_units::CodegenUnit;
 use crate::kani_middle::reachability::CallGraph;
+use crate::kani_middle::transform::black_box::BlackBoxPass;
 use crate::kani_middle::transform::body::CheckType;
 use crate::kani_middle::transform::check_uninit::{DelayedUbPass, UninitPass};
 use crate::kani_middle::transform::check_values::ValidValuePass;
 use crate::kani_middle::transform::contracts::{AnyModifiesPass, FunctionWithContractPass};
+use crate::kani_middle::transform::fn_ptr_restrictions::FnPtrRestrictionPass;
+use crate::kani_middle::transform::inline_always::InlineAlwaysPass;
 use crate::kani_middle::transform::kani_intrinsics::IntrinsicGeneratorPass;
 use crate::kani_middle::transform::loop_contracts::LoopContractPass;
-use crate::kani_middle::transform::stubs::{ExternFnStubPass, FnStubPass};
+use crate::kani_middle::transform::stubs::{
+    ExternFnStubPass, FnStubPass, GlobalAllocatorPass, VecCapacityPass,
+};
+use crate::kani_middle::transform::unreachable_unchecked::UnreachableUncheckedPass;
 use crate::kani_queries::QueryDb;
 use automatic::AutomaticHarnessPass;
 use dump_mir_pass::DumpMirPass;
 use rustc_middle::ty::TyCtxt;
-use stable_mir::mir::Body;
 use stable_mir::mir::mono::{Instance, MonoItem};
+use stable_mir::mir::{BasicBlockIdx, Body};
 use std::collections::HashMap;
 use std::fmt::Debug;
 
@@ -38,16 +44,20 @@
 pub use internal_mir::RustcInternalMir;
 
 mod automatic;
+mod black_box;
 pub(crate) mod body;
 mod check_uninit;
-mod check_values;
+pub(crate) mod check_values;
 mod contracts;
 mod dump_mir_pass;
+mod fn_ptr_restrictions;
+mod inline_always;
 mod internal_mir;
 mod kani_intrinsics;
 mod loop_contracts;
 mod rustc_intrinsics;
 mod stubs;
+mod unreachable_unchecked;
 
 /// Object used to retrieve a transformed instance body.
 /// The transformations to be applied may be controlled by user options.
@@ -63,6 +73,10 @@ pub struct BodyTransformation {
     inst_passes: Vec<Box<dyn TransformPass>>,
     /// Cache transformation results.
     cache: HashMap<Instance, TransformationResult>,
+    /// The possible targets of calls through function pointers, indexed by the caller and the
+    /// basic block that ends with the call. Only populated if function pointer restrictions are
+    /// enabled.
+    fn_ptr_targets: HashMap<(Instance, BasicBlockIdx), Vec<Instance>>,
 }
 
 impl BodyTransformation {
@@ -71,6 +85,7 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
             stub_passes: vec![],
             inst_passes: vec![],
             cache: Default::default(),
+            fn_ptr_targets: Default::default(),
         };
         let safety_check_type = CheckType::new_safety_check_assert_assume(queries);
         let unsupported_check_type = CheckType::new_unsupported_check_assert_assume_false(queries);
@@ -78,10 +93,16 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
         transformer.add_pass(queries, AutomaticHarnessPass::new(unit, queries));
         transformer.add_pass(queries, FnStubPass::new(&unit.stubs));
         transformer.add_pass(queries, ExternFnStubPass::new(&unit.stubs));
+        transformer.add_pass(queries, GlobalAllocatorPass::new(tcx, queries));
+        transformer.add_pass(queries, VecCapacityPass::new(tcx, queries));
         transformer.add_pass(queries, FunctionWithContractPass::new(tcx, queries, &unit));
         // This has to come after the contract pass since we want this to only replace the closure
         // body that is relevant for this harness.
         transformer.add_pass(queries, AnyModifiesPass::new(tcx, queries, &unit));
+        // Remove `black_box` calls before instrumentation, so the checks see the simplified body.
+        transformer.add_pass(queries, BlackBoxPass);
+        // Inline small functions before instrumentation, so the inlined statements get checked.
+        transformer.add_pass(queries, InlineAlwaysPass::new(&unit.stubs));
         transformer.add_pass(
             queries,
             ValidValuePass {
@@ -89,6 +110,10 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
                 unsupported_check_type: unsupported_check_type.clone(),
             },
         );
+        transformer.add_pass(
+            queries,
+            UnreachableUncheckedPass { safety_check_type: safety_check_type.clone() },
+        );
         // Putting `UninitPass` after `ValidValuePass` makes sure that the code generated by
         // `UninitPass` does not get unnecessarily instrumented by valid value checks. However, it
         // would also make sense to check that the values are initialized before checking their
@@ -139,6 +164,12 @@ pub fn body(&mut self, tcx: TyCtxt, instance: Instance) -> Body {
         }
     }
 
+    /// Retrieve the possible targets of the call through a function pointer at the end of the
+    /// given basic block, if any were found.
+    pub fn fn_ptr_targets(&self, instance: Instance, bb: BasicBlockIdx) -> Option<&[Instance]> {
+        self.fn_ptr_targets.get(&(instance, bb)).map(Vec::as_slice)
+    }
+
     fn add_pass<P: TransformPass + 'static>(&mut self, query_db: &QueryDb, pass: P) {
         if pass.is_enabled(&query_db) {
             match P::transformation_type() {
@@ -215,6 +246,7 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
                 queries,
             ),
         );
+        global_passes.add_global_pass(queries, FnPtrRestrictionPass);
         global_passes.add_global_pass(queries, DumpMirPass::new(tcx));
         global_passes
     }
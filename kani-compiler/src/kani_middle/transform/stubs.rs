@@ -3,17 +3,24 @@
 //! This module contains code related to the MIR-to-MIR pass that performs the
 //! stubbing of functions and methods.
 use crate::kani_middle::codegen_units::Stubs;
+use crate::kani_middle::kani_functions::KaniHook;
+use crate::kani_middle::stable_fn_def;
 use crate::kani_middle::stubbing::validate_stub_const;
-use crate::kani_middle::transform::body::{MutMirVisitor, MutableBody};
+use crate::kani_middle::transform::body::{
+    InsertPosition, MutMirVisitor, MutableBody, SourceInstruction,
+};
 use crate::kani_middle::transform::{TransformPass, TransformationType};
 use crate::kani_queries::QueryDb;
+use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_middle::ty::TyCtxt;
 use rustc_smir::rustc_internal;
-use stable_mir::CrateDef;
 use stable_mir::mir::mono::Instance;
 use stable_mir::mir::visit::{Location, MirVisitor};
-use stable_mir::mir::{Body, ConstOperand, LocalDecl, Operand, Terminator, TerminatorKind};
-use stable_mir::ty::{FnDef, MirConst, RigidTy, TyKind};
+use stable_mir::mir::{
+    BinOp, Body, ConstOperand, LocalDecl, Mutability, Operand, Place, Terminator, TerminatorKind,
+};
+use stable_mir::ty::{FnDef, GenericArgs, MirConst, RigidTy, Ty, TyKind, UintTy};
+use stable_mir::{CrateDef, DefId as StableDefId};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use tracing::{debug, trace};
@@ -123,6 +130,179 @@ pub fn new(all_stubs: &Stubs) -> ExternFnStubPass {
     }
 }
 
+/// Redirect calls to the Rust allocation functions, such as `__rust_alloc`, to the functions
+/// generated by the `#[global_allocator]` macro.
+///
+/// By default, the allocation functions are modelled using CBMC's allocation built-ins
+/// (see `kani_lib.c`). This pass is enabled by `--verify-allocator`, which allows users to verify
+/// their own allocator implementation instead.
+///
+/// Note that only calls to the `__rust_*` functions are redirected, so there is no recursion
+/// between the allocator and this pass. If the allocator itself uses the system allocator, the
+/// memory it returns is still modelled by CBMC.
+#[derive(Debug)]
+pub struct GlobalAllocatorPass {
+    inner: ExternFnStubPass,
+}
+
+impl TransformPass for GlobalAllocatorPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        query_db.args().verify_allocator && !self.inner.stubs.is_empty()
+    }
+
+    fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        self.inner.transform(tcx, body, instance)
+    }
+}
+
+impl GlobalAllocatorPass {
+    /// The Rust allocation functions declared in `alloc`, and the name of the function
+    /// generated by the `#[global_allocator]` macro that implements each one of them.
+    const ALLOCATOR_FNS: [(&str, &str); 4] = [
+        ("alloc::alloc::__rust_alloc", "__rg_alloc"),
+        ("alloc::alloc::__rust_alloc_zeroed", "__rg_alloc_zeroed"),
+        ("alloc::alloc::__rust_dealloc", "__rg_dealloc"),
+        ("alloc::alloc::__rust_realloc", "__rg_realloc"),
+    ];
+
+    pub fn new(tcx: TyCtxt, queries: &QueryDb) -> GlobalAllocatorPass {
+        let stubs =
+            if queries.args().verify_allocator { Self::find_stubs(tcx) } else { Stubs::default() };
+        GlobalAllocatorPass { inner: ExternFnStubPass { stubs } }
+    }
+
+    /// Map each allocation function to its implementation in the user's global allocator.
+    fn find_stubs(tcx: TyCtxt) -> Stubs {
+        let Some(krate) = std::iter::once(LOCAL_CRATE)
+            .chain(tcx.crates(()).iter().copied())
+            .find(|krate| tcx.has_global_allocator(*krate))
+        else {
+            tcx.dcx().err(
+                "`--verify-allocator` requires a `#[global_allocator]` to be defined in the \
+                crate or one of its dependencies",
+            );
+            return Stubs::default();
+        };
+        let find_fns = |crate_name: &str, matches: &dyn Fn(&str) -> Option<&'static str>| {
+            stable_mir::find_crates(crate_name)
+                .into_iter()
+                .flat_map(|krate| krate.fn_defs())
+                .filter_map(|def| matches(&def.name()).map(|key| (key, def)))
+                .collect::<HashMap<_, _>>()
+        };
+        let rust_fns = find_fns("alloc", &|name: &str| {
+            Self::ALLOCATOR_FNS.iter().find(|(rust_fn, _)| *rust_fn == name).map(|(key, _)| *key)
+        });
+        let allocator_fns = find_fns(tcx.crate_name(krate).as_str(), &|name: &str| {
+            let short_name = name.rsplit("::").next().unwrap();
+            Self::ALLOCATOR_FNS
+                .iter()
+                .find(|(_, allocator_fn)| *allocator_fn == short_name)
+                .map(|(key, _)| *key)
+        });
+        debug!(?rust_fns, ?allocator_fns, "GlobalAllocatorPass::find_stubs");
+        Self::ALLOCATOR_FNS
+            .iter()
+            .filter_map(|(key, _)| Some((*rust_fns.get(key)?, *allocator_fns.get(key)?)))
+            .collect()
+    }
+}
+
+/// Bound the capacity requested by `Vec::with_capacity` when `--vec-max-capacity` is set.
+///
+/// This pass instruments the body of `Vec::<T>::with_capacity` with an assumption that its
+/// argument does not exceed the maximum capacity, so the solver never has to reason about huge
+/// allocations. The original body is kept, so the resulting vector is still empty and has at
+/// least the requested capacity.
+#[derive(Debug)]
+pub struct VecCapacityPass {
+    /// The definition of `Vec::with_capacity`, if it was found.
+    with_capacity: Option<StableDefId>,
+    /// The `kani::assume` function used to bound the capacity.
+    assume: Option<Instance>,
+    /// The maximum capacity, saturated to the maximum `usize` value of the target.
+    max_capacity: u128,
+}
+
+impl TransformPass for VecCapacityPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        query_db.args().vec_max_capacity.is_some() && self.with_capacity.is_some()
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        if Some(instance.def.def_id()) != self.with_capacity {
+            return (false, body);
+        }
+        debug!(function=?instance.name(), "VecCapacityPass::transform");
+        let mut new_body = MutableBody::from(body);
+        let mut source = if new_body.blocks()[0].statements.is_empty() {
+            SourceInstruction::Terminator { bb: 0 }
+        } else {
+            SourceInstruction::Statement { idx: 0, bb: 0 }
+        };
+        let span = source.span(new_body.blocks());
+        // The requested capacity is the first and only argument.
+        let capacity = Operand::Copy(Place::from(1));
+        let max = new_body.new_uint_operand(self.max_capacity, UintTy::Usize, span);
+        let in_bounds = new_body.insert_binary_op(
+            BinOp::Le,
+            capacity,
+            max,
+            &mut source,
+            InsertPosition::Before,
+        );
+        let unit = new_body.new_local(Ty::new_tuple(&[]), span, Mutability::Not);
+        new_body.insert_call(
+            self.assume.as_ref().unwrap(),
+            &mut source,
+            InsertPosition::Before,
+            vec![Operand::Move(Place::from(in_bounds))],
+            Place::from(unit),
+        );
+        (true, new_body.into())
+    }
+}
+
+impl VecCapacityPass {
+    pub fn new(tcx: TyCtxt, queries: &QueryDb) -> VecCapacityPass {
+        let Some(max_capacity) = queries.args().vec_max_capacity else {
+            return VecCapacityPass { with_capacity: None, assume: None, max_capacity: 0 };
+        };
+        let max_usize = tcx.data_layout.pointer_size.unsigned_int_max();
+        let with_capacity = tcx
+            .get_diagnostic_item(rustc_span::Symbol::intern("vec_with_capacity"))
+            .and_then(|def_id| stable_fn_def(tcx, def_id))
+            .map(|def| def.def_id());
+        let assume_def = queries.kani_functions()[&KaniHook::Assume.into()];
+        let assume = Instance::resolve(assume_def, &GenericArgs(vec![])).unwrap();
+        VecCapacityPass {
+            with_capacity,
+            assume: Some(assume),
+            max_capacity: u128::from(max_capacity).min(max_usize),
+        }
+    }
+}
+
 fn has_body(def: FnDef) -> bool {
     def.body().is_some()
 }
@@ -0,0 +1,74 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Implement a transformation pass that flags any reachable call to
+//! `core::hint::unreachable_unchecked` as undefined behavior.
+//!
+//! Calling `unreachable_unchecked` tells the compiler that the current path is dead, so
+//! reaching it is UB. Its body lowers to an `Unreachable` terminator, which is indistinguishable
+//! from the ones inserted by the compiler (e.g. for exhaustive matches). Thus, we instrument the
+//! body of `unreachable_unchecked` itself with a safety check that always fails.
+//!
+//! Compiler-inserted `Unreachable` terminators keep their existing handling.
+use crate::kani_middle::transform::body::{
+    CheckType, InsertPosition, MutableBody, SourceInstruction,
+};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{Body, ConstOperand, Operand, Rvalue};
+use stable_mir::ty::MirConst;
+use tracing::debug;
+
+/// Instrument `core::hint::unreachable_unchecked` with a safety check.
+#[derive(Debug)]
+pub struct UnreachableUncheckedPass {
+    pub safety_check_type: CheckType,
+}
+
+impl TransformPass for UnreachableUncheckedPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Instrumentation
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        if instance.def.name() != "core::hint::unreachable_unchecked" {
+            return (false, body);
+        }
+        debug!(function=?instance.name(), "transform");
+        let mut new_body = MutableBody::from(body);
+        let mut source = if new_body.blocks()[0].statements.is_empty() {
+            SourceInstruction::Terminator { bb: 0 }
+        } else {
+            SourceInstruction::Statement { idx: 0, bb: 0 }
+        };
+        let span = source.span(new_body.blocks());
+        let reachable = Operand::Constant(ConstOperand {
+            span,
+            user_ty: None,
+            const_: MirConst::from_bool(false),
+        });
+        let value =
+            new_body.insert_assignment(Rvalue::Use(reachable), &mut source, InsertPosition::Before);
+        new_body.insert_check(
+            &self.safety_check_type,
+            &mut source,
+            InsertPosition::Before,
+            Some(value),
+            "reached `unreachable_unchecked` (UB)",
+        );
+        (true, new_body.into())
+    }
+}
@@ -241,6 +241,50 @@ pub struct VerificationArgs {
     /// Kani will only compile the crate. No verification will be performed
     #[arg(long, hide_short_help = true)]
     pub only_codegen: bool,
+    /// Check that the harnesses compile and can be instrumented, without generating goto
+    /// binaries or running verification. Errors that only surface during code generation, such
+    /// as unsupported constructs, are not reported in this mode.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, conflicts_with = "only_codegen")]
+    pub check_only: bool,
+    /// Print all the functions that are reachable from the harnesses whose name contains the
+    /// given string, including which functions are stubbed, which are modeled by Kani and which
+    /// contain unsupported constructs.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "HARNESS")]
+    pub dump_reachable_functions: Option<String>,
+    /// Print the call graph of the functions that are reachable from each harness in Graphviz DOT
+    /// format. This can help understand why a dependency is included in a proof.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub print_call_graph: bool,
+    /// Replace every function that contains an unsupported construct by a stub that returns a
+    /// nondeterministic value. This is unsound: verification results only hold if the stubs
+    /// are a sound abstraction of the replaced functions. Side effects through pointer arguments
+    /// are not modeled by the stubs, and functions that return types with validity invariants
+    /// (e.g. references, `char`, or enums) are not stubbed. Every stubbed function is listed in
+    /// the verification results.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub stub_unsupported: bool,
+    /// Fail the compilation if the nondeterministic input of a harness exceeds the given number
+    /// of bytes. This includes every `kani::any` call reachable from the harness, where arrays
+    /// count their full length and symbolic-length slices count their maximum length.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "BYTES")]
+    pub max_nondet_bytes: Option<u64>,
+    /// Assume that the capacity requested by every call to `Vec::with_capacity` is at most the
+    /// given value. This is unsound: executions that request a larger capacity are discarded.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "CAPACITY")]
+    pub vec_max_capacity: Option<u64>,
+    /// Stub the function with the given fully qualified path by another function, e.g.,
+    /// `--stub-by-name some_crate::module::func=crate::stubs::func`. This can be used to stub
+    /// functions from dependencies that cannot be annotated. The replacement must have a
+    /// signature compatible with the original function. Can be specified multiple times.
+    /// This option requires `-Z stubbing` to be used.
+    #[arg(long, hide_short_help = true, value_name = "ORIGINAL=REPLACEMENT")]
+    pub stub_by_name: Vec<String>,
 
     /// Specify the value used for loop unwinding in CBMC
     #[arg(long)]
@@ -283,7 +327,14 @@ pub struct VerificationArgs {
     /// Disable restricting the targets of virtual table function pointer calls
     #[arg(long, hide_short_help = true)]
     pub no_restrict_vtable: bool,
-    /// Turn off assertion reachability checks
+    /// Restrict the targets of calls through function pointers to the reachable functions with
+    /// a matching signature.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, hide_short_help = true)]
+    pub function_pointer_restrictions: bool,
+    /// Turn off assertion reachability checks, including the ones for covers.
+    /// This may speed up verification, but unreachable checks are then reported as `SUCCESS`
+    /// (or `UNSATISFIABLE` for covers) instead of `UNREACHABLE`.
     #[arg(long)]
     pub no_assertion_reach_checks: bool,
 
@@ -336,6 +387,37 @@ pub struct VerificationArgs {
     #[arg(long, hide = true)]
     pub print_llbc: bool,
 
+    /// Print the functions that took the longest to codegen, aggregated across their
+    /// monomorphizations. This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub verbose_codegen_timing: bool,
+
+    /// Print statistics about the structural sharing of each generated symbol table, such as the
+    /// number of unique ireps and strings. This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub print_symtab_stats: bool,
+
+    /// Print approximate measures of the complexity of each harness, as reported by CBMC, such
+    /// as the number of symbolic execution steps and the size of the SAT formula. Note that
+    /// these are not a count of the explored paths. This option requires `-Z unstable-options`
+    /// to be used.
+    #[arg(long, hide_short_help = true)]
+    pub complexity_stats: bool,
+
+    /// Fail if Kani cannot generate goto binaries in the given version. Use this to ensure that
+    /// the generated goto binaries can be consumed by external CBMC tools built for that version.
+    /// Use `--gen-c` to also dump a C file via `goto-instrument --dump-c`.
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "VERSION")]
+    pub goto_binary_version: Option<usize>,
+
+    /// Verify the crate's `#[global_allocator]` by routing allocations through it, instead of
+    /// modelling them with CBMC's built-in allocation. Allocators that manage large memory pools
+    /// may require a higher number of object bits (`--cbmc-args --object-bits <N>`).
+    /// This option requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub verify_allocator: bool,
+
     /// Timeout for each harness with optional suffix ('s': seconds, 'm': minutes, 'h': hours). Default is seconds. This option is experimental and requires `-Z unstable-options` to be used.
     #[arg(long)]
     pub harness_timeout: Option<Timeout>,
@@ -696,6 +778,84 @@ fn validate(&self) -> Result<(), Error> {
             UnstableFeature::UnstableOptions,
         )?;
 
+        self.common_args.check_unstable(
+            self.verbose_codegen_timing,
+            "--verbose-codegen-timing",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.print_symtab_stats,
+            "--print-symtab-stats",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.goto_binary_version.is_some(),
+            "--goto-binary-version",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.check_only,
+            "--check-only",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.verify_allocator,
+            "--verify-allocator",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.dump_reachable_functions.is_some(),
+            "--dump-reachable-functions",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.print_call_graph,
+            "--print-call-graph",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.stub_unsupported,
+            "--stub-unsupported",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.max_nondet_bytes.is_some(),
+            "--max-nondet-bytes",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.vec_max_capacity.is_some(),
+            "--vec-max-capacity",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            !self.stub_by_name.is_empty(),
+            "--stub-by-name",
+            UnstableFeature::Stubbing,
+        )?;
+
+        self.common_args.check_unstable(
+            self.complexity_stats,
+            "--complexity-stats",
+            UnstableFeature::UnstableOptions,
+        )?;
+
+        self.common_args.check_unstable(
+            self.function_pointer_restrictions,
+            "--function-pointer-restrictions",
+            UnstableFeature::UnstableOptions,
+        )?;
+
         if self.restrict_vtable {
             // Deprecated `--restrict-vtable` in favor our `-Z restrict-vtable`.
             print_deprecated(&self.common_args, "--restrict-vtable", "-Z restrict-vtable");
@@ -995,6 +1155,96 @@ fn check_disable_slicing_unstable() {
         check_unstable_flag!("--no-slice-formula", no_slice_formula);
     }
 
+    #[test]
+    fn check_verbose_codegen_timing_unstable() {
+        check_unstable_flag!("--verbose-codegen-timing", verbose_codegen_timing);
+    }
+
+    #[test]
+    fn check_print_symtab_stats_unstable() {
+        check_unstable_flag!("--print-symtab-stats", print_symtab_stats);
+    }
+
+    #[test]
+    fn check_check_only_unstable() {
+        check_unstable_flag!("--check-only", check_only);
+    }
+
+    #[test]
+    fn check_verify_allocator_unstable() {
+        check_unstable_flag!("--verify-allocator", verify_allocator);
+    }
+
+    #[test]
+    fn check_goto_binary_version_unstable() {
+        check_opt!(
+            "--goto-binary-version 6",
+            Some(UnstableFeature::UnstableOptions),
+            goto_binary_version,
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn check_dump_reachable_functions_unstable() {
+        check_opt!(
+            "--dump-reachable-functions check_foo",
+            Some(UnstableFeature::UnstableOptions),
+            dump_reachable_functions,
+            Some("check_foo".to_string())
+        );
+    }
+
+    #[test]
+    fn check_print_call_graph_unstable() {
+        check_unstable_flag!("--print-call-graph", print_call_graph);
+    }
+
+    #[test]
+    fn check_stub_unsupported_unstable() {
+        check_unstable_flag!("--stub-unsupported", stub_unsupported);
+    }
+
+    #[test]
+    fn check_max_nondet_bytes_unstable() {
+        check_opt!(
+            "--max-nondet-bytes 1024",
+            Some(UnstableFeature::UnstableOptions),
+            max_nondet_bytes,
+            Some(1024)
+        );
+    }
+
+    #[test]
+    fn check_vec_max_capacity_unstable() {
+        check_opt!(
+            "--vec-max-capacity 8",
+            Some(UnstableFeature::UnstableOptions),
+            vec_max_capacity,
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn check_stub_by_name_unstable() {
+        check_opt!(
+            "--stub-by-name dep::foo=stub_foo --stub-by-name dep::bar=stub_bar",
+            Some(UnstableFeature::Stubbing),
+            stub_by_name,
+            vec!["dep::foo=stub_foo".to_string(), "dep::bar=stub_bar".to_string()]
+        );
+    }
+
+    #[test]
+    fn check_complexity_stats_unstable() {
+        check_unstable_flag!("--complexity-stats", complexity_stats);
+    }
+
+    #[test]
+    fn check_function_pointer_restrictions_unstable() {
+        check_unstable_flag!("--function-pointer-restrictions", function_pointer_restrictions);
+    }
+
     #[test]
     fn check_concrete_playback_unstable() {
         let check = |input: &str| {
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::{Result, bail};
-use kani_metadata::{CbmcSolver, HarnessMetadata};
+use kani_metadata::{CbmcSolver, HarnessAttributes, HarnessMetadata};
 use regex::Regex;
 use rustc_demangle::demangle;
 use std::collections::BTreeMap;
@@ -18,7 +18,7 @@
 use crate::args::common::Verbosity;
 use crate::args::{OutputFormat, VerificationArgs};
 use crate::cbmc_output_parser::{
-    CheckStatus, Property, VerificationOutput, extract_results, process_cbmc_output,
+    CheckStatus, ParserItem, Property, VerificationOutput, extract_results, process_cbmc_output,
 };
 use crate::cbmc_property_renderer::{format_coverage, format_result, kani_cbmc_output_filter};
 use crate::coverage::cov_results::{CoverageCheck, CoverageResults};
@@ -48,6 +48,32 @@ pub enum FailedProperties {
     Other,
 }
 
+/// The verification result that the user expects for a harness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpectedOutcome {
+    /// All checks are expected to pass. This is the default.
+    Success,
+    /// One or more checks are expected to fail, and they must all be panic-related.
+    /// I.e., the harness was annotated with `#[kani::should_panic]`.
+    Panic,
+    /// One or more checks are expected to fail. Unsupported constructs and sanity checks do not
+    /// count, since their failures make the result unreliable.
+    /// I.e., the harness was annotated with `#[kani::expect(failure)]`.
+    Failure,
+}
+
+impl From<&HarnessAttributes> for ExpectedOutcome {
+    fn from(attributes: &HarnessAttributes) -> Self {
+        if attributes.expect_failure {
+            ExpectedOutcome::Failure
+        } else if attributes.should_panic {
+            ExpectedOutcome::Panic
+        } else {
+            ExpectedOutcome::Success
+        }
+    }
+}
+
 /// The possible CBMC exit statuses
 #[derive(Clone, Copy, Debug)]
 pub enum ExitStatus {
@@ -75,6 +101,80 @@ pub struct VerificationResult {
     pub generated_concrete_test: bool,
     /// The coverage results
     pub coverage_results: Option<CoverageResults>,
+    /// Approximate measures of the harness complexity. Only collected if `--complexity-stats`
+    /// is set.
+    pub complexity: Option<ComplexityStats>,
+    /// The unsupported constructs of the harness that were reachable during verification.
+    pub reachable_unsupported_features: Vec<String>,
+}
+
+/// Rough measures of how complex the verification of a harness is, as reported by CBMC.
+///
+/// CBMC explores all paths at once through symbolic execution, so it cannot tell how many
+/// distinct paths a harness has. These numbers are only meant to approximate the size of the
+/// problem, and each field is `None` if CBMC did not report it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComplexityStats {
+    /// The number of steps in the program expression generated by symbolic execution.
+    pub symex_steps: Option<u64>,
+    /// The number of verification conditions generated, before and after simplification.
+    pub vccs: Option<(u64, u64)>,
+    /// The number of variables and clauses of the largest formula given to the SAT solver.
+    pub solver_size: Option<(u64, u64)>,
+}
+
+impl ComplexityStats {
+    /// Extract the statistics from the messages printed by CBMC.
+    fn from_items(items: &[ParserItem]) -> ComplexityStats {
+        let stats_re = {
+            static STATS_RE: OnceLock<[Regex; 3]> = OnceLock::new();
+            STATS_RE.get_or_init(|| {
+                [
+                    Regex::new(r"^size of program expression: (?<steps>[0-9]+) steps").unwrap(),
+                    Regex::new(
+                        r"^Generated (?<total>[0-9]+) VCC\(s\), (?<remaining>[0-9]+) remaining",
+                    )
+                    .unwrap(),
+                    Regex::new(r"^(?<vars>[0-9]+) variables, (?<clauses>[0-9]+) clauses").unwrap(),
+                ]
+            })
+        };
+        let [steps_re, vccs_re, solver_re] = stats_re;
+        let number = |captures: &regex::Captures, name: &str| captures[name].parse().ok();
+
+        let mut stats = ComplexityStats::default();
+        for item in items {
+            let ParserItem::Message { message_text, .. } = item else { continue };
+            if let Some(captures) = steps_re.captures(message_text) {
+                stats.symex_steps = number(&captures, "steps");
+            } else if let Some(captures) = vccs_re.captures(message_text) {
+                stats.vccs = number(&captures, "total").zip(number(&captures, "remaining"));
+            } else if let Some(captures) = solver_re.captures(message_text) {
+                // CBMC may invoke the solver more than once, so keep the largest formula.
+                let size = number(&captures, "vars").zip(number(&captures, "clauses"));
+                stats.solver_size = stats.solver_size.max(size);
+            }
+        }
+        stats
+    }
+
+    fn render(&self) -> String {
+        let mut stats = vec![];
+        if let Some(steps) = self.symex_steps {
+            stats.push(format!("{steps} symex steps"));
+        }
+        if let Some((total, remaining)) = self.vccs {
+            stats.push(format!("{total} VCCs ({remaining} after simplification)"));
+        }
+        if let Some((vars, clauses)) = self.solver_size {
+            stats.push(format!("{vars} SAT variables, {clauses} clauses"));
+        }
+        if stats.is_empty() {
+            String::from("Complexity: not reported by CBMC")
+        } else {
+            format!("Complexity (approximation, not a path count): {}", stats.join(", "))
+        }
+    }
 }
 
 impl KaniSession {
@@ -87,7 +187,7 @@ pub fn run_cbmc(&self, file: &Path, harness: &HarnessMetadata) -> Result<Verific
         cmd.args(args);
 
         let verification_results = if self.args.output_format == crate::args::OutputFormat::Old {
-            if self.run_terminal_timeout(cmd).is_err() {
+            if self.run_terminal_timeout(cmd, self.harness_timeout(harness)).is_err() {
                 VerificationResult::mock_failure()
             } else {
                 VerificationResult::mock_success()
@@ -104,6 +204,17 @@ pub fn run_cbmc(&self, file: &Path, harness: &HarnessMetadata) -> Result<Verific
         Ok(verification_results)
     }
 
+    /// The time limit to verify the given harness, if any.
+    ///
+    /// A `#[kani::solver_timeout]` attribute takes precedence over `--harness-timeout`.
+    fn harness_timeout(&self, harness: &HarnessMetadata) -> Option<Duration> {
+        harness
+            .attributes
+            .solver_timeout
+            .map(|secs| Duration::from_secs(secs.into()))
+            .or(self.args.harness_timeout.map(Duration::from))
+    }
+
     async fn run_cbmc_piped(
         &self,
         mut cmd: TokioCommand,
@@ -120,9 +231,9 @@ async fn run_cbmc_piped(
 
         let start_time = Instant::now();
 
-        let res = if let Some(timeout) = self.args.harness_timeout {
+        let res = if let Some(timeout) = self.harness_timeout(harness) {
             tokio::time::timeout(
-                timeout.into(),
+                timeout,
                 process_cbmc_output(&mut cbmc_process, |i| {
                     kani_cbmc_output_filter(
                         i,
@@ -158,11 +269,24 @@ async fn run_cbmc_piped(
                 runtime: start_time.elapsed(),
                 generated_concrete_test: false,
                 coverage_results: None,
+                complexity: None,
+                reachable_unsupported_features: vec![],
             }
         } else {
             // The timeout wasn't reached
             let output = res.unwrap()?;
-            VerificationResult::from(output, harness.attributes.should_panic, start_time)
+            let complexity = self
+                .args
+                .complexity_stats
+                .then(|| ComplexityStats::from_items(&output.processed_items));
+            let mut result =
+                VerificationResult::from(output, (&harness.attributes).into(), start_time);
+            result.complexity = complexity;
+            if let Ok(properties) = &result.results {
+                result.reachable_unsupported_features =
+                    reachable_unsupported_features(harness, properties);
+            }
+            result
         };
 
         Ok(verification_results)
@@ -315,7 +439,7 @@ impl VerificationResult {
     ///      (Do not mistake lack of results for success: report it as failure.)
     fn from(
         output: VerificationOutput,
-        should_panic: bool,
+        expected: ExpectedOutcome,
         start_time: Instant,
     ) -> VerificationResult {
         let runtime = start_time.elapsed();
@@ -323,7 +447,7 @@ fn from(
 
         if let Some(results) = results {
             let (status, failed_properties) =
-                verification_outcome_from_properties(&results, should_panic);
+                verification_outcome_from_properties(&results, expected);
             let coverage_results = coverage_results_from_properties(&results);
             VerificationResult {
                 status,
@@ -332,6 +456,8 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results,
+                complexity: None,
+                reachable_unsupported_features: vec![],
             }
         } else {
             // We never got results from CBMC - something went wrong (e.g. crash) so it's failure
@@ -347,6 +473,8 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results: None,
+                complexity: None,
+                reachable_unsupported_features: vec![],
             }
         }
     }
@@ -359,6 +487,8 @@ pub fn mock_success() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            complexity: None,
+            reachable_unsupported_features: vec![],
         }
     }
 
@@ -373,10 +503,12 @@ fn mock_failure() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            complexity: None,
+            reachable_unsupported_features: vec![],
         }
     }
 
-    pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String {
+    pub fn render(&self, output_format: &OutputFormat, expected: ExpectedOutcome) -> String {
         match &self.results {
             Ok(results) => {
                 let status = self.status;
@@ -388,18 +520,29 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
                         results,
                         cov_results,
                         status,
-                        should_panic,
+                        expected,
                         failed_properties,
                         show_checks,
                     )
                 } else {
-                    format_result(results, status, should_panic, failed_properties, show_checks)
+                    format_result(results, status, expected, failed_properties, show_checks)
                 };
                 writeln!(result, "Verification Time: {}s", self.runtime.as_secs_f32()).unwrap();
+                if let Some(complexity) = &self.complexity {
+                    writeln!(result, "{}", complexity.render()).unwrap();
+                }
+                for feature in &self.reachable_unsupported_features {
+                    writeln!(result, "[Kani] info: Reachable unsupported construct: {feature}")
+                        .unwrap();
+                }
                 result
             }
             Err(exit_status) => {
-                let verification_result = console::style("FAILED").red();
+                let verification_result = if matches!(exit_status, ExitStatus::Timeout) {
+                    console::style("TIMEOUT").yellow()
+                } else {
+                    console::style("FAILED").red()
+                };
                 let (header, explanation) = match exit_status {
                     ExitStatus::OutOfMemory => (
                         String::from("CBMC failed"),
@@ -408,7 +551,7 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
                     code the verifier reasons about.\n",
                     ),
                     ExitStatus::Timeout => (
-                        String::from("CBMC failed"),
+                        String::from("CBMC timed out"),
                         "CBMC timed out. You may want to rerun your proof with a larger timeout \
                     or use stubbing to reduce the size of the code the verifier reasons about.\n",
                     ),
@@ -425,6 +568,11 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
         }
     }
 
+    /// Whether CBMC was stopped because it reached the harness time limit.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.results, Err(ExitStatus::Timeout))
+    }
+
     /// Find the failed properties from this verification run
     pub fn failed_properties(&self) -> Vec<&Property> {
         if let Ok(properties) = &self.results {
@@ -437,25 +585,43 @@ pub fn failed_properties(&self) -> Vec<&Property> {
 }
 
 /// We decide if verification succeeded based on properties, not (typically) on exit code
+///
+/// Note that undetermined checks are not failed checks, so they never satisfy an expected failure.
 fn verification_outcome_from_properties(
     properties: &[Property],
-    should_panic: bool,
+    expected: ExpectedOutcome,
 ) -> (VerificationStatus, FailedProperties) {
     let failed_properties = determine_failed_properties(properties);
-    let status = if should_panic {
-        match failed_properties {
-            FailedProperties::None | FailedProperties::Other => VerificationStatus::Failure,
-            FailedProperties::PanicsOnly => VerificationStatus::Success,
-        }
-    } else {
-        match failed_properties {
+    let status = match expected {
+        ExpectedOutcome::Success => match failed_properties {
             FailedProperties::None => VerificationStatus::Success,
             FailedProperties::PanicsOnly | FailedProperties::Other => VerificationStatus::Failure,
-        }
+        },
+        ExpectedOutcome::Panic => match failed_properties {
+            FailedProperties::None | FailedProperties::Other => VerificationStatus::Failure,
+            FailedProperties::PanicsOnly => VerificationStatus::Success,
+        },
+        ExpectedOutcome::Failure => match failed_properties {
+            FailedProperties::None => VerificationStatus::Failure,
+            // Reaching an unsupported construct or failing a sanity check means the result
+            // cannot be trusted, so it does not count as an expected failure.
+            FailedProperties::Other if has_unsound_failures(properties) => {
+                VerificationStatus::Failure
+            }
+            FailedProperties::PanicsOnly | FailedProperties::Other => VerificationStatus::Success,
+        },
     };
     (status, failed_properties)
 }
 
+/// Whether any unsupported construct or sanity check failed.
+fn has_unsound_failures(properties: &[Property]) -> bool {
+    properties.iter().any(|prop| {
+        prop.status == CheckStatus::Failure
+            && matches!(prop.property_class().as_str(), "unsupported_construct" | "sanity_check")
+    })
+}
+
 /// Determines the `FailedProperties` variant that corresponds to an array of properties
 fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
     let failed_properties: Vec<&Property> =
@@ -464,10 +630,12 @@ fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
     if failed_properties.is_empty() {
         FailedProperties::None
     } else {
-        // Check if all failed properties correspond to the `assertion` class.
-        // Note: Panics caused by `panic!` and `assert!` fall into this class.
-        let all_failed_checks_are_panics =
-            failed_properties.iter().all(|prop| prop.property_class() == "assertion");
+        // Check if all failed properties correspond to the `assertion` or `contract` classes.
+        // Note: Panics caused by `panic!` and `assert!` fall into the `assertion` class, while
+        // contract violations fall into the `contract` class.
+        let all_failed_checks_are_panics = failed_properties
+            .iter()
+            .all(|prop| matches!(prop.property_class().as_str(), "assertion" | "contract"));
         if all_failed_checks_are_panics {
             FailedProperties::PanicsOnly
         } else {
@@ -476,6 +644,40 @@ fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
     }
 }
 
+/// The unsupported constructs of a harness that were reachable during verification.
+///
+/// The compiler records every unsupported construct in the code reachable from the harness, but
+/// the ones behind dead branches are harmless. A construct is only reachable if its
+/// `unsupported_construct` check failed.
+fn reachable_unsupported_features(
+    harness: &HarnessMetadata,
+    properties: &[Property],
+) -> Vec<String> {
+    let failed_locations: Vec<(&str, u64)> = properties
+        .iter()
+        .filter(|prop| {
+            prop.status == CheckStatus::Failure && prop.property_class() == "unsupported_construct"
+        })
+        .filter_map(|prop| {
+            let file = prop.source_location.file.as_deref()?;
+            let line = prop.source_location.line.as_ref()?.parse().ok()?;
+            Some((file, line))
+        })
+        .collect();
+    harness
+        .unsupported_features
+        .iter()
+        .filter(|feature| {
+            feature.locations.iter().any(|loc| {
+                failed_locations
+                    .iter()
+                    .any(|(file, line)| loc.start_line == *line && loc.filename.ends_with(file))
+            })
+        })
+        .map(|feature| feature.feature.clone())
+        .collect()
+}
+
 fn coverage_results_from_properties(properties: &[Property]) -> Option<CoverageResults> {
     let cov_properties: Vec<&Property> =
         properties.iter().filter(|p| p.is_code_coverage_property()).collect();
@@ -551,8 +753,10 @@ pub fn resolve_unwind_value(
 #[cfg(test)]
 mod tests {
     use crate::args;
+    use crate::cbmc_output_parser::{PropertyId, SourceLocation};
     use crate::metadata::tests::mock_proof_harness;
     use clap::Parser;
+    use kani_metadata::{Location, UnsupportedFeature};
 
     use super::*;
 
@@ -587,4 +791,129 @@ fn resolve(args: &[&str], harness: &HarnessMetadata) -> Option<u32> {
         assert_eq!(resolve(&args_only_harness, &harness_some), Some(1));
         assert_eq!(resolve(&args_both, &harness_some), Some(1));
     }
+
+    fn message(text: &str) -> ParserItem {
+        ParserItem::Message {
+            message_text: text.to_string(),
+            message_type: "STATUS-MESSAGE".to_string(),
+        }
+    }
+
+    #[test]
+    fn check_complexity_stats() {
+        let items = [
+            message("Starting Bounded Model Checking"),
+            message("size of program expression: 354 steps"),
+            message("Generated 12 VCC(s), 5 remaining after simplification"),
+            message("1024 variables, 4096 clauses"),
+            message("2048 variables, 8192 clauses"),
+            message("SAT checker: instance is SATISFIABLE"),
+        ];
+        let stats = ComplexityStats::from_items(&items);
+        assert_eq!(
+            stats,
+            ComplexityStats {
+                symex_steps: Some(354),
+                vccs: Some((12, 5)),
+                solver_size: Some((2048, 8192)),
+            }
+        );
+        assert_eq!(
+            stats.render(),
+            "Complexity (approximation, not a path count): 354 symex steps, 12 VCCs (5 after \
+            simplification), 2048 SAT variables, 8192 clauses"
+        );
+    }
+
+    fn property(class: &str, status: CheckStatus) -> Property {
+        Property {
+            description: String::new(),
+            property_id: PropertyId { fn_name: None, class: class.to_string(), id: 1 },
+            source_location: SourceLocation {
+                column: None,
+                file: None,
+                function: None,
+                line: None,
+            },
+            status,
+            reach: None,
+            contract_clause: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn check_should_panic_outcome() {
+        let outcome = |properties: &[Property]| {
+            verification_outcome_from_properties(properties, ExpectedOutcome::Panic).0
+        };
+        let panic = property("assertion", CheckStatus::Failure);
+        let no_panic = property("assertion", CheckStatus::Success);
+        let unsupported = property("unsupported_construct", CheckStatus::Failure);
+        let sanity = property("sanity_check", CheckStatus::Failure);
+        let contract = property("contract", CheckStatus::Failure);
+
+        assert_eq!(outcome(&[panic.clone(), no_panic.clone()]), VerificationStatus::Success);
+        assert_eq!(outcome(&[no_panic]), VerificationStatus::Failure);
+        // Contract violations are reported like panics.
+        assert_eq!(outcome(&[contract.clone(), panic.clone()]), VerificationStatus::Success);
+        assert_eq!(outcome(&[contract]), VerificationStatus::Success);
+        // Only panics satisfy `#[kani::should_panic]`.
+        assert_eq!(outcome(&[unsupported.clone()]), VerificationStatus::Failure);
+        assert_eq!(outcome(&[sanity.clone()]), VerificationStatus::Failure);
+        assert_eq!(outcome(&[panic.clone(), unsupported]), VerificationStatus::Failure);
+        assert_eq!(outcome(&[panic, sanity]), VerificationStatus::Failure);
+    }
+
+    #[test]
+    fn check_expect_failure_outcome() {
+        let outcome = |properties: &[Property]| {
+            verification_outcome_from_properties(properties, ExpectedOutcome::Failure).0
+        };
+        let panic = property("assertion", CheckStatus::Failure);
+        let no_panic = property("assertion", CheckStatus::Success);
+        let overflow = property("arithmetic_overflow", CheckStatus::Failure);
+        let unsupported = property("unsupported_construct", CheckStatus::Failure);
+        let sanity = property("sanity_check", CheckStatus::Failure);
+
+        assert_eq!(outcome(&[panic.clone(), no_panic.clone()]), VerificationStatus::Success);
+        assert_eq!(outcome(&[overflow.clone()]), VerificationStatus::Success);
+        assert_eq!(outcome(&[no_panic]), VerificationStatus::Failure);
+        // Unsupported constructs and sanity checks never count as expected failures.
+        assert_eq!(outcome(&[unsupported.clone()]), VerificationStatus::Failure);
+        assert_eq!(outcome(&[sanity.clone()]), VerificationStatus::Failure);
+        assert_eq!(outcome(&[panic, unsupported]), VerificationStatus::Failure);
+        assert_eq!(outcome(&[overflow, sanity]), VerificationStatus::Failure);
+    }
+
+    #[test]
+    fn check_reachable_unsupported_features() {
+        let feature = |name: &str, line: u64| UnsupportedFeature {
+            feature: name.to_string(),
+            locations: [Location { filename: "/home/src/main.rs".to_string(), start_line: line }]
+                .into_iter()
+                .collect(),
+        };
+        let check = |line: u64, status: CheckStatus| {
+            let mut prop = property("unsupported_construct", status);
+            prop.source_location.file = Some("src/main.rs".to_string());
+            prop.source_location.line = Some(line.to_string());
+            prop
+        };
+        let mut harness = mock_proof_harness("check_asm", None, None, None);
+        harness.unsupported_features = vec![feature("asm", 10), feature("dead_asm", 20)];
+
+        let properties = [check(10, CheckStatus::Failure), check(20, CheckStatus::Success)];
+        assert_eq!(reachable_unsupported_features(&harness, &properties), vec!["asm"]);
+        // Constructs whose checks succeeded are not reachable.
+        let properties = [check(10, CheckStatus::Success), check(20, CheckStatus::Success)];
+        assert!(reachable_unsupported_features(&harness, &properties).is_empty());
+    }
+
+    #[test]
+    fn check_complexity_stats_missing() {
+        let stats = ComplexityStats::from_items(&[message("VERIFICATION SUCCESSFUL")]);
+        assert_eq!(stats, ComplexityStats::default());
+        assert_eq!(stats.render(), "Complexity: not reported by CBMC");
+    }
 }
@@ -122,6 +122,9 @@ pub fn kani_compiler_flags(&self) -> Vec<String> {
         if self.args.restrict_vtable() {
             flags.push("--restrict-vtable-fn-ptrs".into());
         }
+        if self.args.function_pointer_restrictions {
+            flags.push("--restrict-fn-ptr-calls".into());
+        }
         if self.args.assertion_reach_checks() {
             flags.push("--assertion-reach-checks".into());
         }
@@ -152,6 +155,50 @@ pub fn kani_compiler_flags(&self) -> Vec<String> {
             flags.push("--print-llbc".into());
         }
 
+        if self.args.verbose_codegen_timing {
+            flags.push("--verbose-codegen-timing".into());
+        }
+
+        if self.args.print_symtab_stats {
+            flags.push("--print-symtab-stats".into());
+        }
+
+        if self.args.check_only {
+            flags.push("--check-only".into());
+        }
+
+        if self.args.verify_allocator {
+            flags.push("--verify-allocator".into());
+        }
+
+        if let Some(version) = self.args.goto_binary_version {
+            flags.push(format!("--goto-binary-version={version}"));
+        }
+
+        if let Some(harness) = &self.args.dump_reachable_functions {
+            flags.push(format!("--dump-reachable-functions={harness}"));
+        }
+
+        if self.args.print_call_graph {
+            flags.push("--print-call-graph".into());
+        }
+
+        if self.args.stub_unsupported {
+            flags.push("--stub-unsupported".into());
+        }
+
+        if let Some(bytes) = self.args.max_nondet_bytes {
+            flags.push(format!("--max-nondet-bytes={bytes}"));
+        }
+
+        if let Some(capacity) = self.args.vec_max_capacity {
+            flags.push(format!("--vec-max-capacity={capacity}"));
+        }
+
+        for stub in &self.args.stub_by_name {
+            flags.push(format!("--stub-by-name={stub}"));
+        }
+
         if self.args.no_assert_contracts {
             flags.push("--no-assert-contracts".into());
         }
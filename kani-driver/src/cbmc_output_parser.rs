@@ -25,6 +25,7 @@
 // anything from other modules of this crate, these should only be std + dependencies.
 use anyhow::Result;
 use console::style;
+use kani_metadata::ASSERTION_TAGS;
 use pathdiff::diff_paths;
 use rustc_demangle::demangle;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -72,8 +73,8 @@ struct ResultStruct {
 
 /// Struct that represents a single property in the set of CBMC results.
 ///
-/// Note: `reach` is not part of the parsed data, but it's useful to annotate
-/// its reachability status.
+/// Note: `reach` and `contract_clause` are not part of the parsed data, but it's useful to
+/// annotate its reachability status and the kind of contract clause it checks.
 #[derive(Clone, Debug, Deserialize)]
 pub struct Property {
     pub description: String,
@@ -83,6 +84,8 @@ pub struct Property {
     pub source_location: SourceLocation,
     pub status: CheckStatus,
     pub reach: Option<CheckStatus>,
+    #[serde(skip)]
+    pub contract_clause: Option<String>,
     pub trace: Option<Vec<TraceItem>>,
 }
 
@@ -97,6 +100,7 @@ pub struct PropertyId {
 impl Property {
     const COVER_PROPERTY_CLASS: &'static str = "cover";
     const COVERAGE_PROPERTY_CLASS: &'static str = "code_coverage";
+    const CONTRACT_PROPERTY_CLASS: &'static str = "contract";
 
     pub fn property_class(&self) -> String {
         self.property_id.class.clone()
@@ -112,6 +116,25 @@ pub fn is_cover_property(&self) -> bool {
         self.property_id.class == Self::COVER_PROPERTY_CLASS
     }
 
+    /// Returns true if this is a precondition or postcondition of a function contract
+    pub fn is_contract_property(&self) -> bool {
+        self.property_id.class == Self::CONTRACT_PROPERTY_CLASS
+    }
+
+    /// Returns the tag that should be reported for this property, if any: the kind of clause
+    /// of a contract check, or the tag of an assertion created with `kani::assert_tagged`.
+    pub fn tag(&self) -> Option<&str> {
+        self.contract_clause.as_deref().or_else(|| self.assertion_tag())
+    }
+
+    /// Returns the tag of an assertion created with `kani::assert_tagged`, if any.
+    ///
+    /// The tag is recorded as a `[<tag>] ` prefix of the property description.
+    pub fn assertion_tag(&self) -> Option<&str> {
+        let (tag, _) = self.description.strip_prefix('[')?.split_once("] ")?;
+        ASSERTION_TAGS.contains(&tag).then_some(tag)
+    }
+
     pub fn property_name(&self) -> String {
         let class = &self.property_id.class;
         let id = self.property_id.id;
@@ -607,11 +630,38 @@ fn check_property_id_deserialization_general() {
             },
             status: CheckStatus::Success,
             reach: None,
+            contract_clause: None,
             trace: None,
         };
         assert_eq!(dummy_prop.property_name(), prop_id_string[1..prop_id_string.len() - 1]);
     }
 
+    #[test]
+    fn check_assertion_tag() {
+        let property = |description: &str| Property {
+            description: description.to_string(),
+            property_id: PropertyId { fn_name: None, class: "assertion".to_string(), id: 1 },
+            source_location: SourceLocation {
+                function: None,
+                file: None,
+                column: None,
+                line: None,
+            },
+            status: CheckStatus::Failure,
+            reach: None,
+            contract_clause: None,
+            trace: None,
+        };
+        assert_eq!(
+            property("[precondition] x must be positive").assertion_tag(),
+            Some("precondition")
+        );
+        assert_eq!(property("[invariant] ").assertion_tag(), Some("invariant"));
+        assert_eq!(property("[other] x must be positive").assertion_tag(), None);
+        assert_eq!(property("x must be positive").assertion_tag(), None);
+        assert_eq!(property("[postcondition]").assertion_tag(), None);
+    }
+
     #[test]
     fn check_property_id_deserialization_only_name() {
         let prop_id_string = "\"alloc::raw_vec::RawVec::<u8>::allocate_in.1\"";
@@ -637,6 +687,7 @@ fn check_property_id_deserialization_only_name() {
             },
             status: CheckStatus::Success,
             reach: None,
+            contract_clause: None,
             trace: None,
         };
         assert_eq!(
@@ -666,6 +717,7 @@ fn check_property_id_deserialization_only_class() {
             },
             status: CheckStatus::Success,
             reach: None,
+            contract_clause: None,
             trace: None,
         };
         assert_eq!(dummy_prop.property_name(), prop_id_string[1..prop_id_string.len() - 1]);
@@ -692,6 +744,7 @@ fn check_property_id_deserialization_special() {
             },
             status: CheckStatus::Success,
             reach: None,
+            contract_clause: None,
             trace: None,
         };
         assert_eq!(dummy_prop.property_name(), "recursion.1");
@@ -721,6 +774,7 @@ fn check_property_id_deserialization_special_name() {
             },
             status: CheckStatus::Success,
             reach: None,
+            contract_clause: None,
             trace: None,
         };
         assert_eq!(
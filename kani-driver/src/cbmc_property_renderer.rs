@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::args::OutputFormat;
-use crate::call_cbmc::{FailedProperties, VerificationStatus};
+use crate::call_cbmc::{ExpectedOutcome, FailedProperties, VerificationStatus};
 use crate::cbmc_output_parser::{CheckStatus, ParserItem, Property, TraceItem};
 use crate::coverage::cov_results::CoverageResults;
 use console::style;
@@ -245,7 +245,7 @@ fn format_item_terse(_item: &ParserItem) -> Option<String> {
 pub fn format_result(
     properties: &Vec<Property>,
     status: VerificationStatus,
-    should_panic: bool,
+    expected: ExpectedOutcome,
     failed_properties: FailedProperties,
     show_checks: bool,
 ) -> String {
@@ -312,6 +312,10 @@ pub fn format_result(
             result_str.push_str(&status_msg);
             result_str.push_str(&description_msg);
 
+            if let Some(tag) = prop.tag() {
+                result_str.push_str(&format!("\t - Tag: {tag}\n"));
+            }
+
             if !location.is_missing() {
                 let location_msg = format!("\t - Location: {location}\n");
                 result_str.push_str(&location_msg);
@@ -388,18 +392,26 @@ pub fn format_result(
     } else {
         style("FAILED").red()
     };
-    let should_panic_info = if should_panic {
-        match failed_properties {
+    let expected_info = match expected {
+        ExpectedOutcome::Success => "",
+        ExpectedOutcome::Panic => match failed_properties {
             FailedProperties::None => " (encountered no panics, but at least one was expected)",
             FailedProperties::PanicsOnly => " (encountered one or more panics as expected)",
             FailedProperties::Other => {
                 " (encountered failures other than panics, which were unexpected)"
             }
-        }
-    } else {
-        ""
+        },
+        ExpectedOutcome::Failure => match failed_properties {
+            FailedProperties::None => " (encountered no failures, but at least one was expected)",
+            FailedProperties::Other if status == VerificationStatus::Failure => {
+                " (encountered unsupported constructs or failed sanity checks, which were unexpected)"
+            }
+            FailedProperties::PanicsOnly | FailedProperties::Other => {
+                " (encountered one or more failures as expected)"
+            }
+        },
     };
-    let overall_result = format!("\nVERIFICATION:- {verification_result}{should_panic_info}\n");
+    let overall_result = format!("\nVERIFICATION:- {verification_result}{expected_info}\n");
     result_str.push_str(&overall_result);
 
     // Ideally, we should generate two `ParserItem::Message` and push them
@@ -431,7 +443,7 @@ pub fn format_coverage(
     properties: &[Property],
     cov_results: &CoverageResults,
     status: VerificationStatus,
-    should_panic: bool,
+    expected: ExpectedOutcome,
     failed_properties: FailedProperties,
     show_checks: bool,
 ) -> String {
@@ -439,7 +451,7 @@ pub fn format_coverage(
         properties.iter().cloned().partition(|x| x.property_class() == "code_coverage");
 
     let verification_output =
-        format_result(&non_coverage_checks, status, should_panic, failed_properties, show_checks);
+        format_result(&non_coverage_checks, status, expected, failed_properties, show_checks);
     let cov_results_intro = "Source-based code coverage results:";
     let result = format!("{}\n{}\n\n{}", verification_output, cov_results_intro, cov_results);
 
@@ -528,6 +540,8 @@ pub fn postprocess_result(properties: Vec<Property>, extra_ptr_checks: bool) ->
         annotate_properties_with_reach_results(properties_without_sanity_checks, reach_checks);
     // Remove reachability check IDs from regular property descriptions
     let properties_without_ids = remove_check_ids_from_description(properties_annotated);
+    // Move the kind of contract clause out of the description of contract checks
+    let properties_without_ids = extract_contract_clauses(properties_without_ids);
 
     // Filter out extra pointer checks if needed
     let properties_filtered = if !extra_ptr_checks {
@@ -622,7 +636,9 @@ fn get_readable_description(property: &Property) -> String {
 ///     (e.g., a reachable unsupported construct), changes all `SUCCESS` results
 ///     to `UNDETERMINED`.
 ///  3. If there weren't such failures, it updates all results with a `SUCCESS`
-///     reachability check to `UNREACHABLE`.
+///     reachability check to `UNREACHABLE`. Properties without a reachability check
+///     (e.g., when running with `--no-assertion-reach-checks`) have an unknown
+///     reachability status, so their results are left unchanged.
 fn update_properties_with_reach_status(
     mut properties: Vec<Property>,
     has_fundamental_failures: bool,
@@ -707,6 +723,27 @@ fn remove_check_ids_from_description(mut properties: Vec<Property>) -> Vec<Prope
     properties
 }
 
+/// Contract checks record the kind of clause they check (`precondition` or `postcondition`) as
+/// a prefix of their description, e.g.:
+/// ```text
+/// [postcondition] |result| *result > 0
+/// ```
+/// This function moves the kind of clause to `contract_clause`, so the description only contains
+/// the clause itself. This must be done after the check IDs are removed, since they come first.
+fn extract_contract_clauses(mut properties: Vec<Property>) -> Vec<Property> {
+    for prop in properties.iter_mut().filter(|prop| prop.is_contract_property()) {
+        let Some((clause, description)) =
+            prop.description.strip_prefix('[').and_then(|desc| desc.split_once("] "))
+        else {
+            continue;
+        };
+        let (clause, description) = (clause.to_string(), description.to_string());
+        prop.contract_clause = Some(clause);
+        prop.description = description;
+    }
+    properties
+}
+
 /// Partitions `properties` into reachability checks (identified by the
 /// "reachability_check" property class) and non-reachability checks
 fn filter_reach_checks(properties: Vec<Property>) -> (Vec<Property>, Vec<Property>) {
@@ -737,6 +737,7 @@ fn check_concrete_vals_extractor_primitive() {
             },
             status: CheckStatus::Failure,
             reach: None,
+            contract_clause: None,
             source_location: SourceLocation {
                 column: None,
                 file: None,
@@ -782,6 +783,7 @@ fn check_concrete_vals_extractor_array() {
             },
             status: CheckStatus::Failure,
             reach: None,
+            contract_clause: None,
             source_location: SourceLocation {
                 column: None,
                 file: None,
@@ -148,7 +148,7 @@ fn process_output(
                 self.write_output_to_file(result, harness, thread_index);
             }
 
-            let output = result.render(&self.args.output_format, harness.attributes.should_panic);
+            let output = result.render(&self.args.output_format, (&harness.attributes).into());
             if rayon::current_num_threads() > 1 {
                 println!("Thread {thread_index}: {output}");
             } else {
@@ -174,8 +174,7 @@ fn write_output_to_file(
 
         std::fs::create_dir_all(prefix).unwrap();
         let mut file = File::create(&file_name).unwrap();
-        let mut file_output =
-            result.render(&OutputFormat::Regular, harness.attributes.should_panic);
+        let mut file_output = result.render(&OutputFormat::Regular, (&harness.attributes).into());
         if rayon::current_num_threads() > 1 {
             file_output = format!("Thread {thread_index}:\n{file_output}");
         }
@@ -268,7 +267,11 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
         println!("Manual Harness Summary:");
 
         for failure in failures.iter() {
-            println!("Verification failed for - {}", failure.harness.pretty_name);
+            if failure.result.is_timeout() {
+                println!("Verification timed out for - {}", failure.harness.pretty_name);
+            } else {
+                println!("Verification failed for - {}", failure.harness.pretty_name);
+            }
         }
 
         if total > 0 {
@@ -101,7 +101,11 @@ fn cargokani_main(input_args: Vec<OsString>) -> Result<()> {
     }
 
     let project = project::cargo_project(&mut session, false)?;
-    if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
+    if session.args.only_codegen || session.args.check_only {
+        Ok(())
+    } else {
+        verify_project(project, session)
+    }
 }
 
 /// The main function for the `kani` command.
@@ -137,7 +141,11 @@ fn standalone_main() -> Result<()> {
             (session, project)
         }
     };
-    if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
+    if session.args.only_codegen || session.args.check_only {
+        Ok(())
+    } else {
+        verify_project(project, session)
+    }
 }
 
 /// Run verification on the given project.
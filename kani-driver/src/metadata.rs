@@ -73,6 +73,10 @@ fn link_function_pointer_restrictions(
                 combined_possible_methods.get(&trait_def).unwrap_or(&vec![]).clone();
             output.insert(cbmc_call_site_name, possibilities);
         }
+        for call_site in crate_data.fn_ptr_call_sites {
+            let cbmc_call_site_name = format!("{}.{}", call_site.function_name, call_site.label);
+            output.insert(cbmc_call_site_name, call_site.possibilities);
+        }
     }
 
     let f = File::create(output_filename)?;
@@ -231,6 +235,7 @@ pub fn mock_proof_harness(
             contract: Default::default(),
             has_loop_contracts: false,
             is_automatically_generated: false,
+            unsupported_features: vec![],
         }
     }
 
@@ -95,6 +95,17 @@ fn try_new(
         // For each harness (test or proof) from each metadata, read the path for the goto
         // SymTabGoto file. Use that path to find all the other artifacts.
         let mut artifacts = vec![];
+        // No goto binary is generated in check-only mode, so there is nothing to link.
+        if session.args.check_only {
+            return Ok(Project {
+                outdir,
+                input,
+                metadata,
+                artifacts,
+                cargo_metadata,
+                failed_targets,
+            });
+        }
         for crate_metadata in &metadata {
             for harness_metadata in
                 crate_metadata.test_harnesses.iter().chain(crate_metadata.proof_harnesses.iter())
@@ -111,10 +122,12 @@ fn try_new(
 
                 // All other harness artifacts that may have been generated as part of the build.
                 artifacts.extend(
-                    [SymTab, TypeMap, VTableRestriction, PrettyNameMap].iter().filter_map(|typ| {
-                        let artifact = Artifact::try_from(&symtab_out, *typ).ok()?;
-                        Some(artifact)
-                    }),
+                    [SymTab, TypeMap, VTableRestriction, PrettyNameMap, CoverageMappings]
+                        .iter()
+                        .filter_map(|typ| {
+                            let artifact = Artifact::try_from(&symtab_out, *typ).ok()?;
+                            Some(artifact)
+                        }),
                 );
                 artifacts.push(symtab_out);
                 artifacts.push(goto);
@@ -1,7 +1,6 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::args::Timeout;
 use crate::args::VerificationArgs;
 use crate::args::common::Verbosity;
 use crate::util::render_command;
@@ -11,7 +10,7 @@
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::sync::Mutex;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use strum_macros::Display;
 use tokio::process::Command as TokioCommand;
 use tracing::level_filters::LevelFilter;
@@ -139,12 +138,12 @@ pub fn run_terminal(&self, cmd: Command) -> Result<()> {
 
     /// Call [run_terminal_timeout] with the verbosity configured by the user.
     /// The `bool` value indicates whether the command timed out
-    pub fn run_terminal_timeout(&self, cmd: TokioCommand) -> Result<bool> {
-        self.runtime.block_on(run_terminal_timeout(
-            &self.args.common_args,
-            cmd,
-            self.args.harness_timeout,
-        ))
+    pub fn run_terminal_timeout(
+        &self,
+        cmd: TokioCommand,
+        timeout: Option<Duration>,
+    ) -> Result<bool> {
+        self.runtime.block_on(run_terminal_timeout(&self.args.common_args, cmd, timeout))
     }
 
     /// Call [run_suppress] with the verbosity configured by the user.
@@ -206,7 +205,7 @@ pub fn run_terminal(verbosity: &impl Verbosity, mut cmd: Command) -> Result<()>
 async fn run_terminal_timeout(
     verbosity: &impl Verbosity,
     mut cmd: TokioCommand,
-    timeout: Option<Timeout>,
+    timeout: Option<Duration>,
 ) -> Result<bool> {
     if verbosity.quiet() {
         cmd.stdout(std::process::Stdio::null());
@@ -221,7 +220,7 @@ async fn run_terminal_timeout(
         || async {
             if let Some(timeout) = timeout {
                 let mut child = cmd.spawn().unwrap();
-                let res = tokio::time::timeout(timeout.into(), child.wait()).await;
+                let res = tokio::time::timeout(timeout, child.wait()).await;
                 if res.is_err() {
                     // Kill the process
                     child.kill().await.unwrap();
@@ -1,7 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::CbmcSolver;
+use crate::{CbmcSolver, UnsupportedFeature};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use strum_macros::Display;
@@ -41,6 +41,12 @@ pub struct HarnessMetadata {
     pub has_loop_contracts: bool,
     /// If the harness was automatically generated or manually written.
     pub is_automatically_generated: bool,
+    /// The unsupported constructs found in the code reachable from this harness.
+    ///
+    /// This is a static over-approximation: whether any of them is reachable in an actual
+    /// execution is only known after verification.
+    #[serde(default)]
+    pub unsupported_features: Vec<UnsupportedFeature>,
 }
 
 /// The attributes added by the user to control how a harness is executed.
@@ -50,10 +56,17 @@ pub struct HarnessAttributes {
     pub kind: HarnessKind,
     /// Whether the harness is expected to panic or not.
     pub should_panic: bool,
+    /// Whether the harness is expected to fail verification, i.e., `#[kani::expect(failure)]`.
+    #[serde(default)]
+    pub expect_failure: bool,
     /// Optional data to store solver.
     pub solver: Option<CbmcSolver>,
     /// Optional data to store unwind value.
     pub unwind_value: Option<u32>,
+    /// Optional time limit in seconds for verifying this harness, i.e.,
+    /// `#[kani::solver_timeout(secs = N)]`.
+    #[serde(default)]
+    pub solver_timeout: Option<u32>,
     /// The stubs used in this harness.
     pub stubs: Vec<Stub>,
     /// The name of the functions being stubbed by their contract.
@@ -79,8 +92,10 @@ pub fn new(kind: HarnessKind) -> HarnessAttributes {
         HarnessAttributes {
             kind,
             should_panic: false,
+            expect_failure: false,
             solver: None,
             unwind_value: None,
+            solver_timeout: None,
             stubs: vec![],
             verified_stubs: vec![],
         }
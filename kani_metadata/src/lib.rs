@@ -23,6 +23,11 @@
 
 pub use unstable::{EnabledUnstableFeatures, UnstableFeature};
 
+/// The categories that an assertion can be tagged with using `kani::assert_tagged!`.
+///
+/// The tag is recorded as a `[<tag>] ` prefix of the description of the assertion property.
+pub const ASSERTION_TAGS: [&str; 3] = ["precondition", "invariant", "postcondition"];
+
 /// The structure of `.kani-metadata.json` files, which are emitted for each crate
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KaniMetadata {
@@ -76,7 +81,7 @@ pub struct ContractedFunction {
     pub harnesses: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UnsupportedFeature {
     // We could replace this with an enum: https://github.com/model-checking/kani/issues/1765
     /// A string identifying the feature.
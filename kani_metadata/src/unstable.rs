@@ -106,6 +106,20 @@ pub enum UnstableFeature {
     RestrictVtable,
     /// Enable the autoharness subcommand.
     Autoharness,
+    /// Model panics inside `catch_unwind` as unwinding to the `catch_unwind` call.
+    CatchUnwind,
+    /// Allow harnesses to assume a bound on the number of iterations of a loop with
+    /// `#[kani::unwind_assume]`, instead of checking it.
+    AssumeUnwind,
+    /// Model volatile accesses as interacting with memory that may be modified outside of the
+    /// program, i.e., volatile reads return nondeterministic values and volatile writes havoc the
+    /// memory they write to.
+    VolatileNondet,
+    /// Inline calls to small `#[inline(always)]` functions before instrumentation.
+    InlineAlways,
+    /// Remove the assertions of `debug_assert!` and related macros, including the evaluation of
+    /// their conditions, to verify code as it would run in release mode.
+    SkipDebugAssertions,
 }
 
 impl UnstableFeature {
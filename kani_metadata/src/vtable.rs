@@ -47,6 +47,17 @@ pub struct PossibleMethodEntry {
     pub possibilities: Vec<InternedString>,
 }
 
+/// A call through a function pointer, and the set of functions the pointer may point to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FnPtrCallSite {
+    /// The (mangled symbol name of the) function this code is within
+    pub function_name: InternedString,
+    /// The unique label we applied to this function pointer call.
+    pub label: InternedString,
+    /// The (mangled symbol names of the) functions this function pointer may point to.
+    pub possibilities: Vec<InternedString>,
+}
+
 /// Represents the full set of vtable restrictions visible in this crate.
 /// Currently corresponds to a `*.restrictions.json` file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,4 +68,8 @@ pub struct VtableCtxResults {
     /// When all of these are aggregated together from all linked crates, these collectively represent
     /// the only function pointers that might exist in this vtable entry.
     pub possible_methods: Vec<PossibleMethodEntry>,
+    /// Each call through a function pointer that could be restricted, together with all its
+    /// possible targets. Unlike vtable calls, these are complete per harness.
+    #[serde(default)]
+    pub fn_ptr_call_sites: Vec<FnPtrCallSite>,
 }
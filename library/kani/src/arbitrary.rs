@@ -6,6 +6,11 @@
 
 use crate::Arbitrary;
 
+/// Allocate a box that contains an arbitrary value of type `T`.
+///
+/// Note that this cannot be used to generate recursive types directly, such as
+/// `struct Node { next: Option<Box<Node>> }`, since `Node::any()` would never terminate.
+/// For those, users should write a generator that takes a depth bound instead.
 impl<T> Arbitrary for std::boxed::Box<T>
 where
     T: Arbitrary,
@@ -15,11 +20,31 @@ fn any() -> Self {
     }
 }
 
-impl Arbitrary for std::time::Duration {
-    fn any() -> Self {
-        const NANOS_PER_SEC: u32 = 1_000_000_000;
-        let nanos = u32::any();
-        crate::assume(nanos < NANOS_PER_SEC);
-        std::time::Duration::new(u64::any(), nanos)
-    }
+/// Allocate an arbitrary value of type `T` and return its address as an opaque `*mut c_void`.
+///
+/// This is useful to model C APIs that pass user data around as `void*`, such as callbacks.
+/// The allocation is never freed, and the pointer keeps its provenance, so casting it back to
+/// `*mut T` and dereferencing it is sound.
+///
+/// Casting the pointer to a different type is not detected by itself. However, dereferencing
+/// it as a type that is larger than `T` fails the pointer bounds check of the dereference.
+///
+/// ```no_run
+/// # use std::ffi::c_void;
+/// extern "C" fn callback(data: *mut c_void) -> u32 {
+///     unsafe { *(data as *mut u32) }
+/// }
+///
+/// #[kani::proof]
+/// fn check_callback() {
+///     let data = kani::any_opaque_ptr::<u32>();
+///     let expected = unsafe { *(data as *mut u32) };
+///     assert_eq!(callback(data), expected);
+/// }
+/// ```
+pub fn any_opaque_ptr<T>() -> *mut std::ffi::c_void
+where
+    T: Arbitrary,
+{
+    Box::into_raw(Box::new(T::any())).cast()
 }
@@ -40,6 +40,31 @@ pub fn concrete_playback_run<F: Fn()>(mut local_concrete_vals: Vec<Vec<u8>>, pro
     });
 }
 
+/// Play back a list of proof harnesses, each one with its own concrete values.
+///
+/// The name of each harness is printed before it runs, so any assertion failure can be traced
+/// back to the harness that triggered it. A failing harness does not stop the remaining ones
+/// from running; all failures are reported together at the end.
+#[allow(clippy::type_complexity)]
+pub fn concrete_playback_run_all(harnesses: &[(&str, Vec<Vec<u8>>, fn())]) {
+    let mut failures = Vec::new();
+    for (name, concrete_vals, proof_harness) in harnesses {
+        println!("Playing back harness `{name}`");
+        let result = std::panic::catch_unwind(|| {
+            concrete_playback_run(concrete_vals.clone(), proof_harness);
+        });
+        if result.is_err() {
+            failures.push(*name);
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Concrete playback failed for {} harness(es): {}",
+        failures.len(),
+        failures.join(", ")
+    );
+}
+
 /// Iterate over `any_raw_internal` since CBMC produces assignment per element.
 pub(crate) unsafe fn any_raw_array<T: Copy, const N: usize>() -> [T; N] {
     [(); N].map(|_| crate::any_raw_internal::<T>())
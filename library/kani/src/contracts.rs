@@ -275,4 +275,12 @@
 //! Here, the value stored in `a` is precomputed and remembered after the function
 //! is called, even though the contents of `a` changed during the function execution.
 //!
+//! The computation may call other functions. For instance, `old((*a).min(*b))` remembers
+//! the smaller of the two values pointed to by `a` and `b` when the function is called.
+//! The result is remembered as is, so it should be a value rather than a reference to the
+//! arguments, e.g. `old(core::cmp::max(*a, *b))` instead of `old(core::cmp::max(&*a, &*b))`.
+//! Kani does not check that the computation is effect free, which includes the comparison
+//! implemented by a user-defined `Ord` for `min` and `max`. Its side effects, e.g. a panic,
+//! happen when the function is called, and no diagnostic is emitted for them.
+//!
 pub use super::{ensures, modifies, proof_for_contract, requires, stub_verified};
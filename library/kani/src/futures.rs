@@ -94,11 +94,32 @@ pub trait SchedulingStrategy {
 #[derive(Default)]
 pub struct RoundRobin {
     index: usize,
+    /// The number of tasks picked so far.
+    steps: usize,
+    /// The maximum number of tasks that can be picked, if any.
+    max_steps: Option<usize>,
+}
+
+impl RoundRobin {
+    /// Creates a round robin strategy that only explores executions that pick at most
+    /// `max_steps` tasks to poll.
+    ///
+    /// Executions that need more scheduling decisions are discarded with `kani::assume`, so they
+    /// do not cause any failure. This is unsound if the tasks do not complete within the bound,
+    /// but it keeps verification tractable and lets you use an unwinding bound of
+    /// `max_steps + 1` for the scheduler loop.
+    pub fn with_max_steps(max_steps: usize) -> RoundRobin {
+        RoundRobin { index: 0, steps: 0, max_steps: Some(max_steps) }
+    }
 }
 
 impl SchedulingStrategy for RoundRobin {
     #[inline]
     fn pick_task(&mut self, num_tasks: usize) -> (usize, SchedulingAssumption) {
+        if let Some(max_steps) = self.max_steps {
+            crate::assume(self.steps < max_steps);
+            self.steps += 1;
+        }
         self.index = (self.index + 1) % num_tasks;
         (self.index, SchedulingAssumption::CannotAssumeRunning)
     }
@@ -107,37 +128,67 @@ fn pick_task(&mut self, num_tasks: usize) -> (usize, SchedulingAssumption) {
 pub(crate) struct Scheduler {
     tasks: Vec<Option<BoxFuture>>,
     num_running: usize,
+    /// Whether the task being polled is waiting on a [`JoinHandle`] of a task that is running.
+    waiting_on_task: bool,
+    /// Whether the task being polled gave control back to the scheduler with [`yield_now`].
+    yielded: bool,
+    /// Which tasks were only waiting on other tasks the last time they were polled.
+    ///
+    /// Entries are reset whenever a task makes progress, i.e., it completes or yields.
+    blocked: Vec<bool>,
 }
 
 impl Scheduler {
     /// Creates a scheduler with an empty task list
     #[inline]
     pub(crate) const fn new() -> Scheduler {
-        Scheduler { tasks: Vec::new(), num_running: 0 }
+        Scheduler {
+            tasks: Vec::new(),
+            num_running: 0,
+            waiting_on_task: false,
+            yielded: false,
+            blocked: Vec::new(),
+        }
     }
 
     /// Adds a future to the scheduler's task list, returning a JoinHandle
     pub(crate) fn spawn<F: Future<Output = ()> + Sync + 'static>(&mut self, fut: F) -> JoinHandle {
         let index = self.tasks.len();
         self.tasks.push(Some(Box::pin(fut)));
+        self.blocked.push(false);
         self.num_running += 1;
         JoinHandle { index }
     }
 
     /// Runs the scheduler with the given scheduling plan until all tasks have completed
+    ///
+    /// If every running task is waiting on another running task, none of them can ever complete.
+    /// This deadlock is reported as an assertion failure instead of spinning until the unwinding
+    /// bound is reached. Waiting on a task that still yields or eventually completes is not a
+    /// deadlock, and neither are tasks that keep yielding forever.
     fn run(&mut self, mut scheduling_plan: impl SchedulingStrategy) {
         let waker = unsafe { Waker::from_raw(NOOP_RAW_WAKER) };
         let cx = &mut Context::from_waker(&waker);
         while self.num_running > 0 {
             let (index, assumption) = scheduling_plan.pick_task(self.tasks.len());
+            self.waiting_on_task = false;
+            self.yielded = false;
             let task = &mut self.tasks[index];
             if let Some(fut) = task.as_mut() {
                 match fut.as_mut().poll(cx) {
                     std::task::Poll::Ready(()) => {
                         self.num_running -= 1;
                         let _prev = task.take();
+                        self.blocked.fill(false);
+                    }
+                    std::task::Poll::Pending if self.waiting_on_task && !self.yielded => {
+                        self.blocked[index] = true;
+                        if self.is_deadlocked() {
+                            crate::assert(false, "async deadlock");
+                            return;
+                        }
                     }
-                    std::task::Poll::Pending => (),
+                    std::task::Poll::Pending => self.blocked.fill(false),
                 }
             } else if let SchedulingAssumption::CanAssumeRunning = assumption {
                 crate::assume(false); // useful so that we can assume that a nondeterministically picked task is still running
@@ -145,6 +196,11 @@ fn run(&mut self, mut scheduling_plan: impl SchedulingStrategy) {
         }
     }
 
+    /// Whether all running tasks are blocked waiting on each other.
+    fn is_deadlocked(&self) -> bool {
+        self.tasks.iter().zip(&self.blocked).all(|(task, blocked)| task.is_none() || *blocked)
+    }
+
     /// Polls the given future and the tasks it may spawn until all of them complete
     fn block_on<F: Future<Output = ()> + Sync + 'static>(
         &mut self,
@@ -168,7 +224,9 @@ impl Future for JoinHandle {
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
-        if unsafe { GLOBAL_EXECUTOR.as_mut().unwrap().tasks[self.index].is_some() } {
+        let executor = unsafe { GLOBAL_EXECUTOR.as_mut().unwrap() };
+        if executor.tasks[self.index].is_some() {
+            executor.waiting_on_task = true;
             std::task::Poll::Pending
         } else {
             cx.waker().wake_by_ref(); // For completeness. But Kani currently ignores wakers.
@@ -222,12 +280,17 @@ struct YieldNow {
     impl Future for YieldNow {
         type Output = ();
 
+        #[allow(static_mut_refs)]
         fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
             if self.yielded {
                 cx.waker().wake_by_ref(); // For completeness. But Kani currently ignores wakers.
                 std::task::Poll::Ready(())
             } else {
                 self.yielded = true;
+                // Let the scheduler know this task is making progress, even though it is pending.
+                if let Some(executor) = unsafe { GLOBAL_EXECUTOR.as_mut() } {
+                    executor.yielded = true;
+                }
                 std::task::Poll::Pending
             }
         }
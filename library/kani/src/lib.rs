@@ -35,8 +35,9 @@
 
 mod models;
 
+pub use arbitrary::any_opaque_ptr;
 #[cfg(feature = "concrete_playback")]
-pub use concrete_playback::concrete_playback_run;
+pub use concrete_playback::{concrete_playback_run, concrete_playback_run_all};
 pub use invariant::Invariant;
 
 #[cfg(not(feature = "concrete_playback"))]
@@ -45,6 +46,13 @@ pub fn concrete_playback_run<F: Fn()>(_: Vec<Vec<u8>>, _: F) {
     unreachable!("Concrete playback does not work during verification")
 }
 
+#[cfg(not(feature = "concrete_playback"))]
+/// NOP `concrete_playback_run_all` for type checking during verification mode.
+#[allow(clippy::type_complexity)]
+pub fn concrete_playback_run_all(_: &[(&str, Vec<Vec<u8>>, fn())]) {
+    unreachable!("Concrete playback does not work during verification")
+}
+
 pub use futures::{RoundRobin, block_on, block_on_with_spawn, spawn, yield_now};
 
 // Kani proc macros must be in a separate crate
@@ -73,6 +81,36 @@ macro_rules! cover {
     };
 }
 
+/// `assert_tagged!(cond, tag, msg)` creates an assertion tagged with a failure category, so
+/// failures can be filtered by category. See [kani::assert_tagged].
+///
+/// The tag must be one of the string literals `"precondition"`, `"invariant"` or
+/// `"postcondition"`. Any other tag is a compilation error.
+///
+/// ```no_run
+/// let len: usize = kani::any();
+/// kani::assert_tagged!(len < 10, "invariant", "the length is bounded");
+/// ```
+#[macro_export]
+macro_rules! assert_tagged {
+    ($cond:expr, "precondition", $msg:literal $(,)?) => {
+        kani::assert_tagged($cond, "precondition", $msg)
+    };
+    ($cond:expr, "invariant", $msg:literal $(,)?) => {
+        kani::assert_tagged($cond, "invariant", $msg)
+    };
+    ($cond:expr, "postcondition", $msg:literal $(,)?) => {
+        kani::assert_tagged($cond, "postcondition", $msg)
+    };
+    ($cond:expr, $tag:literal, $msg:literal $(,)?) => {
+        compile_error!(concat!(
+            "invalid assertion tag `",
+            $tag,
+            "`, expected one of `precondition`, `invariant` or `postcondition`"
+        ))
+    };
+}
+
 /// `implies!(premise => conclusion)` means that if the `premise` is true, so
 /// must be the `conclusion`.
 ///
@@ -85,6 +123,170 @@ macro_rules! implies {
     };
 }
 
+/// `assume_chain!(a && b && c)` assumes each conjunct in order, i.e., it expands to
+/// `kani::assume(a); kani::assume(b); kani::assume(c);`.
+///
+/// This is useful when a later conjunct should only be evaluated under the assumption that the
+/// previous ones hold, e.g., `assume_chain!(idx < v.len() && v[idx] > 0)`.
+///
+/// Only top-level `&&` get this progressive treatment. An assumption cannot be split over a
+/// disjunction, so if the condition contains a top-level `||`, the whole condition is assumed
+/// at once, exactly like `kani::assume(cond)`.
+#[macro_export]
+macro_rules! assume_chain {
+    // Scan for a top-level `||`. If there's one, assume the entire condition.
+    (@scan [$($all:tt)*]) => {
+        kani::assume_chain!(@split [] $($all)*)
+    };
+    (@scan [$($all:tt)*] || $($rest:tt)*) => {
+        kani::assume($($all)*)
+    };
+    (@scan [$($all:tt)*] $next:tt $($rest:tt)*) => {
+        kani::assume_chain!(@scan [$($all)*] $($rest)*)
+    };
+    // Accumulate tokens of the current conjunct until we find a top-level `&&`.
+    (@split [$($cur:tt)+]) => {
+        kani::assume($($cur)+)
+    };
+    (@split [$($cur:tt)+] && $($rest:tt)+) => {
+        kani::assume($($cur)+);
+        kani::assume_chain!(@split [] $($rest)+)
+    };
+    (@split [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        kani::assume_chain!(@split [$($cur)* $next] $($rest)*)
+    };
+    ($($cond:tt)+) => {
+        kani::assume_chain!(@scan [$($cond)+] $($cond)+)
+    };
+}
+
+/// `assert_float_eq!(a, b, eps)` asserts that the floats `a` and `b` differ by at most `eps`,
+/// i.e., that `(a - b).abs() <= eps`.
+///
+/// The assertion fails if either `a` or `b` is NaN, since NaN isn't close to anything.
+/// Infinities of the same sign are considered equal, even though their difference is NaN.
+#[macro_export]
+macro_rules! assert_float_eq {
+    ($a:expr, $b:expr, $eps:expr $(,)?) => {{
+        let (a, b, eps) = ($a, $b, $eps);
+        kani::assert(
+            !a.is_nan() && !b.is_nan() && (a == b || (a - b).abs() <= eps),
+            concat!(
+                "assertion failed: ",
+                stringify!($a),
+                " is within ",
+                stringify!($eps),
+                " of ",
+                stringify!($b)
+            ),
+        );
+    }};
+}
+
+/// `kani::assert_eq!(left, right)` asserts that `left == right`, like `assert_eq!`.
+///
+/// On failure, the message names both operand expressions.
+/// Unlike `assert_eq!`, the operands don't need to implement `Debug`, and their values are never
+/// formatted into the message, so it stays short even for large types.
+///
+/// A custom message can be passed after the operands, like `assert_eq!(left, right, "{}", msg)`,
+/// in which case it replaces the message naming the operands.
+#[macro_export]
+macro_rules! assert_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => kani::assert(
+                *left_val == *right_val,
+                concat!(
+                    "assertion `left == right` failed (left: `",
+                    stringify!($left),
+                    "`, right: `",
+                    stringify!($right),
+                    "`)"
+                ),
+            ),
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                kani::assert(*left_val == *right_val, concat!(stringify!($($arg)+)));
+                // Type check the message arguments without evaluating them, like the `assert!`
+                // override does.
+                if false {
+                    kani::__kani__workaround_core_assert!(true, $($arg)+);
+                }
+            }
+        }
+    };
+}
+
+/// `kani::assert_ne!(left, right)` asserts that `left != right`, like `assert_ne!`.
+///
+/// See [`assert_eq!`] for how the operands are reported on failure.
+#[macro_export]
+macro_rules! assert_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => kani::assert(
+                *left_val != *right_val,
+                concat!(
+                    "assertion `left != right` failed (left: `",
+                    stringify!($left),
+                    "`, right: `",
+                    stringify!($right),
+                    "`)"
+                ),
+            ),
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                kani::assert(*left_val != *right_val, concat!(stringify!($($arg)+)));
+                // Type check the message arguments without evaluating them, like the `assert!`
+                // override does.
+                if false {
+                    kani::__kani__workaround_core_assert!(true, $($arg)+);
+                }
+            }
+        }
+    };
+}
+
+/// `any_fallible_fn!()` creates a closure that returns a nondeterministic `Result` every time it
+/// is called, i.e., either `Ok(kani::any())` or `Err(kani::any())`.
+///
+/// This is useful to stub fallible operations and to exercise error-handling paths. Each call
+/// makes an independent choice, so a retry loop may observe any sequence of failures followed by
+/// a success. Note that a loop that retries until success is not bounded, so the harness must
+/// set an adequate unwind bound (e.g. `#[kani::unwind(N)]`) for verification to terminate.
+///
+/// The `Ok` and `Err` types are inferred from the context, but they can also be provided
+/// explicitly with `any_fallible_fn!(T, E)`.
+///
+/// ```no_run
+/// fn retry<T, E>(mut op: impl FnMut() -> Result<T, E>, attempts: usize) -> Option<T> {
+///     (0..attempts).find_map(|_| op().ok())
+/// }
+///
+/// #[kani::proof]
+/// #[kani::unwind(4)]
+/// fn check_retry() {
+///     let res: Option<u8> = retry::<u8, ()>(kani::any_fallible_fn!(), 3);
+///     kani::cover!(res.is_none());
+/// }
+/// ```
+#[macro_export]
+macro_rules! any_fallible_fn {
+    () => {
+        || if kani::any() { Ok(kani::any()) } else { Err(kani::any()) }
+    };
+    ($ok:ty, $err:ty $(,)?) => {
+        || -> Result<$ok, $err> { if kani::any() { Ok(kani::any()) } else { Err(kani::any()) } }
+    };
+}
+
 pub(crate) use kani_macros::unstable_feature as unstable;
 
 pub mod contracts;
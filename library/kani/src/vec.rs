@@ -3,6 +3,24 @@
 use crate::{Arbitrary, any, any_where};
 
 /// Generates an arbitrary vector whose length is at most MAX_LENGTH.
+///
+/// Every length in `0..=MAX_LENGTH` is possible, including `0`, and the capacity of the vector is
+/// always equal to its length. The elements are unconstrained values generated by `kani::any()`.
+///
+/// The vector is built from an array of `MAX_LENGTH` elements instead of pushing elements one at
+/// a time. For element types that can be represented by an unconstrained symbolic value (e.g.,
+/// integers), the whole array is generated at once, so no loop needs to be unwound to create
+/// the vector. Any other element type is generated one element at a time by
+/// `core::array::from_fn`, which requires an unwind bound greater than `MAX_LENGTH`. So does
+/// truncating the array if `T` needs to be dropped.
+///
+/// # Example:
+///
+/// ```no_run
+/// let v = kani::vec::any_vec::<u8, 4>();
+/// assert!(v.len() <= 4);
+/// assert_eq!(v.capacity(), v.len());
+/// ```
 pub fn any_vec<T, const MAX_LENGTH: usize>() -> Vec<T>
 where
     T: Arbitrary,
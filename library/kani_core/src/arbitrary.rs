@@ -25,8 +25,12 @@ pub trait Arbitrary
             Self: Sized,
         {
             fn any() -> Self;
+            /// Generates an array where each element is generated by [Arbitrary::any].
+            ///
+            /// Types that can be represented by an unconstrained symbolic value override this
+            /// to generate the whole array at once.
             fn any_array<const MAX_ARRAY_LENGTH: usize>() -> [Self; MAX_ARRAY_LENGTH] {
-                [(); MAX_ARRAY_LENGTH].map(|_| Self::any())
+                core_path::array::from_fn(|_| Self::any())
             }
         }
 
@@ -100,6 +104,58 @@ fn any() -> Self {
         nonzero_arbitrary!(NonZeroI128, i128);
         nonzero_arbitrary!(NonZeroIsize, isize);
 
+        /// Integer types that can be generated within an inclusive range by [nondet_range].
+        pub trait NondetRange: Arbitrary + PartialOrd + Copy {
+            const MIN: Self;
+            const MAX: Self;
+        }
+
+        macro_rules! nondet_range_impl {
+            ( $type: ty ) => {
+                impl NondetRange for $type {
+                    const MIN: Self = <$type>::MIN;
+                    const MAX: Self = <$type>::MAX;
+                }
+            };
+        }
+
+        nondet_range_impl!(u8);
+        nondet_range_impl!(u16);
+        nondet_range_impl!(u32);
+        nondet_range_impl!(u64);
+        nondet_range_impl!(u128);
+        nondet_range_impl!(usize);
+
+        nondet_range_impl!(i8);
+        nondet_range_impl!(i16);
+        nondet_range_impl!(i32);
+        nondet_range_impl!(i64);
+        nondet_range_impl!(i128);
+        nondet_range_impl!(isize);
+
+        /// Generate an arbitrary integer in the inclusive range `[lo, hi]`.
+        ///
+        /// This is equivalent to calling `kani::any()` followed by
+        /// `kani::assume(lo <= x && x <= hi)`, except that no assumption is added when the range
+        /// covers every value of `T`. Kani checks that the range is not empty, i.e., that
+        /// `lo <= hi`, since an empty range would make the rest of the harness vacuous.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let x: u8 = kani::nondet_range(1, 10);
+        /// assert!(x >= 1 && x <= 10);
+        /// ```
+        #[inline(always)]
+        pub fn nondet_range<T: NondetRange>(lo: T, hi: T) -> T {
+            crate::kani::assert(lo <= hi, "`nondet_range` requires `lo <= hi`");
+            let value = T::any();
+            if lo != T::MIN || hi != T::MAX {
+                assume(lo <= value && value <= hi);
+            }
+            value
+        }
+
         // Implement arbitrary for non-trivial types
         impl Arbitrary for bool {
             #[inline(always)]
@@ -172,6 +228,33 @@ fn any() -> Self {
             }
         }
 
+        /// Kani models atomics sequentially, so the ordering has no effect on the verification
+        /// result. This is still useful to exercise code that branches on the ordering.
+        impl Arbitrary for core_path::sync::atomic::Ordering {
+            fn any() -> Self {
+                use core_path::sync::atomic::Ordering;
+                match u8::any() {
+                    0 => Ordering::Relaxed,
+                    1 => Ordering::Acquire,
+                    2 => Ordering::Release,
+                    3 => Ordering::AcqRel,
+                    _ => Ordering::SeqCst,
+                }
+            }
+        }
+
+        /// The nanoseconds are constrained to be less than one second instead of being normalized
+        /// by `Duration::new`. This way, every `Duration` has exactly one representation, the
+        /// seconds are never incremented by a carry, and `Duration::new` cannot panic.
+        impl Arbitrary for core_path::time::Duration {
+            fn any() -> Self {
+                const NANOS_PER_SEC: u32 = 1_000_000_000;
+                let nanos = u32::any();
+                assume(nanos < NANOS_PER_SEC);
+                core_path::time::Duration::new(u64::any(), nanos)
+            }
+        }
+
         arbitrary_tuple!(A);
         arbitrary_tuple!(A, B);
         arbitrary_tuple!(A, B, C);
@@ -216,6 +299,8 @@ fn any() -> Self {
                 }
             }
 
+            /// The endpoints are unconstrained, so the range may be empty, i.e., `start >= end`.
+            /// Use `kani::assume` or `kani::any_where` to restrict them if needed.
             impl<T> Arbitrary for Range<T>
             where
                 T: Arbitrary,
@@ -234,6 +319,12 @@ fn any() -> Self {
                 }
             }
 
+            /// The endpoints are unconstrained, so the range may be empty, i.e., `start > end`.
+            ///
+            /// The generated range is never in the exhausted state that `RangeInclusive` reaches
+            /// after yielding its last element, since that state can only be reached by
+            /// iterating over the range. An empty range is already covered by `start > end`, and
+            /// `is_empty()` reports it in both cases.
             impl<T> Arbitrary for RangeInclusive<T>
             where
                 T: Arbitrary,
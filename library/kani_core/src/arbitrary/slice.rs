@@ -32,6 +32,115 @@ macro_rules! slice_generator {
             &mut arr[from..to]
         }
 
+        /// An array of `MAX_LENGTH` arbitrary elements of which only the first `len()` are in
+        /// use, for some arbitrary `len() <= MAX_LENGTH`.
+        ///
+        /// This is a bounded slice with an arbitrary length that is stored inline instead of on
+        /// the heap. The array that backs the slice is owned by this object, so it stays alive
+        /// for as long as the slice is borrowed.
+        pub struct BoundedSlice<T, const MAX_LENGTH: usize> {
+            array: [T; MAX_LENGTH],
+            len: usize,
+        }
+
+        impl<T, const MAX_LENGTH: usize> BoundedSlice<T, MAX_LENGTH> {
+            /// The number of elements in the slice.
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Whether the slice is empty.
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// A view of the first `len()` elements of the backing array.
+            pub fn as_slice(&self) -> &[T] {
+                // SAFETY: `len <= MAX_LENGTH` and the array is borrowed for the lifetime of the
+                // slice. For an empty slice, the array pointer is still non-null and aligned.
+                unsafe { super::core_path::slice::from_raw_parts(self.array.as_ptr(), self.len) }
+            }
+
+            /// A mutable view of the first `len()` elements of the backing array.
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                // SAFETY: Same as `as_slice`.
+                unsafe {
+                    super::core_path::slice::from_raw_parts_mut(self.array.as_mut_ptr(), self.len)
+                }
+            }
+
+            /// Consume this object and return the backing array and the length of the slice.
+            pub fn into_parts(self) -> ([T; MAX_LENGTH], usize) {
+                (self.array, self.len)
+            }
+        }
+
+        impl<T, const MAX_LENGTH: usize> super::core_path::ops::Deref
+            for BoundedSlice<T, MAX_LENGTH>
+        {
+            type Target = [T];
+
+            fn deref(&self) -> &[T] {
+                self.as_slice()
+            }
+        }
+
+        impl<T, const MAX_LENGTH: usize> super::core_path::ops::DerefMut
+            for BoundedSlice<T, MAX_LENGTH>
+        {
+            fn deref_mut(&mut self) -> &mut [T] {
+                self.as_mut_slice()
+            }
+        }
+
+        /// Generate a slice of at most `MAX_LENGTH` arbitrary elements without allocating on
+        /// the heap. The elements are stored in an array that is owned by the returned object.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let slice = kani::slice::any_bounded_slice::<u8, 4>();
+        /// assert!(slice.len() <= 4);
+        /// for elem in slice.iter() {
+        ///     let _ = *elem;
+        /// }
+        /// ```
+        pub fn any_bounded_slice<T, const MAX_LENGTH: usize>() -> BoundedSlice<T, MAX_LENGTH>
+        where
+            T: kani::Arbitrary,
+        {
+            let (len, array) = any_bounded_array();
+            BoundedSlice { array, len }
+        }
+
+        /// Generate an array of `MAX_LENGTH` arbitrary elements together with an arbitrary
+        /// length `len <= MAX_LENGTH`.
+        ///
+        /// Only the first `len` elements are meant to be used. The remaining elements are still
+        /// initialized with arbitrary values, so reading them is not undefined behavior, but
+        /// nothing should be inferred from them. The array is not shared with anything else, so
+        /// writing to it has no other effect.
+        ///
+        /// Use [any_bounded_slice] to get a slice that only exposes the first `len` elements.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let (len, array) = kani::slice::any_bounded_array::<u8, 4>();
+        /// assert!(len <= 4);
+        /// for elem in &array[..len] {
+        ///     let _ = *elem;
+        /// }
+        /// ```
+        pub fn any_bounded_array<T, const MAX_LENGTH: usize>() -> (usize, [T; MAX_LENGTH])
+        where
+            T: kani::Arbitrary,
+        {
+            let len: usize = kani::any();
+            kani::assume(len <= MAX_LENGTH);
+            (len, kani::any())
+        }
+
         fn any_range<const LENGTH: usize>() -> (usize, usize) {
             let from: usize = kani::any();
             let to: usize = kani::any();
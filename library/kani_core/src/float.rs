@@ -9,6 +9,7 @@ macro_rules! generate_float {
     ($core:path) => {
         use super::kani_intrinsic;
         use core::convert::FloatToInt;
+        use core::num::FpCategory;
         /// Returns whether the given float `value` satisfies the range
         /// condition of the `to_int_unchecked` methods, namely that the `value`
         /// after truncation is in range of the target `Int`
@@ -39,5 +40,112 @@ pub fn float_to_int_in_range<Float, Int>(value: Float) -> bool
         {
             kani_intrinsic()
         }
+
+        /// Returns a nondeterministic `f64` that belongs to the given `category`.
+        ///
+        /// This is useful to make sure a harness covers interesting values, such as subnormals
+        /// (non-zero values with magnitude smaller than `f64::MIN_POSITIVE`), which are easy to
+        /// miss when picking values by hand. Any NaN bit pattern may be returned for
+        /// `FpCategory::Nan`, and both signs are allowed for every category.
+        ///
+        /// Use `kani::any()` for a float that is not restricted to any category.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// use std::num::FpCategory;
+        /// let f = kani::float::any_of_category(FpCategory::Subnormal);
+        /// assert!(f != 0.0 && f.abs() < f64::MIN_POSITIVE);
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "float-lib",
+            issue = "none",
+            reason = "experimental floating-point API"
+        )]
+        pub fn any_of_category(category: FpCategory) -> f64 {
+            let value: f64 = super::any();
+            super::assume(value.classify() == category);
+            value
+        }
+
+        /// Floating-point types supported by the nondeterministic float generators below.
+        pub trait Float: super::Arbitrary + Copy + PartialEq {
+            /// Positive zero.
+            const ZERO: Self;
+            /// Whether the value is neither infinite nor NaN.
+            fn is_finite(self) -> bool;
+            /// Whether the value is neither zero, infinite, subnormal, nor NaN.
+            fn is_normal(self) -> bool;
+        }
+
+        impl Float for f32 {
+            const ZERO: Self = 0.0;
+            fn is_finite(self) -> bool {
+                f32::is_finite(self)
+            }
+            fn is_normal(self) -> bool {
+                f32::is_normal(self)
+            }
+        }
+
+        impl Float for f64 {
+            const ZERO: Self = 0.0;
+            fn is_finite(self) -> bool {
+                f64::is_finite(self)
+            }
+            fn is_normal(self) -> bool {
+                f64::is_normal(self)
+            }
+        }
+
+        /// Returns a nondeterministic float of any value, including NaN and both infinities.
+        ///
+        /// This is the same as `kani::any()`, and it is provided for symmetry with
+        /// [`any_finite_float`] and [`any_normal_float`].
+        #[crate::kani::unstable_feature(
+            feature = "float-lib",
+            issue = "none",
+            reason = "experimental floating-point API"
+        )]
+        pub fn any_float<T: Float>() -> T {
+            super::any()
+        }
+
+        /// Returns a nondeterministic float that is neither NaN nor infinite.
+        ///
+        /// Every other value is possible, including both zeros and subnormals.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let f: f32 = kani::float::any_finite_float();
+        /// assert!(f - f == 0.0);
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "float-lib",
+            issue = "none",
+            reason = "experimental floating-point API"
+        )]
+        pub fn any_finite_float<T: Float>() -> T {
+            let value: T = super::any();
+            super::assume(value.is_finite());
+            value
+        }
+
+        /// Returns a nondeterministic float that is either a normal number or zero.
+        ///
+        /// This excludes NaN, both infinities and subnormals, but unlike `is_normal()`, it keeps
+        /// `0.0` and `-0.0`, since zeros are usually relevant for the property being checked.
+        /// Use `kani::float::any_of_category(FpCategory::Normal)` to exclude zeros as well.
+        #[crate::kani::unstable_feature(
+            feature = "float-lib",
+            issue = "none",
+            reason = "experimental floating-point API"
+        )]
+        pub fn any_normal_float<T: Float>() -> T {
+            let value: T = super::any();
+            super::assume(value.is_normal() || value == T::ZERO);
+            value
+        }
     };
 }
@@ -200,6 +200,35 @@ pub const fn assert(cond: bool, msg: &'static str) {
             assert!(cond, "{}", msg);
         }
 
+        /// Creates an assertion of the specified condition and message, tagged with a failure
+        /// category, e.g. `"precondition"`.
+        ///
+        /// The tag is reported as a `[<tag>]` prefix of the property description, so failures can
+        /// be filtered by category. The tag must be one of `"precondition"`, `"invariant"` or
+        /// `"postcondition"`. The `assert_tagged!` macro checks that at compile time.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let x: u8 = kani::any();
+        /// kani::assert_tagged(x < 200, "precondition", "x must be below 200");
+        /// ```
+        #[cfg(not(feature = "concrete_playback"))]
+        #[inline(never)]
+        #[kanitool::fn_marker = "AssertTaggedHook"]
+        pub fn assert_tagged(cond: bool, tag: &'static str, msg: &'static str) {
+            let _ = cond;
+            let _ = tag;
+            let _ = msg;
+        }
+
+        #[cfg(feature = "concrete_playback")]
+        #[inline(never)]
+        #[kanitool::fn_marker = "AssertTaggedHook"]
+        pub fn assert_tagged(cond: bool, tag: &'static str, msg: &'static str) {
+            assert!(cond, "[{}] {}", tag, msg);
+        }
+
         /// Creates a cover property with the specified condition and message.
         ///
         /// # Example:
@@ -289,6 +318,11 @@ pub fn any_modifies<T>() -> T {
         /// Note: This is a safe construct and can only be used with types that implement the `Arbitrary`
         /// trait. The Arbitrary trait is used to build a symbolic value that represents all possible
         /// valid values for type `T`.
+        ///
+        /// This is equivalent to calling `kani::any()` followed by `kani::assume(f(&value))`.
+        /// The predicate receives a reference, so large values are not moved, and it should not
+        /// have any side effect that matters to the harness, since it is only used to restrict
+        /// the values that are considered.
         #[inline(always)]
         pub fn any_where<T: Arbitrary, F: FnOnce(&T) -> bool>(f: F) -> T {
             let result = T::any();
@@ -296,6 +330,32 @@ pub fn any_where<T: Arbitrary, F: FnOnce(&T) -> bool>(f: F) -> T {
             result
         }
 
+        /// Creates a symbolic set of exactly `N` booleans, represented as an array.
+        ///
+        /// Every slot is an independent `kani::any::<bool>()`, so all `2^N` patterns are
+        /// possible. Unlike a `Vec<bool>`, the set lives on the stack and has a fixed size, which
+        /// makes it convenient for combinatorial proofs. Use [count_ones] to get its cardinality.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let set = kani::any_bitset::<4>();
+        /// assert!(kani::count_ones(&set) <= 4);
+        /// ```
+        #[inline(always)]
+        pub fn any_bitset<const N: usize>() -> [bool; N] {
+            any()
+        }
+
+        /// Returns the number of slots of `bits` that are set.
+        ///
+        /// The count is computed by summing the slots in a loop over the array. Since the loop has a
+        /// constant number of iterations, it doesn't branch on the value of the slots.
+        #[inline(always)]
+        pub fn count_ones<const N: usize>(bits: &[bool; N]) -> usize {
+            bits.iter().map(|bit| *bit as usize).sum()
+        }
+
         /// This function creates a symbolic value of type `T`. This may result in an invalid value.
         ///
         /// # Safety
@@ -452,6 +512,20 @@ pub fn automatic_harness() {
                 super::kani_intrinsic()
             }
 
+            /// Whether the assertions of `debug_assert!` and related macros should be checked.
+            ///
+            /// Kani's overrides of these macros only evaluate the assertion if this returns
+            /// `true`. The compiler replaces its body with one that returns `false` when the
+            /// `skip-debug-assertions` unstable feature is enabled.
+            ///
+            /// This is a `const fn` so the overrides can be used inside user `const fn`s.
+            #[kanitool::fn_marker = "DebugAssertionsEnabledIntrinsic"]
+            #[inline(never)]
+            #[doc(hidden)]
+            pub const fn debug_assertions_enabled() -> bool {
+                true
+            }
+
             /// A way to break the ownerhip rules. Only used by contracts where we can
             /// guarantee it is done safely.
             #[inline(never)]
@@ -595,6 +669,30 @@ pub(crate) const fn check(cond: bool, msg: &'static str) {
             pub(crate) const fn check(cond: bool, msg: &'static str) {
                 assert!(cond, "{}", msg);
             }
+
+            /// Assert a contract clause, where `clause` is either `"precondition"` or
+            /// `"postcondition"`.
+            ///
+            /// This is used by the contract macros instead of `kani::assert`, so contract
+            /// obligations are reported with the `contract` property class.
+            #[doc(hidden)]
+            #[cfg(not(feature = "concrete_playback"))]
+            #[inline(never)]
+            #[kanitool::fn_marker = "ContractAssertHook"]
+            pub const fn contract_assert(cond: bool, clause: &'static str, msg: &'static str) {
+                let _ = cond;
+                let _ = clause;
+                let _ = msg;
+            }
+
+            #[doc(hidden)]
+            #[cfg(feature = "concrete_playback")]
+            #[inline(never)]
+            #[kanitool::fn_marker = "ContractAssertHook"]
+            pub const fn contract_assert(cond: bool, clause: &'static str, msg: &'static str) {
+                let _ = clause;
+                assert!(cond, "{}", msg);
+            }
         }
     };
 }
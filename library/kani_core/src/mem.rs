@@ -110,6 +110,9 @@ pub fn can_read_unaligned<T: ?Sized>(ptr: *const T) -> bool {
         /// are in bounds of that object.
         ///
         /// A pointer is still considered in-bounds if it points to 1-byte past the allocation.
+        /// For fat pointers, only the data component is compared. Equal pointers are always
+        /// considered to be in the same allocation, even if they are dangling, which matches the
+        /// requirements of [`pointer::offset_from`].
         #[crate::kani::unstable_feature(
             feature = "mem-predicates",
             issue = 2690,
@@ -120,11 +123,64 @@ pub fn same_allocation<T: ?Sized>(ptr1: *const T, ptr2: *const T) -> bool {
             same_allocation_internal(ptr1, ptr2)
         }
 
+        /// Mark `len * size_of::<T>()` bytes starting from `ptr` as uninitialized.
+        ///
+        /// This only updates the memory initialization state tracked by Kani when uninitialized
+        /// memory checks are enabled (`-Z uninit-checks`), and it is a no-op otherwise. Any read
+        /// from the poisoned region will fail the initialization check until it is written again.
+        ///
+        /// Poisoning a zero-length region has no effect.
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn poison<T>(ptr: *mut T, len: usize) {
+            poison_internal(ptr, len)
+        }
+
+        /// Read a value from an externally-mutable memory location, such as a memory-mapped
+        /// hardware register.
+        ///
+        /// The memory content is not read. Instead, every call returns a fresh nondeterministic
+        /// value of type `T`, so two reads of the same address may return different values.
+        /// The pointer must still be aligned and point to an allocation large enough to hold a `T`,
+        /// which is checked by an assertion.
+        ///
+        /// Use [self::nondet_at_where] to restrict the values the location may hold.
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn nondet_at<T: crate::kani::Arbitrary>(addr: *const T) -> T {
+            crate::kani::assert(
+                is_ptr_aligned(addr) && is_inbounds(addr),
+                "`nondet_at` requires a pointer that is valid for reads",
+            );
+            crate::kani::any()
+        }
+
+        /// Same as [self::nondet_at], but the returned value is constrained to satisfy `f`.
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn nondet_at_where<T: crate::kani::Arbitrary, F: FnOnce(&T) -> bool>(
+            addr: *const T,
+            f: F,
+        ) -> T {
+            let value = nondet_at(addr);
+            crate::kani::assume(f(&value));
+            value
+        }
+
         #[allow(clippy::not_unsafe_ptr_arg_deref)]
         pub(super) fn same_allocation_internal<T: ?Sized>(ptr1: *const T, ptr2: *const T) -> bool {
             let addr1 = ptr1 as *const ();
             let addr2 = ptr2 as *const ();
-            cbmc::same_allocation(addr1, addr2)
+            addr1 == addr2 || cbmc::same_allocation(addr1, addr2)
         }
 
         /// Compute the size of the val pointed to if it is safe to do so.
@@ -234,6 +290,16 @@ pub(crate) fn is_initialized<T: ?Sized>(_ptr: *const T) -> bool {
             kani_intrinsic()
         }
 
+        /// Mark `len * size_of::<T>()` bytes starting from `ptr` as uninitialized.
+        ///
+        /// The call site is instrumented by the uninitialized memory checks, which update the
+        /// memory initialization state accordingly.
+        #[kanitool::fn_marker = "PoisonIntrinsic"]
+        #[inline(never)]
+        fn poison_internal<T>(_ptr: *mut T, _len: usize) {
+            kani_intrinsic()
+        }
+
         /// A helper to assert `is_initialized` to use it as a part of other predicates.
         fn assert_is_initialized<T: ?Sized>(ptr: *const T) -> bool {
             super::internal::check(
@@ -18,26 +18,6 @@
     parse_quote,
 };
 
-#[cfg(feature = "no_core")]
-macro_rules! kani_path {
-    ($span:expr) => {
-        quote_spanned! { $span => core::kani }
-    };
-    () => {
-        quote! { core::kani }
-    };
-}
-
-#[cfg(not(feature = "no_core"))]
-macro_rules! kani_path {
-    ($span:expr) => {
-        quote_spanned! { $span => kani }
-    };
-    () => {
-        quote! { kani }
-    };
-}
-
 /// Generate the Arbitrary implementation for the given type.
 ///
 /// Note that we cannot use `proc_macro_crate::crate_name()` to discover the name for `kani` crate
@@ -356,6 +336,9 @@ fn parse_safety_expr_input(ident: &Ident, derive_input: &DeriveInput) -> Option<
 /// #    }
 /// # }
 /// ```
+///
+/// Note that we match on the variant index instead of the discriminant value, so enums with
+/// explicit discriminants (e.g. `enum E { A = 1, B = 5 }`) never produce an invalid value.
 fn fn_any_enum(ident: &Ident, data: &DataEnum) -> TokenStream {
     if data.variants.is_empty() {
         let msg = format!(
@@ -0,0 +1,106 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module implements the `for_each_const!` macro, which checks an expression for every value
+//! of a const generic parameter in a range.
+//!
+//! For example, `for_each_const!(N in 0..=2 => f::<N>())` expands to:
+//!
+//! ```ignore
+//! {
+//!     let kani_const_value: usize = kani::any();
+//!     kani::assume(kani_const_value <= 2);
+//!     match kani_const_value {
+//!         0 => { const N: usize = 0; f::<N>() }
+//!         1 => { const N: usize = 1; f::<N>() }
+//!         2 => { const N: usize = 2; f::<N>() }
+//!         _ => unreachable!(),
+//!     }
+//! }
+//! ```
+use proc_macro_error2::abort;
+use proc_macro2::Literal;
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{
+    Expr, ExprRange, Ident, LitInt, RangeLimits, Token, Type, parse_macro_input, parse_quote,
+};
+
+/// The input of the macro: `<ident> [: <type>] in <range> => <expr>`.
+struct ForEachConst {
+    ident: Ident,
+    ty: Type,
+    range: ExprRange,
+    body: Expr,
+}
+
+impl Parse for ForEachConst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        let ty = if input.parse::<Option<Token![:]>>()?.is_some() {
+            input.parse()?
+        } else {
+            parse_quote!(usize)
+        };
+        input.parse::<Token![in]>()?;
+        let range = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let body = input.parse()?;
+        Ok(ForEachConst { ident, ty, range, body })
+    }
+}
+
+pub fn expand_for_each_const(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ForEachConst { ident, ty, range, body } = parse_macro_input!(item as ForEachConst);
+    let start = range_bound(range.start.as_deref(), &range);
+    let end = range_bound(range.end.as_deref(), &range);
+    let values = match range.limits {
+        RangeLimits::HalfOpen(_) => start..end,
+        RangeLimits::Closed(_) => start..end.saturating_add(1),
+    };
+    if values.is_empty() {
+        abort!(range.span(), "The range of values for `{}` is empty", ident);
+    }
+
+    let kani_path = kani_path!();
+    let arms = values.clone().map(|value| {
+        let value = Literal::u128_unsuffixed(value);
+        quote_spanned! { body.span() =>
+            #value => {
+                const #ident: #ty = #value;
+                #body
+            }
+        }
+    });
+    // Avoid comparing unsigned values against zero, which triggers `unused_comparisons`.
+    let lower_bound = (values.start > 0).then(|| {
+        let first = Literal::u128_unsuffixed(values.start);
+        quote!(kani_const_value >= #first &&)
+    });
+    let last = Literal::u128_unsuffixed(values.end - 1);
+    quote! {
+        {
+            let kani_const_value: #ty = #kani_path::any();
+            #kani_path::assume(#lower_bound kani_const_value <= #last);
+            match kani_const_value {
+                #(#arms)*
+                _ => unreachable!(),
+            }
+        }
+    }
+    .into()
+}
+
+/// Extract the value of a range bound, which must be a non-negative integer literal.
+fn range_bound(bound: Option<&Expr>, range: &ExprRange) -> u128 {
+    let Some(bound) = bound else {
+        abort!(range.span(), "Expected a bounded range, e.g., `0..=8`");
+    };
+    let lit: LitInt = match syn::parse2(quote!(#bound)) {
+        Ok(lit) => lit,
+        Err(_) => abort!(bound.span(), "Expected an integer literal as range bound"),
+    };
+    lit.base10_parse().unwrap_or_else(|_| {
+        abort!(lit.span(), "Expected a non-negative integer literal as range bound")
+    })
+}
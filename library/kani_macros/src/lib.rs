@@ -9,7 +9,31 @@
 //   RUSTFLAGS="-Zcrate-attr=feature(register_tool) -Zcrate-attr=register_tool(kanitool)"
 #![feature(proc_macro_diagnostic)]
 #![feature(proc_macro_span)]
+
+/// Path to the Kani library, which depends on whether we are building the `kani` crate or the
+/// standard library with Kani support (`no_core`).
+#[cfg(feature = "no_core")]
+macro_rules! kani_path {
+    ($span:expr) => {
+        quote_spanned! { $span => core::kani }
+    };
+    () => {
+        quote! { core::kani }
+    };
+}
+
+#[cfg(not(feature = "no_core"))]
+macro_rules! kani_path {
+    ($span:expr) => {
+        quote_spanned! { $span => kani }
+    };
+    () => {
+        quote! { kani }
+    };
+}
+
 mod derive;
+mod for_each_const;
 
 // proc_macro::quote is nightly-only, so we'll cobble things together instead
 use proc_macro::TokenStream;
@@ -52,6 +76,19 @@ pub fn should_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::should_panic(attr, item)
 }
 
+/// Specifies the expected verification result of a proof harness.
+///
+/// The attribute `#[kani::expect(failure)]` can only be used alongside `#[kani::proof]`.
+/// It inverts the verification result of the harness: verification succeeds if at least one
+/// check fails, and it fails if all checks pass.
+///
+/// Unlike `#[kani::should_panic]`, any kind of failed check satisfies the expectation.
+/// Note that checks with an `UNDETERMINED` result do not count as failures.
+#[proc_macro_attribute]
+pub fn expect(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::expect(attr, item)
+}
+
 /// Specifies that a function contains recursion for contract instrumentation.**
 ///
 /// This attribute is only used for function-contract instrumentation. Kani uses
@@ -71,6 +108,24 @@ pub fn unwind(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::unwind(attr, item)
 }
 
+/// Assume a bound on the number of iterations of one loop of a proof harness.
+///
+/// The attribute `#[kani::unwind_assume(loop_id = <ID>, bound = <BOUND>)]` can only be used
+/// alongside `#[kani::proof]`, and it requires `-Z assume-unwind`.
+///
+/// Loops are numbered from `0` following the order of their back-edges in the harness body, which
+/// is the same number reported by their `unwinding assertion loop <ID>` check. Thus, an inner loop
+/// comes before the loop that encloses it. Kani assumes that the back-edge of the loop with the
+/// given id is taken at most `<BOUND>` times every time the loop is entered, instead of checking
+/// that the unwinding bound is sufficient.
+/// Other loops keep their unwinding assertions.
+///
+/// This is unsound, since any execution that iterates more than `<BOUND>` times is discarded.
+#[proc_macro_attribute]
+pub fn unwind_assume(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::unwind_assume(attr, item)
+}
+
 /// Specify a function/method stub pair to use for proof harness
 ///
 /// The attribute `#[kani::stub(original, replacement)]` can only be used alongside `#[kani::proof]`.
@@ -93,6 +148,18 @@ pub fn solver(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::solver(attr, item)
 }
 
+/// Bound the time spent verifying this harness.
+///
+/// The attribute `#[kani::solver_timeout(secs = <SECONDS>)]` can only be used alongside
+/// `#[kani::proof]`. If verification does not complete within the given number of seconds, the
+/// harness is reported as `TIMEOUT` and Kani moves on to the next harness.
+///
+/// This takes precedence over the `--harness-timeout` command line option.
+#[proc_macro_attribute]
+pub fn solver_timeout(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::solver_timeout(attr, item)
+}
+
 /// Mark an API as unstable. This should only be used inside the Kani sysroot.
 /// See https://model-checking.github.io/kani/rfc/rfcs/0006-unstable-api.html for more details.
 #[doc(hidden)]
@@ -304,6 +371,36 @@ pub fn derive_invariant(item: TokenStream) -> TokenStream {
     derive::expand_derive_invariant(item)
 }
 
+/// Check an expression that depends on a const generic for every value in a range.
+///
+/// Const generics cannot be made symbolic directly, since their value must be known at compile
+/// time. Instead, this macro picks a nondeterministic value in the given range, and dispatches to
+/// a copy of the expression where the const parameter is bound to each concrete value:
+///
+/// ```rust
+/// fn check_buffer<const N: usize>() {
+///     let buffer = [0u8; N];
+///     assert!(buffer.iter().all(|b| *b == 0));
+/// }
+///
+/// #[kani::proof]
+/// fn check_all_buffers() {
+///     kani::for_each_const!(N in 0..=8 => check_buffer::<N>());
+/// }
+/// ```
+///
+/// The const parameter has type `usize` by default. Use `N: <type> in <range>` to bind it to a
+/// different integer type. Both `start..end` and `start..=end` ranges are supported, and their
+/// bounds must be integer literals.
+///
+/// Note that the expression gets monomorphized for every value in the range, so the size of the
+/// code under verification grows linearly with the size of the range. Keep the range modest.
+#[proc_macro_error]
+#[proc_macro]
+pub fn for_each_const(item: TokenStream) -> TokenStream {
+    for_each_const::expand_for_each_const(item)
+}
+
 /// Add a precondition to this function.
 ///
 /// This is part of the function contract API, for more general information see
@@ -552,11 +649,14 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 
     kani_attribute!(should_panic, no_args);
+    kani_attribute!(expect);
     kani_attribute!(recursion, no_args);
     kani_attribute!(solver);
+    kani_attribute!(solver_timeout);
     kani_attribute!(stub);
     kani_attribute!(unstable);
     kani_attribute!(unwind);
+    kani_attribute!(unwind_assume);
 }
 
 /// This module provides dummy implementations of Kani attributes which cannot be interpreted by
@@ -585,11 +685,14 @@ pub fn proof(_attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 
     no_op!(should_panic);
+    no_op!(expect);
     no_op!(recursion);
     no_op!(solver);
+    no_op!(solver_timeout);
     no_op!(stub);
     no_op!(unstable);
     no_op!(unwind);
+    no_op!(unwind_assume);
     no_op!(requires);
     no_op!(ensures);
     no_op!(modifies);
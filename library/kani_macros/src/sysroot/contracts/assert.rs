@@ -65,7 +65,7 @@ fn make_assert_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
         match &self.condition_type {
             ContractConditionsData::Requires { attr } => {
                 quote!({
-                    kani::assert(#attr, stringify!(#attr_copy));
+                    kani::internal::contract_assert(#attr, "precondition", stringify!(#attr_copy));
                     #(#body_stmts)*
                 })
             }
@@ -73,7 +73,11 @@ fn make_assert_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
                 let (remembers, ensures_clause) = build_ensures(attr);
 
                 let exec_postconditions = quote!(
-                    kani::assert(#ensures_clause, stringify!(#attr_copy));
+                    kani::internal::contract_assert(
+                        #ensures_clause,
+                        "postcondition",
+                        stringify!(#attr_copy)
+                    );
                 );
 
                 let return_expr = body_stmts.pop();
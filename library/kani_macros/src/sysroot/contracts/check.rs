@@ -35,7 +35,11 @@ pub fn make_check_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
                 // The code that enforces the postconditions and cleans up the shallow
                 // argument copies (with `mem::forget`).
                 let exec_postconditions = quote!(
-                    kani::assert(#ensures_clause, stringify!(#attr_copy));
+                    kani::internal::contract_assert(
+                        #ensures_clause,
+                        "postcondition",
+                        stringify!(#attr_copy)
+                    );
                 );
 
                 let return_expr = body_stmts.pop();
@@ -37,7 +37,7 @@ fn initial_replace_stmts(&self) -> Vec<syn::Stmt> {
     ///
     /// ```ignore
     /// // multiple preconditions and argument copies like like
-    /// kani::assert(.. precondition);
+    /// kani::internal::contract_assert(.., "precondition", ..);
     /// let arg_name = kani::internal::untracked_deref(&arg_value);
     /// // single result havoc
     /// let result : ResultType = kani::any();
@@ -86,7 +86,7 @@ fn expand_replace_body(&self, before: &[Stmt], after: &[Stmt]) -> TokenStream {
                 let Self { attr_copy, .. } = self;
                 let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
                 quote!({
-                    kani::assert(#attr, stringify!(#attr_copy));
+                    kani::internal::contract_assert(#attr, "precondition", stringify!(#attr_copy));
                     #(#before)*
                     #(#after)*
                     #result
@@ -28,7 +28,8 @@
 /// ```
 /// This function would return the two kani::assume statements in the former slice
 /// and the remaining statements in the latter.
-/// The flow for ContractMode::Replace is the same, except preconditions are asserted rather than assumed.
+/// The flow for ContractMode::Replace is the same, except preconditions are asserted with
+/// `kani::internal::contract_assert` rather than assumed.
 ///
 /// The caller can use the returned tuple to insert remembers statements after `preconditions` and before `rest`.
 /// Inserting the remembers statements after `preconditions` ensures that they are bound by the preconditions.
@@ -45,18 +46,17 @@
 pub fn split_for_remembers(stmts: &[Stmt], contract_mode: ContractMode) -> (&[Stmt], &[Stmt]) {
     let mut pos = 0;
 
-    let check_str = match contract_mode {
-        ContractMode::SimpleCheck => "assume",
-        ContractMode::Replace | ContractMode::Assert => "assert",
+    let check_path: &[&str] = match contract_mode {
+        ContractMode::SimpleCheck => &["kani", "assume"],
+        ContractMode::Replace | ContractMode::Assert => &["kani", "internal", "contract_assert"],
     };
 
     for stmt in stmts {
         if let Stmt::Expr(Expr::Call(ExprCall { func, .. }), _) = stmt {
             if let Expr::Path(ExprPath { path: Path { segments, .. }, .. }) = func.as_ref() {
-                let first_two_idents =
-                    segments.iter().take(2).map(|sgmt| sgmt.ident.to_string()).collect::<Vec<_>>();
+                let idents = segments.iter().map(|sgmt| sgmt.ident.to_string()).collect::<Vec<_>>();
 
-                if first_two_idents == vec!["kani", check_str] {
+                if idents == check_path {
                     pos += 1;
                 }
             }
@@ -99,23 +99,35 @@ macro_rules! assert_ne {
     });
 }
 
-// Treat the debug assert macros same as non-debug ones
+// Treat the debug assert macros same as non-debug ones, unless the user asked Kani to skip them
 #[cfg(not(feature = "concrete_playback"))]
 #[macro_export]
 macro_rules! debug_assert {
-    ($($x:tt)*) => ({ $crate::assert!($($x)*); })
+    ($($x:tt)*) => ({
+        if kani::internal::debug_assertions_enabled() {
+            $crate::assert!($($x)*);
+        }
+    })
 }
 
 #[cfg(not(feature = "concrete_playback"))]
 #[macro_export]
 macro_rules! debug_assert_eq {
-    ($($x:tt)*) => ({ $crate::assert_eq!($($x)*); })
+    ($($x:tt)*) => ({
+        if kani::internal::debug_assertions_enabled() {
+            $crate::assert_eq!($($x)*);
+        }
+    })
 }
 
 #[cfg(not(feature = "concrete_playback"))]
 #[macro_export]
 macro_rules! debug_assert_ne {
-    ($($x:tt)*) => ({ $crate::assert_ne!($($x)*); })
+    ($($x:tt)*) => ({
+        if kani::internal::debug_assertions_enabled() {
+            $crate::assert_ne!($($x)*);
+        }
+    })
 }
 
 // Override the print macros to skip all the printing functionality (which
@@ -12,3 +12,9 @@ fn main() {
 fn _playback_type_checks() {
     kani::concrete_playback_run(vec![], test_sum);
 }
+
+#[test]
+/// Same as above, but for `kani::concrete_playback_run_all`.
+fn _playback_run_all_type_checks() {
+    kani::concrete_playback_run_all(&[("main", vec![], main)]);
+}
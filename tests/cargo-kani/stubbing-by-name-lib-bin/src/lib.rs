@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! This tests `--stub-by-name` in a package with a library and a binary. The stubbed paths only
+//! exist in the library, which is where the harness is, so they must not be resolved when the
+//! binary is compiled.
+
+pub fn read_sensor() -> u32 {
+    unimplemented!("requires hardware")
+}
+
+pub fn calibrated() -> u32 {
+    read_sensor() + 1
+}
+
+#[cfg(kani)]
+mod stubs {
+    pub fn read_sensor() -> u32 {
+        10
+    }
+}
+
+#[kani::proof]
+fn check_calibrated() {
+    assert_eq!(calibrated(), 11);
+}
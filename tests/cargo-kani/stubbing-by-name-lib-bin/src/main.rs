@@ -0,0 +1,6 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn main() {
+    println!("{}", stubbing_by_name_lib_bin::calibrated());
+}
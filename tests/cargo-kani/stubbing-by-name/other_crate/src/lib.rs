@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+pub mod pub_mod {
+    pub fn answer() -> u32 {
+        0
+    }
+}
+
+pub fn first<T>(a: T, _b: T) -> T {
+    a
+}
+
+/// Calls the functions that are stubbed by the user, so the stubs must also apply to calls that
+/// happen inside this crate.
+pub fn sum_answers() -> u32 {
+    pub_mod::answer() + first(1, 2)
+}
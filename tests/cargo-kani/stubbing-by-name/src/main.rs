@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! This tests stubbing functions from a dependency via `--stub-by-name`, including a generic
+//! function that is stubbed for every instantiation.
+
+mod stubs {
+    pub fn answer() -> u32 {
+        42
+    }
+
+    pub fn second<T>(_a: T, b: T) -> T {
+        b
+    }
+}
+
+#[kani::proof]
+fn main() {
+    assert_eq!(other_crate::pub_mod::answer(), 42);
+    assert_eq!(other_crate::first(1u8, 2u8), 2);
+    assert_eq!(other_crate::first('a', 'b'), 'b');
+    assert_eq!(other_crate::sum_answers(), 44);
+}
@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_bitset` generates an unconstrained set of exactly `N` booleans.
+
+const N: usize = 5;
+
+#[kani::proof]
+fn check_count_range() {
+    let set = kani::any_bitset::<N>();
+    assert_eq!(set.len(), N);
+    let count = kani::count_ones(&set);
+    assert!(count <= N);
+    kani::cover!(count == 0, "empty set");
+    kani::cover!(count == N, "full set");
+}
+
+#[kani::proof]
+fn check_pattern_reachable() {
+    let set = kani::any_bitset::<N>();
+    kani::cover!(set == [true, false, true, true, false], "pattern is reachable");
+    assert_eq!(kani::count_ones(&[true, false, true, true, false]), 3);
+}
@@ -0,0 +1,40 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_fallible_fn!` makes an independent nondeterministic choice per call.
+
+#[derive(Debug, PartialEq, kani::Arbitrary)]
+enum Error {
+    Timeout,
+    Refused,
+}
+
+fn retry<T>(mut op: impl FnMut() -> Result<T, Error>, attempts: usize) -> (Option<T>, usize) {
+    let mut failures = 0;
+    for _ in 0..attempts {
+        match op() {
+            Ok(val) => return (Some(val), failures),
+            Err(_) => failures += 1,
+        }
+    }
+    (None, failures)
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_retry() {
+    let (res, failures) = retry::<u8>(kani::any_fallible_fn!(), 3);
+    assert!(failures <= 3);
+    assert!(res.is_some() || failures == 3);
+    kani::cover!(res.is_some() && failures == 2);
+    kani::cover!(res.is_none());
+}
+
+#[kani::proof]
+fn check_explicit_types() {
+    let mut op = kani::any_fallible_fn!(u32, Error);
+    let first = op();
+    let second = op();
+    kani::cover!(first.is_err() && second.is_ok());
+    kani::cover!(first == Err(Error::Refused));
+}
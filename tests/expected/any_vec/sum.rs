@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `any_vec` can be empty or full, and that its elements are unconstrained.
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_sum() {
+    let data = kani::vec::any_vec::<u8, 4>();
+    assert!(data.len() <= 4);
+    assert_eq!(data.capacity(), data.len());
+
+    let mut sum: u32 = 0;
+    for elem in &data {
+        sum += *elem as u32;
+    }
+    assert!(sum <= 4 * u8::MAX as u32);
+
+    kani::cover!(data.is_empty(), "empty");
+    kani::cover!(data.len() == 4 && sum == 4 * u8::MAX as u32, "full with maximum elements");
+    kani::cover!(data.len() == 2 && data[0] == 7 && data[1] == 200, "arbitrary elements");
+}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_where` only generates values that satisfy the given predicate.
+
+#[derive(kani::Arbitrary)]
+struct Buffer {
+    data: [u8; 16],
+    len: usize,
+}
+
+#[kani::proof]
+fn check_any_where_u8() {
+    let val = kani::any_where::<u8, _>(|x| *x < 10);
+    assert!(val < 10);
+    kani::cover!(val == 9);
+}
+
+#[kani::proof]
+fn check_any_where_struct() {
+    let buf: Buffer = kani::any_where(|buf: &Buffer| buf.len <= buf.data.len());
+    assert!(buf.len <= 16);
+    kani::cover!(buf.len == 16);
+}
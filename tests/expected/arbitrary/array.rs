@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that arbitrary arrays can hold any value of their element type, for elements generated
+//! at once and for elements generated one by one.
+
+#[kani::proof]
+fn check_empty_array() {
+    let arr: [u8; 0] = kani::any();
+    assert_eq!(arr.len(), 0);
+}
+
+#[kani::proof]
+fn check_u32_array() {
+    let arr: [u32; 4] = kani::any();
+    kani::cover!(arr == [0, u32::MAX, 1, 0], "u32 elements are independent");
+    kani::cover!(arr.iter().all(|e| *e == arr[0]), "u32 elements can be equal");
+}
+
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_nested_array() {
+    let arr: [[u8; 2]; 2] = kani::any();
+    kani::cover!(arr == [[0, 1], [2, u8::MAX]], "nested elements are independent");
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_bool_array() {
+    let arr: [bool; 3] = kani::any();
+    assert!(arr.iter().all(|e| (0..=1).contains(&(*e as u8))));
+    kani::cover!(arr == [true, false, true], "bool elements are independent");
+}
@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Check that users can generate arbitrary atomic orderings, and that all of them are reachable.
+
+extern crate kani;
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Loads cannot use `Release` nor `AcqRel` orderings.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+#[kani::proof]
+fn check_all_orderings() {
+    let order: Ordering = kani::any();
+    kani::cover!(order == Ordering::Relaxed);
+    kani::cover!(order == Ordering::Acquire);
+    kani::cover!(order == Ordering::Release);
+    kani::cover!(order == Ordering::AcqRel);
+    kani::cover!(order == Ordering::SeqCst);
+}
+
+#[kani::proof]
+fn check_atomic_load() {
+    let val: u32 = kani::any();
+    let atomic = AtomicU32::new(val);
+    assert_eq!(atomic.load(load_ordering(kani::any())), val);
+}
@@ -0,0 +1,59 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Check that we can generate arbitrary boxed values, and that recursive types can be generated
+// with a bounded builder.
+
+extern crate kani;
+
+#[derive(kani::Arbitrary)]
+struct Point {
+    x: i8,
+    y: i8,
+}
+
+#[kani::proof]
+fn check_box_primitive() {
+    let boxed: Box<u8> = kani::any();
+    kani::cover!(*boxed == 0);
+    kani::cover!(*boxed == u8::MAX);
+}
+
+#[kani::proof]
+fn check_box_struct() {
+    let boxed: Box<Point> = kani::any();
+    kani::cover!(boxed.x == i8::MIN && boxed.y == i8::MAX);
+    assert!(boxed.x as i16 + boxed.y as i16 <= 2 * i8::MAX as i16);
+}
+
+struct Node {
+    val: u8,
+    next: Option<Box<Node>>,
+}
+
+/// Generate a list with at most `depth` nodes.
+fn any_list(depth: usize) -> Option<Box<Node>> {
+    if depth == 0 || kani::any() {
+        None
+    } else {
+        Some(Box::new(Node { val: kani::any(), next: any_list(depth - 1) }))
+    }
+}
+
+fn len(list: &Option<Box<Node>>) -> usize {
+    match list {
+        None => 0,
+        Some(node) => 1 + len(&node.next),
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_bounded_recursive() {
+    let list = any_list(3);
+    assert!(len(&list) <= 3);
+    kani::cover!(len(&list) == 3);
+    if let Some(node) = &list {
+        kani::cover!(node.val == 42);
+    }
+}
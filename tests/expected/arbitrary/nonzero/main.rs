@@ -0,0 +1,47 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Ensure that kani::any can generate every non-zero value of the NonZero types.
+
+use std::num::*;
+
+macro_rules! harness {
+    ( $fn_name: ident, $type: ty, $base: ty ) => {
+        #[kani::proof]
+        fn $fn_name() {
+            let v1 = kani::any::<$type>().get();
+            kani::cover!(v1 == <$base>::MAX, "v1 may be MAX");
+            kani::cover!(v1 == <$base>::MIN, "v1 may be MIN");
+            kani::cover!(v1 == 1, "v1 may be one");
+            kani::cover!(v1 == 0, "v1 cannot be zero");
+        }
+    };
+}
+
+harness!(check_non_zero_i8, NonZeroI8, i8);
+harness!(check_non_zero_i16, NonZeroI16, i16);
+harness!(check_non_zero_i32, NonZeroI32, i32);
+harness!(check_non_zero_i64, NonZeroI64, i64);
+harness!(check_non_zero_i128, NonZeroI128, i128);
+harness!(check_non_zero_isize, NonZeroIsize, isize);
+
+/// Generate one cover property per value `16 * $high + $low` for every pair of digits.
+macro_rules! cover_all {
+    ( $val: ident, [$($high: literal)*], $lows: tt ) => {
+        $( cover_all!(@row $val, $high, $lows); )*
+    };
+    ( @row $val: ident, $high: literal, [$($low: literal)*] ) => {
+        $( kani::cover!($val == 16 * $high + $low); )*
+    };
+}
+
+/// Check that every value of `u8` other than zero is reachable.
+#[kani::proof]
+fn check_all_non_zero_u8() {
+    let val = kani::any::<NonZeroU8>().get();
+    cover_all!(
+        val,
+        [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15],
+        [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15]
+    );
+}
@@ -0,0 +1,48 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the pointer returned by `kani::any_opaque_ptr` can be cast back to its original
+//! type and dereferenced.
+
+use std::ffi::c_void;
+
+#[derive(kani::Arbitrary, Clone, Copy, PartialEq, Debug)]
+struct UserData {
+    id: u32,
+    counter: u64,
+}
+
+/// A C-style callback that increments the counter of the user data.
+extern "C" fn increment(data: *mut c_void) {
+    let user_data = unsafe { &mut *(data as *mut UserData) };
+    user_data.counter = user_data.counter.wrapping_add(1);
+}
+
+#[kani::proof]
+fn check_round_trip() {
+    let data = kani::any_opaque_ptr::<UserData>();
+    assert!(!data.is_null());
+    let before = unsafe { *(data as *mut UserData) };
+    increment(data);
+    let after = unsafe { *(data as *mut UserData) };
+    assert_eq!(after.id, before.id);
+    assert_eq!(after.counter, before.counter.wrapping_add(1));
+}
+
+#[kani::proof]
+fn check_nondet_value() {
+    let data = kani::any_opaque_ptr::<u8>() as *mut u8;
+    let value = unsafe { *data };
+    kani::cover!(value == 0);
+    kani::cover!(value == u8::MAX);
+}
+
+#[kani::proof]
+fn check_distinct_allocations() {
+    let first = kani::any_opaque_ptr::<u32>();
+    let second = kani::any_opaque_ptr::<u32>();
+    assert_ne!(first, second);
+    unsafe { *(first as *mut u32) = 0 };
+    unsafe { *(second as *mut u32) = 1 };
+    assert_eq!(unsafe { *(first as *mut u32) }, 0);
+}
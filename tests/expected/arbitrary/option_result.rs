@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that arbitrary `Option` and `Result` values can be any of their variants, including
+//! for types that use a niche to encode the variant.
+
+use std::num::NonZeroU8;
+
+#[kani::proof]
+fn check_option() {
+    let opt: Option<u8> = kani::any();
+    kani::cover!(opt.is_none(), "Option<u8> can be None");
+    kani::cover!(opt == Some(u8::MAX), "Option<u8> can be Some");
+}
+
+#[kani::proof]
+fn check_option_niche() {
+    let opt: Option<NonZeroU8> = kani::any();
+    kani::cover!(opt.is_none(), "Option<NonZeroU8> can be None");
+    kani::cover!(opt.is_some(), "Option<NonZeroU8> can be Some");
+    assert_ne!(opt.map_or(1, NonZeroU8::get), 0);
+}
+
+#[kani::proof]
+fn check_option_box() {
+    let opt: Option<Box<u8>> = kani::any();
+    kani::cover!(opt.is_none(), "Option<Box<u8>> can be None");
+    kani::cover!(opt.as_deref() == Some(&7), "Option<Box<u8>> can be Some");
+}
+
+/// References don't implement `Arbitrary`, so build the `Option<&u8>` from an arbitrary `bool`.
+#[kani::proof]
+fn check_option_ref() {
+    let x: u8 = kani::any();
+    let opt: Option<&u8> = kani::any::<bool>().then_some(&x);
+    kani::cover!(opt.is_none(), "Option<&u8> can be None");
+    kani::cover!(opt == Some(&x), "Option<&u8> can be Some");
+    assert!(opt.is_none_or(|val| *val == x));
+}
+
+#[kani::proof]
+fn check_result() {
+    let res: Result<u8, i8> = kani::any();
+    kani::cover!(res == Ok(1), "Result<u8, i8> can be Ok");
+    kani::cover!(res == Err(-1), "Result<u8, i8> can be Err");
+}
+
+#[kani::proof]
+fn check_result_niche() {
+    let res: Result<NonZeroU8, ()> = kani::any();
+    kani::cover!(res.is_ok(), "Result<NonZeroU8, ()> can be Ok");
+    kani::cover!(res.is_err(), "Result<NonZeroU8, ()> can be Err");
+}
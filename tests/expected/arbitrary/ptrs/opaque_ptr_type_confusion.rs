@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that dereferencing a pointer returned by `kani::any_opaque_ptr` as a larger type than
+//! the one it was allocated with fails.
+
+#[kani::proof]
+fn check_wrong_type() {
+    let data = kani::any_opaque_ptr::<u16>();
+    let _value = unsafe { *(data as *mut u64) };
+}
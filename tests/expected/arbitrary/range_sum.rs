@@ -0,0 +1,49 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that iterating over bounded arbitrary ranges terminates and yields the expected sums.
+
+use std::ops::{Range, RangeInclusive};
+
+fn is_bounded(start: u32, end: u32) -> bool {
+    start <= 10 && end <= 10
+}
+
+#[kani::proof]
+#[kani::unwind(12)]
+fn check_range_sum() {
+    let range: Range<u32> = kani::any();
+    kani::assume(is_bounded(range.start, range.end));
+    let (start, end) = (range.start, range.end);
+    let sum: u32 = range.sum();
+    if start >= end {
+        assert_eq!(sum, 0);
+    } else {
+        assert_eq!(sum, (end * (end - 1) - start * (start.wrapping_sub(1))) / 2);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(13)]
+fn check_range_inclusive_sum() {
+    let range: RangeInclusive<u32> = kani::any();
+    kani::assume(is_bounded(*range.start(), *range.end()));
+    let (start, end) = (*range.start(), *range.end());
+    assert_eq!(range.is_empty(), start > end);
+    let sum: u32 = range.clone().sum();
+    let expected: u32 = (start..end + 1).sum();
+    assert_eq!(sum, expected);
+}
+
+#[kani::proof]
+#[kani::unwind(13)]
+fn check_range_inclusive_exhausted() {
+    let mut range: RangeInclusive<u32> = kani::any();
+    kani::assume(is_bounded(*range.start(), *range.end()));
+    let was_empty = range.is_empty();
+    // Exhaust the range by consuming all its elements.
+    while range.next().is_some() {}
+    assert!(range.is_empty());
+    assert_eq!(range.next(), None);
+    kani::cover!(!was_empty);
+}
@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assert_float_eq!` compares floats with a tolerance, fails for NaN and
+//! treats infinities of the same sign as equal.
+
+#[kani::proof]
+fn check_within_eps() {
+    let a: f32 = kani::any();
+    kani::assume(a > -10.0 && a < 10.0);
+    kani::assert_float_eq!(a, a + 0.5, 1.0);
+}
+
+#[kani::proof]
+fn check_outside_eps() {
+    let a: f64 = 1.0;
+    let b: f64 = kani::any();
+    kani::assume(b > 3.0 && b < 4.0);
+    kani::assert_float_eq!(a, b, 1.0);
+}
+
+#[kani::proof]
+fn check_nan() {
+    let a: f64 = kani::any();
+    kani::assume(a.is_nan());
+    kani::assert_float_eq!(a, 0.0, f64::INFINITY);
+}
+
+#[kani::proof]
+fn check_infinity() {
+    match kani::any::<u8>() {
+        0 => kani::assert_float_eq!(f32::INFINITY, f32::INFINITY, 0.0),
+        1 => kani::assert_float_eq!(f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0),
+        _ => kani::assert_float_eq!(f32::INFINITY, f32::NEG_INFINITY, f32::MAX),
+    }
+}
@@ -0,0 +1,10 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assert_tagged!` rejects tags that are not in the allowed set.
+
+#[kani::proof]
+fn check_invalid_tag() {
+    let x: u8 = kani::any();
+    kani::assert_tagged!(x < 200, "requirement", "x is small");
+}
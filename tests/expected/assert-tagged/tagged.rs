@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the tag of a failed `kani::assert_tagged!` is reported with the property.
+
+fn checked_div(num: u32, den: u32) -> u32 {
+    kani::assert_tagged!(den != 0, "precondition", "the denominator must not be zero");
+    let result = num / den;
+    kani::assert_tagged!(result <= num, "postcondition", "the result is at most the numerator");
+    result
+}
+
+#[kani::proof]
+fn check_div() {
+    let num: u32 = kani::any();
+    let den: u32 = kani::any();
+    checked_div(num, den);
+}
@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assume_chain!` assumes each conjunct in order.
+
+fn is_valid_idx(arr: &[u8], idx: usize) -> bool {
+    idx < arr.len()
+}
+
+fn is_even_at(arr: &[u8], idx: usize) -> bool {
+    arr[idx] % 2 == 0
+}
+
+#[kani::proof]
+fn check_progressive_assume() {
+    let arr: [u8; 4] = kani::any();
+    let idx: usize = kani::any();
+    kani::assume_chain!(is_valid_idx(&arr, idx) && is_even_at(&arr, idx));
+    assert!(idx < arr.len());
+    assert!(arr[idx] % 2 == 0);
+}
+
+#[kani::proof]
+fn check_single_condition() {
+    let val: u32 = kani::any();
+    kani::assume_chain!(val > 10);
+    assert!(val != 0);
+}
+
+#[kani::proof]
+fn check_disjunction() {
+    let a: bool = kani::any();
+    let b: bool = kani::any();
+    let c: bool = kani::any();
+    // `&&` binds tighter than `||`, so this must be assumed as a whole.
+    kani::assume_chain!(a || b && c);
+    assert!(a || (b && c));
+    kani::cover!(a && !b);
+}
@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// compile-flags: --edition 2018
+// kani-flags: -Z async-lib
+
+//! Check that the executor reports tasks that wait on each other as a deadlock, but not tasks
+//! that wait on a task that eventually completes.
+
+use kani::futures::JoinHandle;
+
+static mut HANDLES: [Option<JoinHandle>; 2] = [None, None];
+
+#[allow(static_mut_refs)]
+fn take_handle(index: usize) -> JoinHandle {
+    unsafe { HANDLES[index].take().unwrap() }
+}
+
+/// Two tasks that await each other can never complete.
+#[kani::proof]
+#[kani::unwind(8)]
+fn mutual_await() {
+    kani::block_on_with_spawn(
+        async {
+            let first = kani::spawn(async {
+                kani::yield_now().await;
+                take_handle(1).await;
+            });
+            let second = kani::spawn(async {
+                kani::yield_now().await;
+                take_handle(0).await;
+            });
+            unsafe { HANDLES = [Some(first), Some(second)] };
+        },
+        kani::RoundRobin::default(),
+    );
+}
+
+/// The main task waits on a task that yields a few times before completing.
+#[kani::proof]
+#[kani::unwind(8)]
+fn await_yielding_task() {
+    kani::block_on_with_spawn(
+        async {
+            let handle = kani::spawn(async {
+                kani::yield_now().await;
+                kani::yield_now().await;
+            });
+            handle.await;
+        },
+        kani::RoundRobin::default(),
+    );
+}
@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Check that the branch hints in `core::hint` have no effect on verification: both branches of a
+// hinted condition remain reachable, and the result is the value of the condition.
+
+#![feature(likely_unlikely)]
+use std::hint::{likely, unlikely};
+
+fn clamp(val: u8, max: u8) -> u8 {
+    if likely(val <= max) { val } else { max }
+}
+
+#[kani::proof]
+fn check_likely_branches() {
+    let val: u8 = kani::any();
+    let max: u8 = kani::any();
+    let res = clamp(val, max);
+    assert!(res <= max);
+    assert!(res == val || res == max);
+    kani::cover!(val <= max, "likely branch");
+    kani::cover!(val > max, "unlikely branch");
+}
+
+#[kani::proof]
+fn check_hint_value() {
+    let cond: bool = kani::any();
+    assert_eq!(likely(cond), cond);
+    assert_eq!(unlikely(cond), cond);
+    if unlikely(cond) {
+        assert!(cond);
+    } else {
+        assert!(!cond);
+    }
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z catch-unwind
+
+//! Check that panics are only caught inside `catch_unwind`.
+
+use std::panic;
+
+#[kani::proof]
+fn check_panic_after_catch() {
+    let result = panic::catch_unwind(|| {
+        panic!("caught");
+    });
+    assert!(result.is_err());
+    panic!("not caught");
+}
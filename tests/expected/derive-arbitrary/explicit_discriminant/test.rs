@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani can automatically derive `Arbitrary` on enums with explicit discriminants,
+//! that every variant is reachable, and that no invalid discriminant is produced.
+
+#[derive(kani::Arbitrary, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+enum Sparse {
+    A = 1,
+    B = 5,
+    C = 200,
+}
+
+#[derive(kani::Arbitrary)]
+enum WithFields {
+    Unit = 10,
+    Tuple(Sparse, bool) = 20,
+    Named { val: u8 } = 30,
+}
+
+#[derive(kani::Arbitrary, PartialEq, Eq)]
+enum Single {
+    Only = 42,
+}
+
+#[kani::proof]
+fn check_sparse() {
+    let e: Sparse = kani::any();
+    kani::cover!(e == Sparse::A);
+    kani::cover!(e == Sparse::B);
+    kani::cover!(e == Sparse::C);
+    let discr = e as u8;
+    assert!(discr == 1 || discr == 5 || discr == 200, "valid discriminant");
+}
+
+#[kani::proof]
+fn check_with_fields() {
+    let e: WithFields = kani::any();
+    match e {
+        WithFields::Unit => kani::cover!(true, "unit variant"),
+        WithFields::Tuple(inner, _) => {
+            kani::cover!(inner == Sparse::C, "tuple variant");
+            assert!(matches!(inner as u8, 1 | 5 | 200), "valid inner discriminant");
+        }
+        WithFields::Named { val } => kani::cover!(val == u8::MAX, "named variant"),
+    }
+}
+
+#[kani::proof]
+fn check_single() {
+    let e: Single = kani::any();
+    assert!(e == Single::Only, "single variant");
+}
@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Check that a pointer to a local can be exposed as an integer and re-derived from it.
+
+use std::ptr;
+
+#[kani::proof]
+fn check_round_trip() {
+    let mut value: u32 = kani::any();
+    let original = value;
+    let addr = ptr::addr_of_mut!(value).expose_provenance();
+    let ptr = ptr::with_exposed_provenance_mut::<u32>(addr);
+    unsafe {
+        assert_eq!(*ptr, original);
+        *ptr = original.wrapping_add(1);
+    }
+    assert_eq!(value, original.wrapping_add(1));
+}
+
+#[kani::proof]
+fn check_address_matches() {
+    let array = [1u16, 2, 3];
+    let idx: usize = kani::any_where(|i| *i < array.len());
+    let addr = (&array[idx] as *const u16).expose_provenance();
+    assert_eq!(addr, array.as_ptr().expose_provenance() + idx * size_of::<u16>());
+    let elem = unsafe { *ptr::with_exposed_provenance::<u16>(addr) };
+    assert_eq!(elem, array[idx]);
+}
@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfloat-lib
+
+//! This test checks that `kani::float::any_of_category` only returns values of the given category.
+
+use std::num::FpCategory;
+
+#[kani::proof]
+fn check_zero() {
+    let f = kani::float::any_of_category(FpCategory::Zero);
+    assert!(f == 0.0);
+    kani::cover!(f.is_sign_negative());
+}
+
+#[kani::proof]
+fn check_subnormal() {
+    let f = kani::float::any_of_category(FpCategory::Subnormal);
+    assert!(f != 0.0 && f.abs() < f64::MIN_POSITIVE);
+    kani::cover!(f == f64::from_bits(1));
+}
+
+#[kani::proof]
+fn check_normal() {
+    let f = kani::float::any_of_category(FpCategory::Normal);
+    assert!(f.is_finite() && f.abs() >= f64::MIN_POSITIVE);
+    kani::cover!(f == -1.0);
+}
+
+#[kani::proof]
+fn check_infinite() {
+    let f = kani::float::any_of_category(FpCategory::Infinite);
+    assert!(f == f64::INFINITY || f == f64::NEG_INFINITY);
+    kani::cover!(f == f64::INFINITY);
+    kani::cover!(f == f64::NEG_INFINITY);
+}
+
+#[kani::proof]
+fn check_nan() {
+    let f = kani::float::any_of_category(FpCategory::Nan);
+    assert!(f.is_nan());
+    kani::cover!(f.to_bits() != f64::NAN.to_bits());
+}
@@ -0,0 +1,44 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfloat-lib
+
+//! This test checks the values returned by the nondeterministic float generators.
+
+#[kani::proof]
+fn check_any_float_includes_special_values() {
+    let f: f64 = kani::float::any_float();
+    kani::cover!(f.is_nan());
+    kani::cover!(f == f64::INFINITY);
+    kani::cover!(f == f64::NEG_INFINITY);
+    kani::cover!(f == 1.5);
+    kani::cover!(f == 0.0 && f.is_sign_negative());
+}
+
+#[kani::proof]
+fn check_any_finite_float_f32() {
+    let f: f32 = kani::float::any_finite_float();
+    assert!(!f.is_nan());
+    assert!(!f.is_infinite());
+    kani::cover!(f == 1.5);
+    kani::cover!(f == f32::MAX);
+    kani::cover!(f == 0.0 && f.is_sign_negative());
+    kani::cover!(f.is_subnormal());
+}
+
+#[kani::proof]
+fn check_any_finite_float_f64() {
+    let f: f64 = kani::float::any_finite_float();
+    assert!(f.is_finite());
+    kani::cover!(f == -42.25);
+}
+
+#[kani::proof]
+fn check_any_normal_float() {
+    let f: f64 = kani::float::any_normal_float();
+    assert!(f.is_finite());
+    assert!(!f.is_subnormal());
+    assert!(f == 0.0 || f.abs() >= f64::MIN_POSITIVE);
+    kani::cover!(f == 0.0 && f.is_sign_positive());
+    kani::cover!(f == 0.0 && f.is_sign_negative());
+    kani::cover!(f == 3.0);
+}
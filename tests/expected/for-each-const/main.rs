@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::for_each_const!` covers every value of a const generic in a range.
+
+fn sum_of_ones<const N: usize>() -> usize {
+    let buffer = [1u8; N];
+    buffer.iter().map(|b| *b as usize).sum()
+}
+
+fn identity<const N: u8>() -> u8 {
+    N
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn check_inclusive_range() {
+    let result = kani::for_each_const!(N in 0..=8 => (N, sum_of_ones::<N>()));
+    assert_eq!(result.0, result.1);
+    kani::cover!(result.0 == 0);
+    kani::cover!(result.0 == 8);
+}
+
+#[kani::proof]
+fn check_exclusive_range_with_type() {
+    let result = kani::for_each_const!(N: u8 in 1..4 => identity::<N>());
+    assert!(result >= 1 && result < 4);
+    kani::cover!(result == 1);
+    kani::cover!(result == 3);
+}
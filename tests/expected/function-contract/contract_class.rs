@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that preconditions checked at a call site and postconditions checked in the function
+//! are both reported with the `contract` property class, and that they are tagged differently.
+
+#[kani::requires(x > 0)]
+#[kani::ensures(|result : &u32| *result < x)]
+fn decrement(x: u32) -> u32 {
+    x - 1
+}
+
+#[kani::requires(x > 0)]
+#[kani::ensures(|result : &u32| *result < x)]
+fn wrong_decrement(x: u32) -> u32 {
+    x
+}
+
+#[kani::proof_for_contract(decrement)]
+fn check_decrement() {
+    decrement(kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(decrement)]
+fn check_precondition_at_call_site() {
+    decrement(kani::any());
+}
+
+#[kani::proof_for_contract(wrong_decrement)]
+fn check_postcondition_in_function() {
+    wrong_decrement(kani::any());
+}
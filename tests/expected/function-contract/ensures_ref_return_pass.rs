@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that the postcondition of a function that returns a mutable reference can constrain the
+//! pointee, even when the reference points to one of the modified locations.
+
+#[kani::requires(*a < i32::MAX && *b < i32::MAX)]
+#[kani::modifies(a, b)]
+#[kani::ensures(|result: &&mut i32| **result == old(if *a >= *b { *a } else { *b }) + 1)]
+fn increment_max<'a>(a: &'a mut i32, b: &'a mut i32) -> &'a mut i32 {
+    let max = if *a >= *b { a } else { b };
+    *max += 1;
+    max
+}
+
+#[kani::proof_for_contract(increment_max)]
+fn check_increment_max() {
+    let mut a = kani::any();
+    let mut b = kani::any();
+    let max = increment_max(&mut a, &mut b);
+    // The returned reference can still be used to write to the modified location.
+    *max = 0;
+}
@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that `old` expressions that call `min` and `max` are evaluated when the function is
+//! called.
+
+#[kani::requires(*a < 100 && *b < 100)]
+#[kani::ensures(|result| *result == old((*a).min(*b)))]
+#[kani::ensures(|_| *a == old(core::cmp::max(*a, *b)) + 1 && *b == *a)]
+#[kani::modifies(a, b)]
+fn raise_both(a: &mut u32, b: &mut u32) -> u32 {
+    let min = (*a).min(*b);
+    let max = core::cmp::max(*a, *b);
+    *a = max + 1;
+    *b = max + 1;
+    min
+}
+
+#[kani::ensures(|result: &T| *result == old(a.min(b)) && *result <= old(a.max(b)))]
+fn smaller<T: Ord + Copy>(a: T, b: T) -> T {
+    if a < b { a } else { b }
+}
+
+#[kani::proof_for_contract(raise_both)]
+fn check_raise_both() {
+    let mut a = kani::any();
+    let mut b = kani::any();
+    raise_both(&mut a, &mut b);
+}
+
+#[kani::proof_for_contract(smaller)]
+fn check_smaller() {
+    smaller::<i8>(kani::any(), kani::any());
+}
@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+/// This test calls `min` within an `old` expression on a type whose
+/// `Ord` implementation has a side effect, i.e., it may panic. The
+/// comparison is evaluated when the function is called, and there are
+/// currently no checks that report the side effect.
+/// See https://github.com/model-checking/kani/issues/3213
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fragile(u8);
+
+impl PartialOrd for Fragile {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fragile {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        assert!(self.0 != other.0, "comparison of equal values");
+        self.0.cmp(&other.0)
+    }
+}
+
+#[kani::ensures(|result: &u8| *result >= old(a.min(b)).0)]
+fn larger(a: Fragile, b: Fragile) -> u8 {
+    if a.0 >= b.0 { a.0 } else { b.0 }
+}
+
+#[kani::proof_for_contract(larger)]
+fn main() {
+    larger(Fragile(kani::any()), Fragile(kani::any()));
+}
@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --function-pointer-restrictions
+//
+//! Check that restricting calls through function pointers to the reachable functions with a
+//! matching signature preserves every possible target.
+
+fn double(x: u8) -> u8 {
+    x.wrapping_mul(2)
+}
+
+fn increment(x: u8) -> u8 {
+    x.wrapping_add(1)
+}
+
+/// Same signature, but its address is never taken.
+#[allow(dead_code)]
+fn unused(x: u8) -> u8 {
+    x
+}
+
+#[kani::proof]
+fn check_fn_ptr_array() {
+    let fns: [fn(u8) -> u8; 2] = [double, increment];
+    let idx: usize = kani::any_where(|idx| *idx < fns.len());
+    let x: u8 = kani::any_where(|x| *x < 100);
+    let result = fns[idx](x);
+    if idx == 0 {
+        assert_eq!(result, x * 2);
+    } else {
+        assert_eq!(result, x + 1);
+    }
+    kani::cover!(idx == 0);
+    kani::cover!(idx == 1);
+}
+
+#[kani::proof]
+fn check_closure_fn_ptr() {
+    let offset: fn(u8) -> u8 = if kani::any() { |x| x.wrapping_sub(1) } else { increment };
+    let x: u8 = kani::any_where(|x| *x > 0 && *x < u8::MAX);
+    let result = offset(x);
+    assert!(result == x - 1 || result == x + 1);
+    kani::cover!(result == x - 1);
+}
+
+#[kani::proof]
+fn check_closure_only_fn_ptr() {
+    let half: fn(u8) -> u8 = |x| x / 2;
+    let x: u8 = kani::any();
+    let result = half(x);
+    assert!(result <= 127);
+    kani::cover!(result == 127);
+}
@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that overwriting a place with a nondet value forgets its previous contents.
+
+#[kani::proof]
+fn check_havoc_scalar() {
+    let mut x = 5u32;
+    assert_eq!(x, 5);
+    x = kani::any();
+    kani::cover!(x != 5, "scalar is no longer known");
+}
+
+#[kani::proof]
+fn check_havoc_array() {
+    let mut arr = [1u8; 4];
+    assert_eq!(arr, [1; 4]);
+    arr = kani::any();
+    kani::cover!(arr[3] != 1, "array element is no longer known");
+}
+
+#[kani::proof]
+fn check_havoc_behind_deref() {
+    let mut boxed = Box::new(5u32);
+    *boxed = kani::any();
+    kani::cover!(*boxed != 5, "boxed value is no longer known");
+}
+
+/// Overwrite the value behind a mutable reference.
+fn havoc_ref<T: kani::Arbitrary>(value: &mut T) {
+    *value = kani::any();
+}
+
+#[kani::proof]
+fn check_havoc_through_reference() {
+    let mut x = 5u32;
+    havoc_ref(&mut x);
+    kani::cover!(x != 5, "value behind a reference is no longer known");
+}
+
+#[kani::proof]
+fn check_havoc_through_pointer() {
+    let mut x = 5u32;
+    let ptr = &mut x as *mut u32;
+    unsafe { *ptr = kani::any() };
+    kani::cover!(x != 5, "value behind a pointer is no longer known");
+}
+
+#[kani::proof]
+fn check_havoc_zst() {
+    let _unit: () = kani::any();
+    let empty: [u32; 0] = kani::any();
+    assert_eq!(empty.len(), 0);
+}
@@ -0,0 +1,37 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assert_eq!` and `kani::assert_ne!` report both operand expressions on
+//! failure, and that they work for types that don't implement `Debug`.
+
+#[derive(PartialEq)]
+struct NoDebug {
+    inner: [u64; 32],
+}
+
+#[kani::proof]
+fn check_eq_fail() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    kani::assert_eq!(x + 0, y);
+}
+
+#[kani::proof]
+fn check_ne_fail() {
+    let x: i32 = kani::any();
+    kani::assert_ne!(x, x.wrapping_add(0));
+}
+
+#[kani::proof]
+fn check_no_debug() {
+    let big = NoDebug { inner: kani::any() };
+    let other = NoDebug { inner: big.inner };
+    kani::assert_eq!(big, other);
+    kani::assert_ne!(big.inner[0], big.inner[0].wrapping_add(1));
+}
+
+#[kani::proof]
+fn check_custom_message() {
+    let x: u8 = kani::any();
+    kani::assert_eq!(x, 0, "x must be zero");
+}
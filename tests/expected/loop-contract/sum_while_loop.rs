@@ -0,0 +1,25 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z loop-contracts
+
+//! Check that a loop invariant referencing locals mutated by the loop is enough to prove the
+//! result of a sum loop, without unwinding the loop for every possible bound.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+fn sum_while_loop_harness() {
+    let n: u32 = kani::any_where(|n| *n <= 1000);
+    let mut i: u32 = 0;
+    let mut sum: u64 = 0;
+
+    #[kani::loop_invariant(i <= n && 2 * sum == (i as u64) * (i as u64 + 1))]
+    while i < n {
+        i += 1;
+        sum += i as u64;
+    }
+
+    assert!(2 * sum == (n as u64) * (n as u64 + 1));
+}
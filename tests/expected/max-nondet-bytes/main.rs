@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --max-nondet-bytes 32
+
+//! Check that arrays that are generated one element at a time count every element against the
+//! nondeterministic input budget.
+
+#[kani::proof]
+fn check_bool_array() {
+    let flags: [bool; 64] = kani::any();
+    assert!(flags.len() == 64);
+}
@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z mem-predicates
+
+//! Check that `kani::mem::nondet_at` models an externally-mutable memory location, i.e., two
+//! reads may return different values, and that the pointer validity is still checked.
+
+/// A fake memory-mapped device with a status register.
+struct Device {
+    status: u32,
+}
+
+const READY: u32 = 1;
+
+#[kani::proof]
+fn check_status_polling() {
+    let device = Device { status: 0 };
+    let status_reg = &device.status as *const u32;
+    let first = kani::mem::nondet_at(status_reg);
+    let second = kani::mem::nondet_at(status_reg);
+    kani::cover!(first != second, "reads may disagree");
+    kani::cover!(first == READY && second != READY, "device may become busy again");
+    // The memory itself is never read, so it still holds its original value.
+    assert_eq!(device.status, 0);
+}
+
+#[kani::proof]
+fn check_constrained_read() {
+    let status: u8 = 0;
+    let value = kani::mem::nondet_at_where(&status as *const u8, |v| *v < 4);
+    assert!(value < 4);
+}
+
+#[kani::proof]
+fn check_out_of_bounds_register() {
+    let byte: u8 = 0;
+    let status_reg = (&byte as *const u8).cast::<u32>();
+    let _ = kani::mem::nondet_at(status_reg);
+}
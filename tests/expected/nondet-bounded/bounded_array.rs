@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Test the Kani library's API for creating a non-det array with a bounded effective length
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_len() {
+    let (len, array) = kani::slice::any_bounded_array::<u8, 4>();
+    assert!(len <= 4);
+    assert_eq!(array[..len].len(), len);
+    kani::cover!(len == 0);
+    kani::cover!(len == 4);
+}
+
+#[kani::proof]
+fn check_elements_past_len_are_arbitrary() {
+    let (len, array) = kani::slice::any_bounded_array::<u8, 3>();
+    kani::assume(len == 1);
+    // Nothing is implied about the elements that are not in use.
+    kani::cover!(array[1] == 0);
+    kani::cover!(array[1] != 0);
+    kani::cover!(array[2] == u8::MAX);
+}
+
+#[kani::proof]
+fn check_zero_max_length() {
+    let (len, array) = kani::slice::any_bounded_array::<u64, 0>();
+    assert_eq!(len, 0);
+    assert!(array.is_empty());
+}
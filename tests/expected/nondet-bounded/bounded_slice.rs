@@ -0,0 +1,49 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Test the Kani library's API for creating a non-det slice backed by an array
+
+fn sum(s: &[u8]) -> u32 {
+    s.iter().map(|elem| *elem as u32).sum()
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_iterate() {
+    let slice = kani::slice::any_bounded_slice::<u8, 5>();
+    assert!(slice.len() <= 5);
+    let mut count = 0;
+    for _ in slice.iter() {
+        count += 1;
+    }
+    assert_eq!(count, slice.len());
+    assert!(sum(&slice) <= 5 * u8::MAX as u32);
+    kani::cover!(slice.is_empty());
+    kani::cover!(slice.len() == 5);
+}
+
+#[kani::proof]
+fn check_empty() {
+    let slice = kani::slice::any_bounded_slice::<u32, 3>();
+    kani::assume(slice.is_empty());
+    let view = slice.as_slice();
+    assert!(view.is_empty());
+    assert!(!view.as_ptr().is_null());
+    assert!(view.as_ptr().is_aligned());
+}
+
+#[kani::proof]
+fn check_zero_max_length() {
+    let slice = kani::slice::any_bounded_slice::<u64, 0>();
+    assert!(slice.is_empty());
+}
+
+#[kani::proof]
+fn check_mutate() {
+    let mut slice = kani::slice::any_bounded_slice::<u16, 4>();
+    kani::assume(!slice.is_empty());
+    slice[0] = 10;
+    let len = slice.len();
+    let (array, parts_len) = slice.into_parts();
+    assert_eq!(parts_len, len);
+    assert_eq!(array[0], 10);
+}
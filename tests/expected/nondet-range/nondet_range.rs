@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that `kani::nondet_range` only generates values in the given inclusive range, that both
+//! endpoints are reachable, and that an empty range is rejected.
+
+#[kani::proof]
+fn check_in_range() {
+    let x: i32 = kani::nondet_range(-5, 10);
+    assert!(-5 <= x && x <= 10);
+    kani::cover!(x == -5, "lower bound");
+    kani::cover!(x == 10, "upper bound");
+}
+
+#[kani::proof]
+fn check_single_value() {
+    let x: u64 = kani::nondet_range(7, 7);
+    assert_eq!(x, 7);
+}
+
+#[kani::proof]
+fn check_full_range() {
+    let x: u8 = kani::nondet_range(u8::MIN, u8::MAX);
+    kani::cover!(x == u8::MIN, "min");
+    kani::cover!(x == u8::MAX, "max");
+}
+
+#[kani::proof]
+fn check_empty_range() {
+    let lo: usize = kani::any();
+    let hi: usize = kani::any();
+    let _ = kani::nondet_range(lo, hi);
+}
@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that offsetting a pointer past the end of an array is caught, while zero, in-bounds
+//! negative and one-past-the-end offsets are accepted.
+
+#[kani::proof]
+fn check_offset_past_end() {
+    let array = [1u16, 2, 3];
+    let ptr = array.as_ptr();
+    let count: isize = kani::any_where(|c| *c > 3 && *c < 10);
+    // SAFETY: This is not safe! The result is past the end of the array.
+    let _oob = unsafe { ptr.offset(count) };
+}
+
+#[kani::proof]
+fn check_offset_valid() {
+    let array = [1u16, 2, 3];
+    let ptr = array.as_ptr();
+    // SAFETY: All these offsets stay within the array or point one past its end.
+    unsafe {
+        assert_eq!(ptr.offset(0), ptr);
+        let end = ptr.offset(3);
+        let last = end.offset(-1);
+        assert_eq!(*last, 3);
+        assert_eq!(*last.offset(-2), 1);
+    }
+}
+
+#[kani::proof]
+fn check_zero_offset_dangling() {
+    let ptr = std::ptr::NonNull::<u64>::dangling().as_ptr();
+    // SAFETY: An offset of zero is always valid.
+    let same = unsafe { ptr.offset(0) };
+    assert_eq!(same, ptr);
+}
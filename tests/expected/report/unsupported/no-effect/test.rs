@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This test checks that an assertion that comes after a reachable unsupported construct is not
+// reported as successful, even if the construct doesn't affect the asserted condition.
+
+fn foo() {
+    unsafe {
+        std::arch::asm!("nop");
+    }
+}
+
+#[kani::proof]
+fn main() {
+    let x: u8 = kani::any();
+    foo();
+    assert!(x / 2 <= x);
+}
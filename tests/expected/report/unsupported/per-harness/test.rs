@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a reachable unsupported construct makes the other assertions of the same harness
+//! UNDETERMINED, while a construct behind a dead branch does not affect the results.
+
+fn unsupp(x: &mut u8) {
+    unsafe {
+        std::arch::asm!("nop");
+    }
+}
+
+#[kani::proof]
+fn check_reachable() {
+    let mut x = 0;
+    unsupp(&mut x);
+    assert!(x == 0, "reachable: x is still zero");
+}
+
+#[kani::proof]
+fn check_dead_branch() {
+    let mut x = 0;
+    let y = 5;
+    if x + y == 6 {
+        unsupp(&mut x);
+    }
+    assert!(x == 0, "dead branch: x is still zero");
+}
@@ -0,0 +1,50 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z skip-debug-assertions
+
+//! Check that debug assertions are removed with `-Z skip-debug-assertions`, including the
+//! evaluation of their conditions, while regular assertions are still checked.
+
+fn checked_half(x: u8) -> u8 {
+    debug_assert!(false, "debug-only invariant");
+    debug_assert_eq!(x % 2, 1);
+    debug_assert_ne!(x, x);
+    x / 2
+}
+
+#[kani::proof]
+fn check_debug_assert_elided() {
+    let x: u8 = kani::any();
+    let half = checked_half(x);
+    assert!(half <= 127, "regular assertion");
+    kani::cover!(x == 4, "code after the debug assertions is reachable");
+}
+
+#[kani::proof]
+fn check_assert_untouched() {
+    let x: u8 = kani::any();
+    debug_assert!(false);
+    assert!(x != 10, "regular assertion fails");
+}
+
+fn evaluated() -> bool {
+    panic!("debug assertion condition was evaluated")
+}
+
+#[kani::proof]
+fn check_debug_assert_condition_skipped() {
+    debug_assert!(evaluated());
+    debug_assert_eq!(evaluated(), true);
+    debug_assert_ne!(evaluated(), false, "with a message");
+}
+
+const fn const_checked_half(x: u8) -> u8 {
+    debug_assert!(x % 2 == 1);
+    x / 2
+}
+
+#[kani::proof]
+fn check_debug_assert_in_const_fn() {
+    let x: u8 = kani::any();
+    assert!(const_checked_half(x) <= 127, "const fn result");
+}
@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that debug assertions are checked by default.
+
+#[kani::proof]
+fn check_debug_assert_fires() {
+    let x: u8 = kani::any();
+    debug_assert!(false, "debug-only invariant");
+    assert!(x / 2 <= 127, "regular assertion");
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `slice::from_raw_parts` fails if the total size of the slice exceeds `isize::MAX`.
+
+use std::slice;
+
+#[kani::proof]
+fn check_huge_len() {
+    let array = [0u32; 4];
+    let len: usize = kani::any();
+    let slice = unsafe { slice::from_raw_parts(array.as_ptr(), len) };
+    kani::cover!(slice.len() == len);
+}
+
+#[kani::proof]
+fn check_small_len() {
+    let mut array = [1u32, 2, 3, 4];
+    let len: usize = kani::any_where(|len| *len <= 4);
+    let slice = unsafe { slice::from_raw_parts_mut(array.as_mut_ptr(), len) };
+    assert_eq!(slice.len(), len);
+    if len > 0 {
+        slice[0] = 10;
+        assert_eq!(array[0], 10);
+    }
+}
+
+#[kani::proof]
+fn check_zst_huge_len() {
+    let unit = ();
+    let slice = unsafe { slice::from_raw_parts(&unit as *const (), usize::MAX) };
+    assert_eq!(slice.len(), usize::MAX);
+}
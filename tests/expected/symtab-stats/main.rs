@@ -0,0 +1,12 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --print-symtab-stats
+
+//! Check that `--print-symtab-stats` prints a summary of the generated symbol table and does not
+//! affect verification.
+
+#[kani::proof]
+fn check_symtab_stats() {
+    let x: u8 = kani::any();
+    assert!(x.wrapping_add(1) != x);
+}
@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z valid-value-checks
+
+//! Check that transmuting bytes into an enum fails if the bytes don't encode a valid variant.
+
+use std::mem::transmute;
+
+/// C-like enum with gaps in its discriminant values.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+enum Gaps {
+    A = 0,
+    B = 2,
+    C = 5,
+}
+
+/// Niche-encoded enum that uses the niche of `bool` for `Y` and `Z`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Niche {
+    X(bool),
+    Y,
+    Z,
+}
+
+#[kani::proof]
+fn check_gaps_invalid() {
+    let byte: u8 = kani::any();
+    let value: Gaps = unsafe { transmute(byte) };
+    assert!(matches!(value, Gaps::A | Gaps::B | Gaps::C));
+}
+
+#[kani::proof]
+fn check_gaps_valid() {
+    let byte: u8 = kani::any_where(|b| matches!(*b, 0 | 2 | 5));
+    let value: Gaps = unsafe { transmute(byte) };
+    assert_eq!(value as u8, byte);
+}
+
+#[kani::proof]
+fn check_niche_invalid() {
+    let byte: u8 = kani::any();
+    let value: Niche = unsafe { transmute(byte) };
+    kani::cover!(value == Niche::Z);
+}
+
+#[kani::proof]
+fn check_niche_valid() {
+    let byte: u8 = kani::any_where(|b| *b <= 3);
+    let value: Niche = unsafe { transmute(byte) };
+    kani::cover!(value == Niche::X(true));
+    kani::cover!(value == Niche::Y);
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z uninit-checks
+
+//! Checks that `MaybeUninit::assume_init_read` is checked for uninitialized memory.
+
+use std::mem::MaybeUninit;
+
+/// Only a prefix of the buffer was written, so reading it is UB.
+#[kani::proof]
+fn check_partially_initialized() {
+    let mut buffer: MaybeUninit<[u8; 4]> = MaybeUninit::uninit();
+    let ptr = buffer.as_mut_ptr() as *mut u8;
+    unsafe {
+        ptr.write(1);
+        ptr.add(1).write(2);
+        let _ = buffer.assume_init_read();
+    }
+}
+
+/// The whole buffer was written, so reading it is fine.
+#[kani::proof]
+fn check_fully_initialized() {
+    let mut buffer: MaybeUninit<[u8; 4]> = MaybeUninit::uninit();
+    let ptr = buffer.as_mut_ptr() as *mut u8;
+    unsafe {
+        for i in 0..4 {
+            ptr.add(i).write(i as u8);
+        }
+        assert_eq!(buffer.assume_init_read(), [0, 1, 2, 3]);
+    }
+}
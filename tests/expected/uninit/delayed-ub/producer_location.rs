@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z ghost-state -Z uninit-checks
+
+//! Checks that delayed UB checks point to the instruction that produced the pointer used to write
+//! uninitialized bytes, even if it is in a different function than the read.
+
+/// Write a value with padding through a pointer to `value`.
+unsafe fn write_with_padding(value: &mut u128) {
+    let ptr = value as *mut u128;
+    let ptr = ptr as *mut (u8, u32, u64);
+    *ptr = (4, 4, 4);
+}
+
+fn read(value: &u128) -> u128 {
+    *value // UB: This reads a padding value!
+}
+
+#[kani::proof]
+fn delayed_ub_producer_location() {
+    let mut value: u128 = 0;
+    unsafe { write_with_padding(&mut value) };
+    let _read = read(&value);
+}
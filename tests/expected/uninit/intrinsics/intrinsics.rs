@@ -130,6 +130,25 @@ fn check_volatile_store_and_load_safe() {
     }
 }
 
+#[kani::proof]
+fn check_read_via_copy() {
+    unsafe {
+        let layout = Layout::from_size_align(16, 8).unwrap();
+        let src: *mut u8 = alloc(layout);
+        read_via_copy(src as *const u8); // ~ERROR: Accessing `src` here, which is uninitialized.
+    }
+}
+
+#[kani::proof]
+fn check_write_via_move_and_read_via_copy_safe() {
+    unsafe {
+        let layout = Layout::from_size_align(16, 8).unwrap();
+        let src: *mut u8 = alloc(layout);
+        write_via_move(src, 0);
+        read_via_copy(src as *const u8); // `src` is initialized here.
+    }
+}
+
 #[kani::proof]
 fn check_typed_swap_nonoverlapping() {
     unsafe {
@@ -0,0 +1,42 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z uninit-checks -Z mem-predicates
+
+//! Checks that `kani::mem::poison` marks memory as uninitialized.
+
+/// Reading a poisoned element should fail.
+#[kani::proof]
+fn check_read_poisoned() {
+    let mut arr: [u32; 4] = [1, 2, 3, 4];
+    let ptr = arr.as_mut_ptr();
+    kani::mem::poison(unsafe { ptr.add(1) }, 2);
+    let _ = unsafe { *ptr.add(2) };
+}
+
+/// Reading the elements around the poisoned region should succeed.
+#[kani::proof]
+fn check_read_outside_poisoned() {
+    let mut arr: [u32; 4] = [1, 2, 3, 4];
+    let ptr = arr.as_mut_ptr();
+    kani::mem::poison(unsafe { ptr.add(1) }, 2);
+    assert_eq!(unsafe { *ptr }, 1);
+    assert_eq!(unsafe { *ptr.add(3) }, 4);
+}
+
+/// Writing to a poisoned element makes it initialized again.
+#[kani::proof]
+fn check_write_after_poison() {
+    let mut arr: [u32; 4] = [1, 2, 3, 4];
+    let ptr = arr.as_mut_ptr();
+    kani::mem::poison(ptr, 4);
+    unsafe { *ptr.add(3) = 10 };
+    assert_eq!(unsafe { *ptr.add(3) }, 10);
+}
+
+/// Poisoning a zero-length region is a no-op.
+#[kani::proof]
+fn check_poison_zero_length() {
+    let mut val: u64 = 42;
+    kani::mem::poison(&mut val as *mut u64, 0);
+    assert_eq!(val, 42);
+}
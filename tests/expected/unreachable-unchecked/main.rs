@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that reaching `unreachable_unchecked` is reported as UB, while compiler-inserted
+//! unreachable code keeps its existing handling.
+
+fn to_digit(val: u8) -> u8 {
+    match val {
+        0..=9 => val,
+        // SAFETY: This is not safe, since callers may pass any value.
+        _ => unsafe { std::hint::unreachable_unchecked() },
+    }
+}
+
+#[kani::proof]
+fn check_reachable() {
+    let val: u8 = kani::any();
+    assert!(to_digit(val) < 10);
+}
+
+#[kani::proof]
+fn check_unreachable() {
+    let val: u8 = kani::any();
+    kani::assume(val < 10);
+    assert!(to_digit(val) < 10);
+}
+
+#[kani::proof]
+fn check_exhaustive_match() {
+    let val: bool = kani::any();
+    let res = match val {
+        true => 1,
+        false => 0,
+    };
+    assert!(res <= 1);
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z assume-unwind
+
+//! Check that `#[kani::unwind_assume]` is rejected if the harness has no loop with the given id.
+
+#[kani::proof]
+#[kani::unwind_assume(loop_id = 1, bound = 3)]
+fn check_single_loop() {
+    let limit: u8 = kani::any();
+    let mut count = 0;
+    while count < limit {
+        count += 1;
+    }
+}
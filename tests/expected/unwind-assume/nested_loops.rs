@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z assume-unwind
+
+//! Check that loops are numbered in the same order as their unwinding assertions, which follows
+//! their back-edges. Thus, an inner loop comes before the loop that encloses it.
+
+#[kani::proof]
+#[kani::unwind(4)]
+#[kani::unwind_assume(loop_id = 0, bound = 2)]
+fn check_inner_loop_assumed() {
+    let mut outer = 0;
+    while outer < 3 {
+        let limit: u32 = kani::any();
+        let mut inner = 0;
+        while inner < limit {
+            inner += 1;
+        }
+        assert!(inner <= 2, "inner loop is bounded by the assumption");
+        outer += 1;
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z assume-unwind
+
+//! Check that `#[kani::unwind_assume]` only replaces the unwinding assertion of the selected loop
+//! by an assumption, while the other loop keeps its unwinding assertion.
+
+#[kani::proof]
+#[kani::unwind(5)]
+#[kani::unwind_assume(loop_id = 0, bound = 3)]
+fn check_first_loop_assumed() {
+    let limit: u32 = kani::any();
+    let mut first = 0;
+    while first < limit {
+        first += 1;
+    }
+    assert!(first <= 3, "first loop is bounded by the assumption");
+
+    let mut second = 0;
+    while second < 10 {
+        second += 1;
+    }
+}
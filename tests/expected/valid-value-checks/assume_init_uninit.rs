@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z valid-value-checks
+//! Check that Kani flags `MaybeUninit::uninit().assume_init()` as UB when the target type has
+//! invalid bit patterns, and accepts it for types where every bit pattern is valid.
+
+use std::mem::MaybeUninit;
+
+#[kani::proof]
+pub fn check_uninit_u8() {
+    let _val: u8 = unsafe { MaybeUninit::uninit().assume_init() };
+}
+
+#[kani::proof]
+pub fn check_uninit_bool() {
+    let _val: bool = unsafe { MaybeUninit::uninit().assume_init() };
+}
+
+#[kani::proof]
+pub fn check_uninit_ref() {
+    let _val: &u32 = unsafe { MaybeUninit::uninit().assume_init() };
+}
+
+#[kani::proof]
+pub fn check_init_bool() {
+    let val: bool = unsafe { MaybeUninit::new(true).assume_init() };
+    assert!(val);
+}
@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z valid-value-checks
+//! Check that Kani flags `char::from_u32_unchecked` with a value that is out of range or is a
+//! surrogate code point, while the safe conversions are not reported.
+
+#[kani::proof]
+fn check_from_u32_unchecked() {
+    let c = unsafe { char::from_u32_unchecked(kani::any()) };
+    std::hint::black_box(c);
+}
+
+#[kani::proof]
+fn check_from_u32_safe() {
+    let val: u32 = kani::any();
+    if let Some(c) = char::from_u32(val) {
+        assert!(!(0xD800..=0xDFFF).contains(&(c as u32)));
+        assert!(c as u32 <= 0x10FFFF);
+    }
+    let literal = '\u{10FFFF}';
+    assert_eq!(literal as u32, 0x10FFFF);
+}
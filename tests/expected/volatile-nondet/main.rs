@@ -0,0 +1,45 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z volatile-nondet
+
+//! Check that volatile accesses are modeled as nondeterministic when `-Z volatile-nondet` is
+//! enabled, while regular accesses to the same memory are not affected.
+
+use std::ptr;
+
+#[kani::proof]
+fn check_volatile_reads_may_differ() {
+    let mut register: u32 = 0;
+    let addr = &mut register as *mut u32;
+    let first = unsafe { ptr::read_volatile(addr) };
+    let second = unsafe { ptr::read_volatile(addr) };
+    kani::cover!(first != second, "volatile reads differ");
+    assert!(first == second, "volatile reads are equal");
+}
+
+#[kani::proof]
+fn check_volatile_write_havocs() {
+    let mut register: u32 = 0;
+    let addr = &mut register as *mut u32;
+    unsafe { ptr::write_volatile(addr, 10) };
+    kani::cover!(register != 10, "volatile write was havocked");
+}
+
+#[kani::proof]
+fn check_regular_accesses() {
+    let mut value: u32 = 0;
+    let addr = &mut value as *mut u32;
+    unsafe { ptr::write(addr, 10) };
+    let first = unsafe { ptr::read(addr) };
+    let second = unsafe { ptr::read(addr) };
+    assert_eq!(first, 10);
+    assert_eq!(first, second);
+}
+
+#[kani::proof]
+fn check_volatile_bool_unsupported() {
+    let mut flag = false;
+    let addr = &mut flag as *mut bool;
+    let value = unsafe { ptr::read_volatile(addr) };
+    assert!(!value);
+}
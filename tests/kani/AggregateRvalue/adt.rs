@@ -0,0 +1,82 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Test that Kani can verify code that builds structs and enums from aggregate rvalues, including
+// enums whose discriminant is encoded in a niche.
+
+use std::marker::PhantomData;
+use std::num::NonZeroU8;
+
+struct Pair<T> {
+    first: u8,
+    _marker: PhantomData<T>,
+    second: u32,
+}
+
+enum Shape {
+    Circle(u32),
+    Rect { width: u16, height: u16 },
+    Empty,
+}
+
+/// Like `Option<NonZeroU8>`, `Zero` is encoded as `0` in the niche of `NonZeroU8`.
+enum Niche {
+    Value(NonZeroU8),
+    Zero,
+}
+
+/// A niche-encoded enum with more than one dataless variant.
+enum MultiNiche {
+    Some(bool),
+    First,
+    Second,
+}
+
+#[kani::proof]
+fn check_struct() {
+    let first: u8 = kani::any();
+    let second: u32 = kani::any();
+    let pair = Pair::<()> { first, _marker: PhantomData, second };
+    assert_eq!(pair.first, first);
+    assert_eq!(pair.second, second);
+}
+
+#[kani::proof]
+fn check_enum() {
+    let width: u16 = kani::any();
+    let height: u16 = kani::any();
+    let shape = if kani::any() { Shape::Rect { width, height } } else { Shape::Circle(5) };
+    match shape {
+        Shape::Rect { width: w, height: h } => assert!(w == width && h == height),
+        Shape::Circle(radius) => assert_eq!(radius, 5),
+        Shape::Empty => unreachable!(),
+    }
+    assert!(!matches!(Shape::Empty, Shape::Circle(_)));
+}
+
+#[kani::proof]
+fn check_niche_enum() {
+    let val: NonZeroU8 = kani::any();
+    let niche = if kani::any() { Niche::Value(val) } else { Niche::Zero };
+    match niche {
+        Niche::Value(v) => assert_eq!(v, val),
+        Niche::Zero => {}
+    }
+    assert!(matches!(Niche::Value(val), Niche::Value(_)));
+    assert!(matches!(Niche::Zero, Niche::Zero));
+}
+
+#[kani::proof]
+fn check_multi_niche_enum() {
+    let flag: bool = kani::any();
+    let choice: u8 = kani::any();
+    let value = match choice {
+        0 => MultiNiche::Some(flag),
+        1 => MultiNiche::First,
+        _ => MultiNiche::Second,
+    };
+    match value {
+        MultiNiche::Some(f) => assert!(choice == 0 && f == flag),
+        MultiNiche::First => assert_eq!(choice, 1),
+        MultiNiche::Second => assert!(choice > 1),
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the properties of arbitrary `Duration` values.
+
+use core::time::Duration;
+
+#[kani::proof]
+fn check_nanos_in_range() {
+    let duration: Duration = kani::any();
+    assert!(duration.subsec_nanos() < 1_000_000_000);
+    kani::cover!(duration.subsec_nanos() == 999_999_999);
+    kani::cover!(duration == Duration::MAX);
+}
+
+#[kani::proof]
+fn check_as_millis() {
+    let duration: Duration = kani::any();
+    let millis = duration.as_millis();
+    assert_eq!(millis / 1000, duration.as_secs() as u128);
+    assert_eq!(millis % 1000, duration.subsec_millis() as u128);
+}
+
+#[kani::proof]
+fn check_checked_add() {
+    let a: Duration = kani::any();
+    let b: Duration = kani::any();
+    if let Some(sum) = a.checked_add(b) {
+        assert!(sum >= a && sum >= b);
+        assert!(sum.subsec_nanos() < 1_000_000_000);
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the codegen of repeat expressions `[e; N]`, including the empty case.
+
+fn repeat<const N: usize>(elem: u8) -> [u8; N] {
+    [elem; N]
+}
+
+#[kani::proof]
+fn check_repeat_empty() {
+    let arr = [kani::any::<u8>(); 0];
+    assert_eq!(arr.len(), 0);
+    assert_eq!(arr, []);
+    assert_eq!(repeat::<0>(kani::any()), []);
+}
+
+#[kani::proof]
+fn check_repeat_const() {
+    let arr = [7u8; 3];
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr, [7, 7, 7]);
+    assert_eq!(repeat::<3>(7), arr);
+}
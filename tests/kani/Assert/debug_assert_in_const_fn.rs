@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This test checks that `debug_assert!` and related macros can be used in a const fn
+
+const fn const_sub(x: u32, y: u32) -> u32 {
+    debug_assert!(x >= y);
+    debug_assert_ne!(y, u32::MAX);
+    x - y
+}
+
+const DIFF: u32 = const_sub(10, 3);
+
+#[kani::proof]
+fn check() {
+    let x = kani::any();
+    let y = kani::any_where(|y: &u32| *y <= x && *y < 10);
+    assert!(const_sub(x, y) <= x);
+    assert!(DIFF == 7);
+}
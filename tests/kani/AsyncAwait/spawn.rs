@@ -46,3 +46,44 @@ fn round_robin_schedule_manual() {
     );
     assert_eq!(x.load(Ordering::Relaxed), 2);
 }
+
+/// Spawn three tasks that yield once before incrementing a shared counter, and wait for them.
+fn spawn_three_tasks(max_steps: usize) -> i64 {
+    let x = Arc::new(AtomicI64::new(0));
+    let x2 = x.clone();
+    kani::block_on_with_spawn(
+        async move {
+            let handles: Vec<_> = (0..3)
+                .map(|_| {
+                    let x3 = x2.clone();
+                    kani::spawn(async move {
+                        kani::yield_now().await;
+                        x3.fetch_add(1, Ordering::Relaxed);
+                    })
+                })
+                .collect();
+            for handle in handles {
+                handle.await;
+            }
+            assert_eq!(x2.load(Ordering::Relaxed), 3);
+        },
+        kani::RoundRobin::with_max_steps(max_steps),
+    );
+    x.load(Ordering::Relaxed)
+}
+
+/// All tasks complete within 9 scheduling decisions, so the bound does not change the result.
+#[kani::proof]
+#[kani::unwind(11)]
+fn round_robin_max_steps() {
+    assert_eq!(spawn_three_tasks(10), 3);
+}
+
+/// The tasks cannot complete within 4 scheduling decisions, so the execution is discarded
+/// instead of reporting a failure.
+#[kani::proof]
+#[kani::unwind(6)]
+fn round_robin_max_steps_exceeded() {
+    spawn_three_tasks(4);
+    unreachable!("the execution should be cut off by the step bound");
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Test that closures capturing a mix of by-value, by-reference and zero-sized upvars are
+//! constructed correctly.
+
+struct Void;
+
+#[kani::proof]
+fn check_mixed_captures() {
+    let by_value: u32 = kani::any();
+    let mut by_ref: u8 = kani::any();
+    let void = Void;
+    let old_ref = by_ref;
+    let expected = by_value as u64 + old_ref as u64;
+    let closure = move || {
+        let _void = &void;
+        by_value as u64
+    };
+    let mut add = |offset: u64| {
+        by_ref = by_ref.wrapping_add(1);
+        closure() + offset
+    };
+    assert_eq!(add(old_ref as u64), expected);
+    assert_eq!(by_ref, old_ref.wrapping_add(1));
+}
+
+#[kani::proof]
+fn check_no_captures() {
+    let closure = || 42u8;
+    assert_eq!(std::mem::size_of_val(&closure), 0);
+    assert_eq!(closure(), 42);
+}
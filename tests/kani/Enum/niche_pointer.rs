@@ -0,0 +1,56 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the discriminant of an `Option` of a reference is read from the pointer niche,
+//! i.e., the option is `None` exactly when the pointer is null. For slice references, the niche
+//! is the data pointer of the fat pointer.
+
+use std::mem::transmute;
+use std::ptr;
+
+#[kani::proof]
+fn check_option_ref() {
+    let val: u8 = kani::any();
+    let raw: *const u8 = if kani::any() { ptr::null() } else { &val };
+    let opt: Option<&u8> = unsafe { transmute(raw) };
+    assert_eq!(opt.is_none(), raw.is_null());
+    match opt {
+        Some(inner) => assert_eq!(*inner, val),
+        None => assert!(raw.is_null()),
+    }
+}
+
+#[kani::proof]
+fn check_option_mut_ref() {
+    let mut val: u8 = kani::any();
+    let raw: *mut u8 = if kani::any() { ptr::null_mut() } else { &mut val };
+    let opt: Option<&mut u8> = unsafe { transmute(raw) };
+    assert_eq!(opt.is_some(), !raw.is_null());
+    if let Some(inner) = opt {
+        *inner = 10;
+        assert_eq!(val, 10);
+    }
+}
+
+#[kani::proof]
+fn check_option_slice() {
+    let arr: [u8; 4] = kani::any();
+    let len: usize = kani::any_where(|len| *len <= arr.len());
+    let raw: *const [u8] =
+        if kani::any() { ptr::slice_from_raw_parts(ptr::null(), len) } else { &arr[..len] };
+    let opt: Option<&[u8]> = unsafe { transmute(raw) };
+    assert_eq!(opt.is_none(), raw.is_null());
+    if let Some(slice) = opt {
+        assert_eq!(slice.len(), len);
+    }
+}
+
+#[kani::proof]
+fn check_option_ref_roundtrip() {
+    let val: u8 = kani::any();
+    let opt: Option<&u8> = if kani::any() { Some(&val) } else { None };
+    let raw: *const u8 = unsafe { transmute(opt) };
+    assert_eq!(opt.is_none(), raw.is_null());
+    let slice_opt: Option<&[u8]> = opt.map(std::slice::from_ref);
+    assert_eq!(slice_opt.is_some(), opt.is_some());
+}
@@ -0,0 +1,60 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --verify-allocator
+
+//! Check that `--verify-allocator` routes allocations through the user's global allocator.
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const POOL_SIZE: usize = 64;
+
+#[repr(align(16))]
+struct Pool([u8; POOL_SIZE]);
+
+/// A simple bump allocator that never frees memory.
+struct BumpAllocator {
+    pool: UnsafeCell<Pool>,
+    next: AtomicUsize,
+    allocs: AtomicUsize,
+    deallocs: AtomicUsize,
+}
+
+unsafe impl Sync for BumpAllocator {}
+
+unsafe impl GlobalAlloc for BumpAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocs.fetch_add(1, Ordering::Relaxed);
+        // The pool is aligned to 16 bytes, which is enough for the types used in this test.
+        let start = self.next.load(Ordering::Relaxed).next_multiple_of(layout.align());
+        let end = start + layout.size();
+        if end > POOL_SIZE {
+            return std::ptr::null_mut();
+        }
+        self.next.store(end, Ordering::Relaxed);
+        unsafe { self.pool.get().cast::<u8>().add(start) }
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
+        self.deallocs.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: BumpAllocator = BumpAllocator {
+    pool: UnsafeCell::new(Pool([0; POOL_SIZE])),
+    next: AtomicUsize::new(0),
+    allocs: AtomicUsize::new(0),
+    deallocs: AtomicUsize::new(0),
+};
+
+#[kani::proof]
+fn check_box_uses_allocator() {
+    let val: u32 = kani::any();
+    let boxed = Box::new(val);
+    assert_eq!(*boxed, val);
+    assert_eq!(ALLOCATOR.allocs.load(Ordering::Relaxed), 1);
+    drop(boxed);
+    assert_eq!(ALLOCATOR.deallocs.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Check that `atomic_nand` and other variants (stable version) return the
+// expected result.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[kani::proof]
+fn main() {
+    // pub fn fetch_nand(&self, val: u8, order: Ordering) -> u8
+    // Performs a bitwise "nand" operation on the current value and
+    // the argument val, and sets the new value to the result.
+    // Returns the previous value.
+    let old: u8 = kani::any();
+    let val: u8 = kani::any();
+    let a1 = AtomicU8::new(old);
+    let a2 = AtomicU8::new(old);
+    let a3 = AtomicU8::new(old);
+    let a4 = AtomicU8::new(old);
+    let a5 = AtomicU8::new(old);
+
+    // `fetch_nand` is the stable version of `atomic_nand`
+    assert!(a1.fetch_nand(val, Ordering::Acquire) == old);
+    assert!(a2.fetch_nand(val, Ordering::Release) == old);
+    assert!(a3.fetch_nand(val, Ordering::AcqRel) == old);
+    assert!(a4.fetch_nand(val, Ordering::Relaxed) == old);
+    assert!(a5.fetch_nand(val, Ordering::SeqCst) == old);
+
+    let new = !(old & val);
+    assert!(a1.load(Ordering::SeqCst) == new);
+    assert!(a2.load(Ordering::SeqCst) == new);
+    assert!(a3.load(Ordering::SeqCst) == new);
+    assert!(a4.load(Ordering::SeqCst) == new);
+    assert!(a5.load(Ordering::SeqCst) == new);
+}
@@ -0,0 +1,51 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Check that `ptr::copy` and `ptr::copy_nonoverlapping` work with a symbolic number of elements,
+// including zero elements and zero-sized types.
+
+use std::ptr;
+
+#[kani::proof]
+fn check_copy_nonoverlapping_any_count() {
+    let src: [u16; 4] = kani::any();
+    let mut dst = [0u16; 4];
+    let count: usize = kani::any();
+    kani::assume(count <= src.len());
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), count) };
+    let idx: usize = kani::any();
+    kani::assume(idx < src.len());
+    if idx < count {
+        assert_eq!(dst[idx], src[idx]);
+    } else {
+        assert_eq!(dst[idx], 0);
+    }
+}
+
+#[kani::proof]
+fn check_copy_any_count() {
+    let mut arr: [u8; 4] = kani::any();
+    let orig = arr;
+    let count: usize = kani::any();
+    kani::assume(count < arr.len());
+    // Overlapping copy that shifts the first `count` elements by one.
+    unsafe { ptr::copy(arr.as_ptr(), arr.as_mut_ptr().add(1), count) };
+    let idx: usize = kani::any();
+    kani::assume(idx < count);
+    assert_eq!(arr[idx + 1], orig[idx]);
+}
+
+#[kani::proof]
+fn check_copy_zero_count_dangling() {
+    let src = ptr::NonNull::<u64>::dangling();
+    let dst = ptr::NonNull::<u64>::dangling();
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_ptr(), 0) };
+}
+
+#[kani::proof]
+fn check_copy_zst() {
+    let src = [(); 8];
+    let mut dst = [(); 8];
+    let count: usize = kani::any();
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), count) };
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Checks that `read_via_copy` returns the value pointed to by the pointer
+// passed as the argument.
+#![feature(core_intrinsics)]
+
+#[kani::proof]
+fn main() {
+    let arr: [u32; 2] = kani::any();
+    let ptr = arr.as_ptr();
+    let fst_val = unsafe { std::intrinsics::read_via_copy(ptr) };
+    assert_eq!(fst_val, arr[0]);
+    let snd_val = unsafe { std::intrinsics::read_via_copy(ptr.add(1)) };
+    assert_eq!(snd_val, arr[1]);
+}
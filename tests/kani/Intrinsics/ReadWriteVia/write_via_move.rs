@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Checks that `write_via_move` writes the value to the location pointed to by
+// the pointer passed as the argument.
+#![feature(core_intrinsics)]
+
+#[kani::proof]
+fn main() {
+    let mut arr = [0u32; 2];
+    let val: u32 = kani::any();
+    let ptr = arr.as_mut_ptr();
+    unsafe { std::intrinsics::write_via_move(ptr.add(1), val) };
+    assert_eq!(arr, [0, val]);
+}
@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Check that Kani supports transmutes between arrays, tuples and integers of the same size, and
+// that the bytes are preserved.
+
+#[kani::proof]
+fn check_arr_to_tuple() {
+    let arr: [u8; 4] = kani::any();
+    let tuple = unsafe { std::mem::transmute::<[u8; 4], (u8, u8, u8, u8)>(arr) };
+    // The layout of tuples is unspecified, so only check that the bytes are preserved.
+    for byte in [tuple.0, tuple.1, tuple.2, tuple.3] {
+        assert!(arr.contains(&byte));
+    }
+    let back = unsafe { std::mem::transmute::<(u8, u8, u8, u8), [u8; 4]>(tuple) };
+    assert_eq!(back, arr);
+}
+
+#[kani::proof]
+fn check_u32_to_arr() {
+    let num: u32 = 0x12345678;
+    let bytes = unsafe { std::mem::transmute::<u32, [u8; 4]>(num) };
+    assert_eq!(bytes, num.to_ne_bytes());
+    assert_eq!(u32::from_ne_bytes(bytes), num);
+}
+
+#[kani::proof]
+fn check_u16_arr_to_u32() {
+    let halves: [u16; 2] = kani::any();
+    let num = unsafe { std::mem::transmute::<[u16; 2], u32>(halves) };
+    let bytes = num.to_ne_bytes();
+    assert_eq!([bytes[0], bytes[1]], halves[0].to_ne_bytes());
+    assert_eq!([bytes[2], bytes[3]], halves[1].to_ne_bytes());
+    let back = unsafe { std::mem::transmute::<u32, [u16; 2]>(num) };
+    assert_eq!(back, halves);
+}
@@ -0,0 +1,56 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z catch-unwind
+
+// https://doc.rust-lang.org/std/panic/fn.catch_unwind.html
+// Stable way of calling the `catch_unwind` intrinsic.
+use std::panic;
+
+#[kani::proof]
+fn main() {
+    let result = panic::catch_unwind(|| {
+        println!("hello!");
+    });
+    assert!(result.is_ok());
+
+    let result = panic::catch_unwind(|| {
+        panic!("oh no!");
+    });
+    assert!(result.is_err());
+}
+
+fn fail_if_zero(val: u8, counter: &mut u8) -> u8 {
+    *counter += 1;
+    if val == 0 {
+        panic!("zero");
+    }
+    *counter += 1;
+    val
+}
+
+/// Side effects that happen before the panic are preserved.
+#[kani::proof]
+fn check_nested_panic() {
+    let val: u8 = kani::any();
+    let mut counter = 0;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| fail_if_zero(val, &mut counter)));
+    if val == 0 {
+        assert!(result.is_err());
+        assert_eq!(counter, 1);
+    } else {
+        assert_eq!(result.ok(), Some(val));
+        assert_eq!(counter, 2);
+    }
+}
+
+/// Failed arithmetic checks and `unwrap` also unwind.
+#[kani::proof]
+fn check_builtin_panics() {
+    let val: u8 = kani::any();
+    let result = panic::catch_unwind(|| val + 1);
+    assert_eq!(result.is_err(), val == u8::MAX);
+
+    let opt: Option<u8> = kani::any();
+    let result = panic::catch_unwind(|| opt.unwrap());
+    assert_eq!(result.is_err(), opt.is_none());
+}
@@ -97,3 +97,26 @@ fn check_not_same_alloc_slice() {
     let slice_2 = ptr2 as *const [_];
     assert!(!same_allocation(slice_1, slice_2));
 }
+
+#[kani::proof]
+fn check_array_elements() {
+    let arr1 = [0u32; 4];
+    let arr2 = [0u32; 4];
+    let idx1: usize = kani::any_where(|idx| *idx < 4);
+    let idx2: usize = kani::any_where(|idx| *idx < 4);
+    assert!(same_allocation(&arr1[idx1], &arr1[idx2]));
+    assert!(!same_allocation(&arr1[idx1], &arr2[idx2]));
+}
+
+#[kani::proof]
+fn check_dangling_self() {
+    let dangling = std::ptr::NonNull::<u64>::dangling().as_ptr() as *const u64;
+    assert!(same_allocation(dangling, dangling));
+
+    let mut generator = PointerGenerator::<100>::new();
+    let ArbitraryPointer { ptr, status, .. } = generator.any_alloc_status::<u8>();
+    kani::assume(status == AllocationStatus::Dangling || status == AllocationStatus::DeadObject);
+    assert!(same_allocation(ptr, ptr));
+    let slice = std::ptr::slice_from_raw_parts(ptr, 10);
+    assert!(same_allocation(slice, slice));
+}
@@ -0,0 +1,71 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `offset_of!` evaluates to the offset used by Kani's layout, including for nested
+//! field paths and for both `#[repr(C)]` and the default representation.
+
+use std::mem::offset_of;
+
+#[repr(C)]
+struct Inner {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C)]
+struct OuterC {
+    x: u16,
+    inner: Inner,
+    y: u64,
+}
+
+struct OuterRust {
+    x: u16,
+    inner: Inner,
+    y: u64,
+}
+
+/// Compute the offset of a field using pointer arithmetic.
+fn addr_offset<T, F>(base: &T, field: &F) -> usize {
+    field as *const F as usize - base as *const T as usize
+}
+
+#[kani::proof]
+fn check_repr_c() {
+    assert_eq!(offset_of!(Inner, a), 0);
+    assert_eq!(offset_of!(Inner, b), 4);
+    assert_eq!(offset_of!(OuterC, x), 0);
+    assert_eq!(offset_of!(OuterC, inner), 4);
+    assert_eq!(offset_of!(OuterC, y), 16);
+}
+
+#[kani::proof]
+fn check_nested_path() {
+    assert_eq!(offset_of!(OuterC, inner.b), offset_of!(OuterC, inner) + offset_of!(Inner, b));
+    assert_eq!(offset_of!(OuterC, inner.b), 8);
+    assert_eq!(offset_of!(OuterRust, inner.b), offset_of!(OuterRust, inner) + offset_of!(Inner, b));
+}
+
+#[kani::proof]
+fn check_matches_layout() {
+    let outer = OuterRust { x: kani::any(), inner: Inner { a: kani::any(), b: kani::any() }, y: 0 };
+    assert_eq!(offset_of!(OuterRust, x), addr_offset(&outer, &outer.x));
+    assert_eq!(offset_of!(OuterRust, y), addr_offset(&outer, &outer.y));
+    assert_eq!(offset_of!(OuterRust, inner.a), addr_offset(&outer, &outer.inner.a));
+    assert_eq!(offset_of!(OuterRust, inner.b), addr_offset(&outer, &outer.inner.b));
+}
+
+#[kani::proof]
+fn check_pointer_arithmetic() {
+    let outer = OuterC { x: 0, inner: Inner { a: 0, b: kani::any() }, y: 0 };
+    let base = &outer as *const OuterC as *const u8;
+    let field = unsafe { base.add(offset_of!(OuterC, inner.b)) as *const u32 };
+    assert_eq!(unsafe { *field }, outer.inner.b);
+}
+
+#[kani::proof]
+fn check_tuple() {
+    let t: (u8, u32) = kani::any();
+    assert_eq!(offset_of!((u8, u32), 0), addr_offset(&t, &t.0));
+    assert_eq!(offset_of!((u8, u32), 1), addr_offset(&t, &t.1));
+}
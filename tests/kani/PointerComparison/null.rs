@@ -0,0 +1,42 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Test that `ptr::null` and `ptr::null_mut` can be compared against real pointers.
+use std::ptr;
+
+#[kani::proof]
+fn check_null_u8() {
+    let value: u8 = kani::any();
+    let real = &value as *const u8;
+    let null = ptr::null::<u8>();
+    assert!(null.is_null());
+    assert!(!real.is_null());
+    assert_ne!(null, real);
+    assert_eq!(null, ptr::null());
+}
+
+#[kani::proof]
+fn check_null_mut() {
+    let mut value: [u32; 2] = kani::any();
+    let real = &mut value as *mut [u32; 2];
+    let null = ptr::null_mut::<[u32; 2]>();
+    assert!(null.is_null());
+    assert_ne!(null, real);
+    assert_eq!(null as *const [u32; 2], ptr::null());
+}
+
+#[kani::proof]
+fn check_null_zst() {
+    let unit = ();
+    let null = ptr::null::<()>();
+    assert!(null.is_null());
+    assert_ne!(null, &unit as *const ());
+}
+
+#[kani::proof]
+fn check_null_cast_to_slice() {
+    let null = ptr::slice_from_raw_parts(ptr::null::<u8>(), 0);
+    assert!(null.is_null());
+    let array = [1u8, 2, 3];
+    assert_ne!(null, &array[..] as *const [u8]);
+}
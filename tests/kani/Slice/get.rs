@@ -0,0 +1,68 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the modeling of `<[T]>::get` and `<[T]>::get_mut` for index and range arguments.
+
+#[kani::proof]
+fn check_get_index() {
+    let arr: [u32; 4] = kani::any();
+    let idx: usize = kani::any();
+    match arr.get(idx) {
+        Some(val) => {
+            assert!(idx < arr.len());
+            assert_eq!(*val, arr[idx]);
+        }
+        None => assert!(idx >= arr.len()),
+    }
+}
+
+#[kani::proof]
+fn check_get_mut_index() {
+    let mut arr: [u32; 4] = kani::any();
+    let idx: usize = kani::any();
+    if let Some(val) = arr.get_mut(idx) {
+        *val = 10;
+        assert_eq!(arr[idx], 10);
+    } else {
+        assert!(idx >= arr.len());
+    }
+}
+
+#[kani::proof]
+fn check_get_range() {
+    let arr: [u8; 5] = kani::any();
+    let slice = &arr[..];
+    let start: usize = kani::any();
+    let end: usize = kani::any();
+    match slice.get(start..end) {
+        Some(sub) => {
+            assert!(start <= end && end <= slice.len());
+            assert_eq!(sub.len(), end - start);
+            if !sub.is_empty() {
+                assert_eq!(sub[0], slice[start]);
+                assert_eq!(sub[sub.len() - 1], slice[end - 1]);
+            }
+        }
+        None => assert!(start > end || end > slice.len()),
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_get_mut_range() {
+    let mut arr: [u8; 5] = [0; 5];
+    let start: usize = kani::any();
+    let end: usize = kani::any();
+    if let Some(sub) = arr.get_mut(start..end) {
+        sub.fill(1);
+        assert!(arr[start..end].iter().all(|x| *x == 1));
+    }
+}
+
+#[kani::proof]
+fn check_get_zst() {
+    let arr = [(); 3];
+    let idx: usize = kani::any();
+    assert_eq!(arr.get(idx).is_some(), idx < 3);
+    assert_eq!(arr.get(idx..3).map(|s| s.len()), if idx <= 3 { Some(3 - idx) } else { None });
+}
@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Checks that thread locals are initialized and can be mutated through interior mutability,
+// both when declared with `thread_local!` and with the `#[thread_local]` attribute.
+#![feature(thread_local)]
+
+use std::cell::Cell;
+
+thread_local! {
+    static LAZY: Cell<u32> = Cell::new(initial_value());
+    static CONST: Cell<u32> = const { Cell::new(10) };
+}
+
+#[thread_local]
+static RAW: Cell<u32> = Cell::new(5);
+
+fn initial_value() -> u32 {
+    100
+}
+
+#[kani::proof]
+fn check_lazy_initializer() {
+    assert_eq!(LAZY.get(), 100);
+    let delta: u32 = kani::any_where(|d| *d < 10);
+    LAZY.set(LAZY.get() + delta);
+    assert_eq!(LAZY.get(), 100 + delta);
+}
+
+#[kani::proof]
+fn check_const_initializer() {
+    assert_eq!(CONST.get(), 10);
+    CONST.with(|c| c.set(c.get() * 2));
+    assert_eq!(CONST.replace(0), 20);
+    assert_eq!(CONST.get(), 0);
+}
+
+#[kani::proof]
+fn check_thread_local_attribute() {
+    assert_eq!(RAW.get(), 5);
+    RAW.set(RAW.get() + 1);
+    assert_eq!(RAW.get(), 6);
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --vec-max-capacity 8
+
+//! Check that `--vec-max-capacity` bounds the capacity requested by `Vec::with_capacity`, while
+//! the vector still honors the requested capacity.
+
+#[kani::proof]
+#[kani::unwind(9)]
+fn check_push_with_symbolic_capacity() {
+    let capacity: usize = kani::any();
+    let mut vec: Vec<u32> = Vec::with_capacity(capacity);
+    assert!(capacity <= 8);
+    assert!(vec.is_empty());
+    assert!(vec.capacity() >= capacity);
+
+    let len: usize = kani::any_where(|len| *len <= capacity);
+    for i in 0..len {
+        vec.push(i as u32);
+    }
+    assert_eq!(vec.len(), len);
+    assert!(vec.capacity() >= capacity);
+    if len > 0 {
+        assert_eq!(vec[len - 1], len as u32 - 1);
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+set -u
+
+OUT_DIR=tmp_black_box_removal
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+cp opaque_value.rs ${OUT_DIR}
+pushd ${OUT_DIR} > /dev/null
+
+RUSTFLAGS="--emit mir" kani opaque_value.rs | grep "Complete -"
+
+MIR_FILES=$(find . -name "*.kani.mir")
+if [[ -z "${MIR_FILES}" ]]; then
+    echo "failed: no MIR dump was generated"
+    exit 1
+elif grep -q "black_box" ${MIR_FILES}; then
+    echo "failed: found calls to \`black_box\` in the MIR dump"
+    exit 1
+else
+    echo "success: no calls to \`black_box\` found in the MIR dump"
+fi
+
+popd > /dev/null
+rm -rf ${OUT_DIR}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that calls to `black_box` are removed from the MIR that Kani verifies.
+
+use std::hint::black_box;
+
+#[kani::proof]
+fn check_place_arg() {
+    let x: u8 = kani::any();
+    let y = black_box(x);
+    assert_eq!(x, y);
+}
+
+#[kani::proof]
+fn check_const_arg() {
+    assert_eq!(black_box(10u32), 10);
+}
+
+#[kani::proof]
+fn check_zst_arg() {
+    black_box(());
+    let _ = black_box([0u8; 0]);
+}
@@ -0,0 +1,55 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that the coverage mappings file generated by the compiler contains one entry per code
+# coverage check, and that the regions of macro-expanded code are attributed to the call site.
+set -eu
+
+OUT_DIR=tmp_dir
+
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+cp test.rs ${OUT_DIR}
+cd ${OUT_DIR}
+
+kani --coverage -Z source-coverage --keep-temps test.rs > kani.log
+
+python3 - <<'PY'
+import glob
+import json
+from collections import Counter
+
+mappings = [m for f in glob.glob("*.coverage_mappings.json") for m in json.load(open(f))]
+checks = [
+    check
+    for f in glob.glob("kanicov_*/*_kaniraw.json")
+    for file_checks in json.load(open(f))["data"].values()
+    for check in file_checks
+]
+
+def mapping_region(m):
+    r = m["region"]
+    return (r["start_line"], r["start_col"], r["end_line"], r["end_col"])
+
+def check_region(c):
+    r = c["region"]
+    return (r["start"][0], r["start"][1], r["end"][0], r["end"][1])
+
+assert mappings, "no coverage mappings were generated"
+assert all(m["file_name"].endswith("test.rs") for m in mappings)
+if Counter(map(mapping_region, mappings)) == Counter(map(check_region, checks)):
+    print("Coverage mappings match the coverage checks")
+else:
+    print(f"Mismatch: {len(mappings)} mappings and {len(checks)} checks")
+
+lines = open("test.rs").read().splitlines()
+call_site = next(i for i, line in enumerate(lines, 1) if "abs_diff!(x, y)" in line)
+definition = next(i for i, line in enumerate(lines, 1) if "if $a > $b" in line)
+starts = {m["region"]["start_line"] for m in mappings}
+if call_site in starts and definition not in starts:
+    print("Macro-expanded regions are attributed to the call site")
+PY
+
+cd ..
+rm -rf ${OUT_DIR}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+macro_rules! abs_diff {
+    ($a:expr, $b:expr) => {
+        if $a > $b { $a - $b } else { $b - $a }
+    };
+}
+
+fn distance(x: u8, y: u8) -> u8 {
+    let dist = abs_diff!(x, y);
+    if dist == 0 { 0 } else { dist - 1 }
+}
+
+#[kani::proof]
+fn check_distance() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    assert!(distance(x, y) < u8::MAX);
+}
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--goto-binary-version` accepts the supported version and can be combined with
+# `--gen-c` to dump the C code of the goto binary, and that other versions are rejected.
+set -u
+
+OUT_DIR=tmp_goto_binary_version
+
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+cp test.rs ${OUT_DIR}
+pushd ${OUT_DIR} > /dev/null
+
+kani test.rs -Z unstable-options --goto-binary-version 6 --gen-c > kani.log 2>&1
+echo "Supported version exit code: $?"
+grep "VERIFICATION:" kani.log
+if ls test_*check_version.c > /dev/null 2>&1; then
+    echo "C file generated"
+fi
+
+kani test.rs -Z unstable-options --goto-binary-version 5 > kani.log 2>&1
+echo "Unsupported version exit code: $?"
+grep "error:" kani.log | head -1
+
+popd > /dev/null
+rm -rf ${OUT_DIR}
@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+set -u
+
+OUT_DIR=tmp_inline_always
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+cp small_fns.rs ${OUT_DIR}
+pushd ${OUT_DIR} > /dev/null
+
+RUSTFLAGS="--emit mir" kani -Z inline-always small_fns.rs | grep "Complete -"
+
+MIR_FILES=$(find . -name "*.kani.mir")
+if [[ -z "${MIR_FILES}" ]]; then
+    echo "failed: no MIR dump was generated"
+    exit 1
+fi
+
+# Ignore the item headers, and only look for mentions of the functions in the bodies.
+function calls {
+    grep -hE "$1" ${MIR_FILES} | grep -vE "^\s*(// Item:|fn )"
+}
+
+if [[ -n "$(calls "mask_low|toggle")" ]]; then
+    echo "failed: found calls to \`#[inline(always)]\` functions in the MIR dump"
+elif ! grep -q "BitAnd(" ${MIR_FILES} || ! grep -q "BitXor(" ${MIR_FILES}; then
+    echo "failed: the body of the inlined functions is missing from the MIR dump"
+else
+    echo "success: calls to small \`#[inline(always)]\` functions were inlined"
+fi
+
+if [[ -n "$(calls "countdown")" ]]; then
+    echo "success: calls to recursive functions were kept"
+else
+    echo "failed: calls to recursive functions were inlined"
+fi
+
+popd > /dev/null
+rm -rf ${OUT_DIR}
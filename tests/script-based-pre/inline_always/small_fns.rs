@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that calls to small `#[inline(always)]` functions are inlined when `-Z inline-always`
+//! is enabled, while calls to other functions are kept.
+
+#[inline(always)]
+fn mask_low(x: u8) -> u8 {
+    x & 0x0F
+}
+
+#[inline(always)]
+fn toggle(a: u8, b: u8) -> u8 {
+    a ^ b
+}
+
+/// This function has more than one basic block, so it is not inlined.
+#[inline(always)]
+fn countdown(n: u8) -> u8 {
+    if n == 0 { 0 } else { countdown(n - 1) }
+}
+
+#[kani::proof]
+fn check_inlined() {
+    let x: u8 = kani::any();
+    let y = mask_low(x);
+    assert!(y < 16);
+    assert_eq!(toggle(toggle(x, y), y), x);
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_recursive() {
+    let n: u8 = kani::any_where(|n| *n < 3);
+    assert_eq!(countdown(n), 0);
+}
@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--no-assertion-reach-checks` removes every reachability check, including the ones
+# for covers, without changing the results of the user checks.
+set -u
+
+OUT_DIR=tmp_no_reach_checks
+
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+cp test.rs ${OUT_DIR}
+pushd ${OUT_DIR} > /dev/null
+
+# Run verification and collect the goto model of the harness, as well as the properties in it.
+run() {
+    local name=$1
+    shift
+    mkdir ${name}
+    cp test.rs ${name}
+    pushd ${name} > /dev/null
+    kani --keep-temps test.rs "$@" > kani.log
+    grep -E "Status:|Description:" kani.log | paste - - | sort > results.log
+    local goto_file=$(ls *.out | grep -v "symtab.out" | head -1)
+    cbmc --show-properties ${goto_file} > properties.log
+    popd > /dev/null
+}
+
+run with_checks
+run without_checks --no-assertion-reach-checks
+
+with_total=$(grep -c "^Property " with_checks/properties.log)
+without_total=$(grep -c "^Property " without_checks/properties.log)
+with_reach=$(grep -c "reachability_check" with_checks/properties.log)
+without_reach=$(grep -c "reachability_check" without_checks/properties.log)
+
+echo "reachability checks with the flag off: $((with_reach > 0))"
+echo "reachability checks with the flag on: ${without_reach}"
+if [[ $((with_total - with_reach)) -eq ${without_total} ]]; then
+    echo "success: only the reachability checks were removed"
+else
+    echo "failed: expected $((with_total - with_reach)) properties, found ${without_total}"
+fi
+
+# The unreachable assertion is reported as UNREACHABLE with the checks, and as SUCCESS without.
+sed -i 's/UNREACHABLE/SUCCESS/' with_checks/results.log
+if diff with_checks/results.log without_checks/results.log; then
+    echo "success: the results of the user checks are the same"
+fi
+grep "Complete -" without_checks/kani.log
+
+popd > /dev/null
+rm -rf ${OUT_DIR}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harness with assertions and covers used to compare the properties generated with and without
+//! assertion reachability checks.
+
+#[kani::proof]
+fn check_reach() {
+    let x: u8 = kani::any();
+    if x > 10 {
+        assert!(x != 5, "unreachable assertion");
+    }
+    kani::cover!(x == 3, "cover three");
+    kani::cover!(x == 3 && x == 4, "cover unsatisfiable");
+    assert!(x < 200, "failing assertion");
+}
@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Test that `kani::concrete_playback_run_all` keeps running the remaining harnesses after a
+# failure, and fails with the list of failing harnesses.
+
+set -o nounset
+
+export RUSTFLAGS="--edition 2021"
+
+echo "[TEST] Run all harnesses..."
+kani playback -Z concrete-playback run_all.rs -- replay_all 2>&1 | tee output.log
+echo "Exit code: ${PIPESTATUS[0]}"
+
+rm -f output.log
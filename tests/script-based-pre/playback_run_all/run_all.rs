@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Harness used to test that `kani::concrete_playback_run_all` replays every harness even if one of
+// them fails, and reports the failing ones at the end.
+
+#[kani::proof]
+pub fn check_small() {
+    let x: u8 = kani::any();
+    assert!(x < 10, "value is too big");
+}
+
+#[test]
+fn replay_all() {
+    kani::concrete_playback_run_all(&[
+        ("check_pass", vec![vec![3]], check_small),
+        ("check_fail", vec![vec![42]], check_small),
+        ("check_after_failure", vec![vec![9]], check_small),
+    ]);
+}
@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing -Z unstable-options --dump-reachable-functions check_reachable
+//
+//! Checks that `--dump-reachable-functions` lists the functions reachable from the selected
+//! harness, and that it marks stubs and functions modeled by Kani.
+
+fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+fn mock_increment(x: u32) -> u32 {
+    x.wrapping_add(1)
+}
+
+fn double(x: u32) -> u32 {
+    increment(x) * 2
+}
+
+#[kani::proof]
+#[kani::stub(increment, mock_increment)]
+fn check_reachable() {
+    let x: u32 = kani::any();
+    kani::assume(x < 100);
+    assert!(double(x) > x);
+}
+
+#[kani::proof]
+fn check_unrelated() {
+    assert!(unrelated());
+}
+
+fn unrelated() -> bool {
+    true
+}
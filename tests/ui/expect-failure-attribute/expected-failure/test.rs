@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that verification passes when `#[kani::expect(failure)]` is used and at least one
+//! check fails, regardless of whether it's related to a panic or not.
+
+#[kani::proof]
+#[kani::expect(failure)]
+fn check_overflow() {
+    let x: u8 = kani::any();
+    let _ = x + 1;
+}
+
+#[kani::proof]
+#[kani::expect(failure)]
+fn check_assert() {
+    let x: u8 = kani::any();
+    assert!(x != 10, "x can be 10");
+}
@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that `#[kani::expect]` only accepts `failure` as argument, and that it cannot be
+//! combined with `#[kani::should_panic]`.
+
+#[kani::proof]
+#[kani::expect(success)]
+fn check_invalid_arg() {}
+
+#[kani::proof]
+#[kani::expect(failure)]
+#[kani::should_panic]
+fn check_with_should_panic() {}
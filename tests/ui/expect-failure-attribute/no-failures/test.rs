@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that verification fails when `#[kani::expect(failure)]` is used and all checks pass.
+
+#[kani::proof]
+#[kani::expect(failure)]
+fn check() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
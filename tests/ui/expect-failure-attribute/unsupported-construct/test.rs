@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that verification fails when `#[kani::expect(failure)]` is used and the only failure
+//! comes from a reachable unsupported construct.
+
+#[kani::proof]
+#[kani::expect(failure)]
+fn check() {
+    unsafe {
+        std::arch::asm!("nop");
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z loop-contracts
+
+//! Check that loop invariants are rejected for constructs other than while-loops.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+fn invariant_on_block() {
+    let mut x: u8 = kani::any();
+
+    #[kani::loop_invariant(x >= 2)]
+    if x > 2 {
+        x = x - 1;
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --max-nondet-bytes 64
+
+//! Check that Kani rejects harnesses whose nondeterministic input exceeds the given budget, and
+//! that the error points at the largest contributor.
+
+fn any_buffer() -> [u8; 100] {
+    kani::any()
+}
+
+#[kani::proof]
+fn check_within_budget() {
+    let a: u64 = kani::any();
+    let b: [u32; 8] = kani::any();
+    assert!(a > 0 || b[0] < u32::MAX || true);
+}
+
+#[kani::proof]
+fn check_exceeds_budget() {
+    let first: u32 = kani::any();
+    let buffer = any_buffer();
+    assert!(buffer[0] == 0 || first > 0 || true);
+}
+
+#[kani::proof]
+fn check_slice_max_bound() {
+    let array: [u16; 40] = kani::any();
+    let slice = kani::slice::any_slice_of_array(&array);
+    assert!(slice.len() <= 40);
+}
@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --print-call-graph
+//
+//! Checks that `--print-call-graph` prints the edges from the harness to the functions it calls,
+//! including recursive calls.
+
+fn double(x: u32) -> u32 {
+    x.wrapping_mul(2)
+}
+
+fn countdown(n: u8) -> u8 {
+    if n == 0 { 0 } else { countdown(n - 1) }
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_call_graph() {
+    let x: u32 = kani::any();
+    assert_eq!(double(x) % 2, 0);
+    assert_eq!(countdown(2), 0);
+}
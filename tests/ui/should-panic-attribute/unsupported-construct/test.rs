@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that verfication fails when `#[kani::should_panic]` is used and an
+//! unsupported construct is reached, even if a panic is also encountered.
+
+#[kani::proof]
+#[kani::should_panic]
+fn check() {
+    if kani::any() {
+        panic!("expected panic");
+    }
+    // `catch_unwind` is not supported unless `-Z catch-unwind` is enabled.
+    let _ = std::panic::catch_unwind(|| 0);
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that `#[kani::solver_timeout]` only accepts a positive number of seconds.
+
+#[kani::proof]
+#[kani::solver_timeout(secs = 0)]
+fn check_zero() {}
+
+#[kani::proof]
+#[kani::solver_timeout(mins = 1)]
+fn check_unknown_key() {}
+
+#[kani::proof]
+#[kani::solver_timeout(10)]
+fn check_no_key() {}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that a harness that exceeds its `#[kani::solver_timeout]` is reported as a timeout,
+//! and that the other harnesses are still verified.
+
+#[kani::proof]
+#[kani::solver_timeout(secs = 5)]
+fn check_hard_harness() {
+    // construct a problem that requires a long time to solve
+    let (a1, b1, c1): (u64, u64, u64) = kani::any();
+    let (a2, b2, c2): (u64, u64, u64) = kani::any();
+    let p1 = a1.saturating_mul(b1).saturating_mul(c1);
+    let p2 = a2.saturating_mul(b2).saturating_mul(c2);
+    // (a1 == a2 && b1 == b2 && c1 == c2) implies p1 == p2
+    assert!(a1 != a2 || b1 != b2 || c1 != c2 || p1 == p2)
+}
+
+#[kani::proof]
+#[kani::solver_timeout(secs = 60)]
+fn check_easy_harness() {
+    let x: u8 = kani::any();
+    assert_eq!(x.wrapping_add(0), x);
+}
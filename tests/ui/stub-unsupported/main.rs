@@ -0,0 +1,55 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --stub-unsupported
+//
+//! Checks that `--stub-unsupported` replaces functions with unsupported constructs by stubs that
+//! return a nondeterministic value, that it lists every stubbed function in the verification
+//! results, and that it does not stub functions whose return type has validity invariants.
+
+/// Unsupported function that is replaced by a stub.
+fn read_counter() -> u64 {
+    let mut value = 0;
+    unsafe {
+        std::arch::asm!("nop");
+    }
+    value += 1;
+    value
+}
+
+/// Unsupported function that only has side effects, which the stub does not model.
+fn write_counter(counter: &mut u64) {
+    unsafe {
+        std::arch::asm!("nop");
+    }
+    *counter = 10;
+}
+
+/// Unsupported function that cannot be stubbed, since not every `char` value is valid.
+fn read_char() -> char {
+    unsafe {
+        std::arch::asm!("nop");
+    }
+    'a'
+}
+
+#[kani::proof]
+fn check_stubbed_value() {
+    let counter = read_counter();
+    // The stub returns any value.
+    kani::cover!(counter == 0);
+    kani::cover!(counter == u64::MAX);
+}
+
+#[kani::proof]
+fn check_stubbed_side_effect() {
+    let mut counter = 0;
+    write_counter(&mut counter);
+    assert_eq!(counter, 0, "side effects are not modeled");
+}
+
+#[kani::proof]
+fn check_not_stubbed() {
+    let c = read_char();
+    assert!(char::from_u32(c as u32).is_some());
+}